@@ -0,0 +1,395 @@
+/// Asserts that the absolute difference between two expressions is no greater than an epsilon.
+///
+/// Works with [`f32`], [`f64`], and all primitive integer types. The epsilon can be provided as a
+/// third argument, in the same way as for [`assert_slice_abs_diff_eq!`]. If it is omitted, it
+/// defaults to [`f32::EPSILON`] or [`f64::EPSILON`] for floating point values, and to `0` (i.e.
+/// exact equality) for integers.
+/// For signed integers, the epsilon (and the reported difference) is of the corresponding unsigned
+/// type, so that the difference between any two values can always be represented.
+///
+/// Values which are exactly equal always pass, so infinities of the same sign are considered equal.
+/// `NaN` is never considered equal to anything, including another `NaN`. If either side is `NaN`,
+/// the panic message will point that out explicitly.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_abs_diff_eq!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form. The epsilon must be given
+/// explicitly in this form, since it would otherwise be indistinguishable from the message.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_abs_diff_eq!(0.1 + 0.2, 0.3);
+/// assert_abs_diff_eq!(1.0, 1.05, 0.1);
+/// assert_abs_diff_eq!(10u32, 12, 2);
+///
+/// // With a custom message
+/// assert_abs_diff_eq!(1.0, 1.05, 0.1, "Expecting {} to be close to {}", 1.0, 1.05);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_abs_diff_eq!(1.0, 1.5, 0.1);  // Will panic
+/// # }
+/// ```
+///
+/// [`f32`]: https://doc.rust-lang.org/core/primitive.f32.html
+/// [`f64`]: https://doc.rust-lang.org/core/primitive.f64.html
+/// [`f32::EPSILON`]: https://doc.rust-lang.org/core/primitive.f32.html#associatedconstant.EPSILON
+/// [`f64::EPSILON`]: https://doc.rust-lang.org/core/primitive.f64.html#associatedconstant.EPSILON
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_abs_diff_eq!`]: crate::debug_assert_abs_diff_eq!
+/// [`assert_slice_abs_diff_eq!`]: crate::assert_slice_abs_diff_eq!
+#[macro_export]
+macro_rules! assert_abs_diff_eq {
+    ($left:expr, $right:expr, $epsilon:expr $(,)?) => {
+        match (&$left, &$right, &$epsilon) {
+            (left_val, right_val, epsilon_val) => {
+                if !$crate::__private::AbsDiffEq::abs_diff_eq(left_val, right_val, epsilon_val) {
+                    ::core::panic!(r#"assertion failed: `(|left - right| <= epsilon)`
+    left: `{:?}`,
+    right: `{:?}`,
+    difference: `{:?}`,
+    epsilon: `{:?}`{}"#,
+                        &*left_val,
+                        &*right_val,
+                        $crate::__private::AbsDiffEq::abs_diff(left_val, right_val),
+                        &*epsilon_val,
+                        $crate::__private::AbsDiffEq::nan_note(left_val, right_val),
+                    )
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $epsilon:expr, $($arg:tt)+) => {
+        match (&$left, &$right, &$epsilon) {
+            (left_val, right_val, epsilon_val) => {
+                if !$crate::__private::AbsDiffEq::abs_diff_eq(left_val, right_val, epsilon_val) {
+                    ::core::panic!(r#"assertion failed: `(|left - right| <= epsilon)`
+    left: `{:?}`,
+    right: `{:?}`,
+    difference: `{:?}`,
+    epsilon: `{:?}`{}: {}"#,
+                        &*left_val,
+                        &*right_val,
+                        $crate::__private::AbsDiffEq::abs_diff(left_val, right_val),
+                        &*epsilon_val,
+                        $crate::__private::AbsDiffEq::nan_note(left_val, right_val),
+                        ::core::format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                $crate::assert_abs_diff_eq!(
+                    *left_val,
+                    *right_val,
+                    $crate::__private::AbsDiffEq::default_epsilon(left_val)
+                )
+            }
+        }
+    };
+}
+
+/// Asserts that the absolute difference between two expressions is no greater than an epsilon on
+/// debug builds.
+///
+/// This macro behaves the same as [`assert_abs_diff_eq!`] on debug builds. On release builds it is
+/// a no-op.
+#[macro_export]
+macro_rules! debug_assert_abs_diff_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_abs_diff_eq!($($arg)*);
+        }
+    }};
+}
+
+/// Types which can be compared using an absolute difference.
+///
+/// This is an implementation detail of [`assert_abs_diff_eq!`] and is not part of the public API.
+#[doc(hidden)]
+pub trait AbsDiffEq: PartialEq {
+    type Epsilon: PartialOrd;
+
+    fn default_epsilon(&self) -> Self::Epsilon;
+
+    fn abs_diff(&self, other: &Self) -> Self::Epsilon;
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: &Self::Epsilon) -> bool {
+        // Equal infinities have a `NaN` difference, so they must be compared directly.
+        *self == *other || self.abs_diff(other) <= *epsilon
+    }
+
+    fn nan_note(&self, _other: &Self) -> &'static str {
+        ""
+    }
+}
+
+macro_rules! impl_abs_diff_eq_float {
+    ($($float:ident)*) => {
+        $(
+            impl AbsDiffEq for $float {
+                type Epsilon = $float;
+
+                fn default_epsilon(&self) -> $float {
+                    ::core::$float::EPSILON
+                }
+
+                fn abs_diff(&self, other: &$float) -> $float {
+                    // `abs()` is not available in `core`.
+                    if *self > *other {
+                        *self - *other
+                    } else {
+                        *other - *self
+                    }
+                }
+
+                fn nan_note(&self, other: &$float) -> &'static str {
+                    match (self.is_nan(), other.is_nan()) {
+                        (true, true) => ",\n    note: left and right are NaN",
+                        (true, false) => ",\n    note: left is NaN",
+                        (false, true) => ",\n    note: right is NaN",
+                        (false, false) => "",
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_abs_diff_eq_float! {f32 f64}
+
+macro_rules! impl_abs_diff_eq_int {
+    ($($int:ident => $unsigned:ident)*) => {
+        $(
+            impl AbsDiffEq for $int {
+                type Epsilon = $unsigned;
+
+                fn default_epsilon(&self) -> $unsigned {
+                    0
+                }
+
+                fn abs_diff(&self, other: &$int) -> $unsigned {
+                    // Wrapping subtraction of the two's complement representations yields the
+                    // correct difference, since it always fits in the unsigned type.
+                    if *self > *other {
+                        (*self as $unsigned).wrapping_sub(*other as $unsigned)
+                    } else {
+                        (*other as $unsigned).wrapping_sub(*self as $unsigned)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_abs_diff_eq_int! {
+    u8 => u8
+    u16 => u16
+    u32 => u32
+    u64 => u64
+    u128 => u128
+    usize => usize
+    i8 => u8
+    i16 => u16
+    i32 => u32
+    i64 => u64
+    i128 => u128
+    isize => usize
+}
+
+impl<T> AbsDiffEq for &T
+where
+    T: AbsDiffEq + ?Sized,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon(&self) -> T::Epsilon {
+        T::default_epsilon(*self)
+    }
+
+    fn abs_diff(&self, other: &Self) -> T::Epsilon {
+        T::abs_diff(*self, *other)
+    }
+
+    fn nan_note(&self, other: &Self) -> &'static str {
+        T::nan_note(*self, *other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn equal() {
+        assert_abs_diff_eq!(1.0, 1.0);
+    }
+
+    #[test]
+    fn within_default_epsilon_f64() {
+        assert_abs_diff_eq!(0.1 + 0.2, 0.3);
+    }
+
+    #[test]
+    fn within_default_epsilon_f32() {
+        assert_abs_diff_eq!(0.1f32 + 0.2, 0.3);
+    }
+
+    #[rustversion::since(1.58)]
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|left - right| <= epsilon)`\n    left: `1.0`,\n    right: `1.5`,\n    difference: `0.5`,\n    epsilon: `2.220446049250313e-16`"
+    )]
+    fn not_within_default_epsilon() {
+        assert_abs_diff_eq!(1.0, 1.5);
+    }
+
+    #[test]
+    fn equal_infinities() {
+        assert_abs_diff_eq!(core::f64::INFINITY, core::f64::INFINITY);
+        assert_abs_diff_eq!(core::f32::NEG_INFINITY, core::f32::NEG_INFINITY, 1.0);
+    }
+
+    #[test]
+    fn within_epsilon() {
+        assert_abs_diff_eq!(1.0, 1.5, 0.5);
+    }
+
+    #[test]
+    fn within_epsilon_trailing_comma() {
+        assert_abs_diff_eq!(1.0, 1.5, 0.5,);
+    }
+
+    #[test]
+    fn within_default_epsilon_trailing_comma() {
+        assert_abs_diff_eq!(1.0, 1.0,);
+    }
+
+    #[test]
+    fn within_epsilon_reversed() {
+        assert_abs_diff_eq!(1.5, 1.0, 0.5);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|left - right| <= epsilon)`\n    left: `1.0`,\n    right: `1.5`,\n    difference: `0.5`,\n    epsilon: `0.25`"
+    )]
+    fn not_within_epsilon() {
+        assert_abs_diff_eq!(1.0, 1.5, 0.25);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|left - right| <= epsilon)`\n    left: `1.0`,\n    right: `1.5`,\n    difference: `0.5`,\n    epsilon: `0.25`: foo"
+    )]
+    fn not_within_epsilon_custom_message() {
+        assert_abs_diff_eq!(1.0, 1.5, 0.25, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|left - right| <= epsilon)`\n    left: `NaN`,\n    right: `1.0`,\n    difference: `NaN`,\n    epsilon: `1.0`,\n    note: left is NaN"
+    )]
+    fn left_nan() {
+        assert_abs_diff_eq!(core::f64::NAN, 1.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|left - right| <= epsilon)`\n    left: `1.0`,\n    right: `NaN`,\n    difference: `NaN`,\n    epsilon: `1.0`,\n    note: right is NaN"
+    )]
+    fn right_nan() {
+        assert_abs_diff_eq!(1.0, core::f64::NAN, 1.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|left - right| <= epsilon)`\n    left: `NaN`,\n    right: `NaN`,\n    difference: `NaN`,\n    epsilon: `1.0`,\n    note: left and right are NaN: foo"
+    )]
+    fn both_nan_custom_message() {
+        assert_abs_diff_eq!(core::f32::NAN, core::f32::NAN, 1.0, "foo");
+    }
+
+    #[test]
+    fn integers_equal() {
+        assert_abs_diff_eq!(42, 42);
+    }
+
+    #[test]
+    fn integers_within_epsilon() {
+        assert_abs_diff_eq!(10u32, 12, 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|left - right| <= epsilon)`\n    left: `10`,\n    right: `13`,\n    difference: `3`,\n    epsilon: `2`"
+    )]
+    fn integers_not_within_epsilon() {
+        assert_abs_diff_eq!(10u32, 13, 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|left - right| <= epsilon)`\n    left: `-128`,\n    right: `127`,\n    difference: `255`,\n    epsilon: `254`"
+    )]
+    fn signed_integers_full_range() {
+        assert_abs_diff_eq!(-128i8, 127, 254);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_within_epsilon() {
+        debug_assert_abs_diff_eq!(1.0, 1.5, 0.5);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(|left - right| <= epsilon)`\n    left: `1.0`,\n    right: `1.5`,\n    difference: `0.5`,\n    epsilon: `0.25`"
+    )]
+    fn debug_not_within_epsilon() {
+        debug_assert_abs_diff_eq!(1.0, 1.5, 0.25);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(|left - right| <= epsilon)`\n    left: `1.0`,\n    right: `1.5`,\n    difference: `0.5`,\n    epsilon: `0.25`: foo"
+    )]
+    fn debug_not_within_epsilon_custom_message() {
+        debug_assert_abs_diff_eq!(1.0, 1.5, 0.25, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_within_epsilon() {
+        debug_assert_abs_diff_eq!(1.0, 1.5, 0.25);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_abs_diff_eq!(1.0, 1.05, 0.1);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_returns_unit() {
+        let () = debug_assert_abs_diff_eq!(1.0, 1.5, 0.1);
+    }
+}
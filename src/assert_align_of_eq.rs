@@ -0,0 +1,170 @@
+/// Asserts that the alignment of a type, as given by [`align_of`], is equal to the expected
+/// alignment in bytes.
+///
+/// This is useful for checking the layout of types used in FFI or with SIMD instructions, where an
+/// unexpected alignment can lead to undefined behavior.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_align_of_eq!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// #[repr(C, align(16))]
+/// struct Lanes {
+///     values: [f32; 4],
+/// }
+///
+/// assert_align_of_eq!(Lanes, 16);
+/// assert_align_of_eq!(u8, 1);
+///
+/// // With a custom message
+/// assert_align_of_eq!(Lanes, 16, "Expecting lanes to be aligned to {} bytes", 16);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_align_of_eq!(u16, 4);  // Will panic
+/// # }
+/// ```
+///
+/// [`align_of`]: https://doc.rust-lang.org/core/mem/fn.align_of.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_align_of_eq!`]: crate::debug_assert_align_of_eq!
+#[macro_export]
+macro_rules! assert_align_of_eq {
+    ($type:ty, $expected:expr $(,)?) => {
+        match (::core::mem::align_of::<$type>(), &$expected) {
+            (align, expected_val) => {
+                if align != *expected_val {
+                    ::core::panic!("assertion failed, align_of::<{}>() == {}, expected {}", ::core::stringify!($type), align, expected_val);
+                }
+            }
+        }
+    };
+    ($type:ty, $expected:expr, $($arg:tt)+) => {
+        match (::core::mem::align_of::<$type>(), &$expected) {
+            (align, expected_val) => {
+                if align != *expected_val {
+                    ::core::panic!("assertion failed, align_of::<{}>() == {}, expected {}: {}", ::core::stringify!($type), align, expected_val, ::core::format_args!($($arg)+));
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that the alignment of a type is equal to the expected alignment in bytes on debug
+/// builds.
+///
+/// This macro behaves the same as [`assert_align_of_eq!`] on debug builds. On release builds it
+/// is a no-op.
+#[macro_export]
+macro_rules! debug_assert_align_of_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_align_of_eq!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[repr(C, align(16))]
+    struct Lanes {
+        _values: [f32; 4],
+    }
+
+    #[repr(C, packed)]
+    struct Packed {
+        _tag: u8,
+        _value: u32,
+    }
+
+    #[test]
+    fn primitive() {
+        assert_align_of_eq!(u16, 2);
+    }
+
+    #[test]
+    fn unit() {
+        assert_align_of_eq!((), 1);
+    }
+
+    #[test]
+    fn array() {
+        assert_align_of_eq!([u16; 3], 2);
+    }
+
+    #[test]
+    fn over_aligned() {
+        assert_align_of_eq!(Lanes, 16);
+    }
+
+    #[test]
+    fn packed() {
+        assert_align_of_eq!(Packed, 1);
+    }
+
+    #[test]
+    fn pointer() {
+        assert_align_of_eq!(&u8, core::mem::align_of::<usize>());
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, align_of::<u16>() == 2, expected 4")]
+    fn primitive_mismatch() {
+        assert_align_of_eq!(u16, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, align_of::<Lanes>() == 16, expected 4")]
+    fn over_aligned_mismatch() {
+        assert_align_of_eq!(Lanes, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, align_of::<u16>() == 2, expected 4: foo")]
+    fn mismatch_custom_message() {
+        assert_align_of_eq!(u16, 4, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_primitive() {
+        debug_assert_align_of_eq!(u16, 2);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, align_of::<u16>() == 2, expected 4")]
+    fn debug_primitive_mismatch() {
+        debug_assert_align_of_eq!(u16, 4);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, align_of::<u16>() == 2, expected 4: foo")]
+    fn debug_mismatch_custom_message() {
+        debug_assert_align_of_eq!(u16, 4, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_primitive_mismatch() {
+        debug_assert_align_of_eq!(u16, 4);
+    }
+}
@@ -0,0 +1,273 @@
+/// Asserts that every element of an iterator satisfies the predicate.
+///
+/// Works with anything that can be iterated over by reference, such as arrays, slices, and `Vec`s,
+/// which are only borrowed, as well as with any iterator, which is consumed. The predicate is
+/// called with a reference to each element, and can either be a closure, such as `|x| x.valid`, or
+/// a named binding followed by an expression, such as `x => x.valid`. The assertion panics at the
+/// first element for which the predicate returns `false`, reporting the predicate, the element, and
+/// its index. An empty iterator passes.
+///
+/// The elements are not required to implement [`Debug`]. Elements which do not implement it are
+/// displayed as `<non-Debug>` in the panic message.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_all!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// struct Item {
+///     valid: bool,
+/// }
+///
+/// let items = vec![Item { valid: true }, Item { valid: true }];
+/// assert_all!(&items, |item| item.valid);
+/// assert_all!(&items, item => item.valid);
+///
+/// // With a custom message
+/// assert_all!(&items, |item| item.valid, "Expecting all items to be valid");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_all!(vec![2, 4, 5], |x| x % 2 == 0);  // Will panic
+/// # }
+/// ```
+///
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_all!`]: crate::debug_assert_all!
+#[macro_export]
+macro_rules! assert_all {
+    ($collection:expr, $binding:ident => $predicate:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::__private::{BorrowCollection as _, IsDebug as _, IsNotDebug as _, TakeIterator as _, ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+        if let ::core::option::Option::Some((index, element)) = (&&&&&$collection.wrap_collection()).view().first_failing(|$binding| $predicate) {
+            ::core::panic!(r#"assertion failed, expected all elements to satisfy predicate
+    predicate: `{} => {}`,
+    index: `{}`,
+    element: `{:?}`"#, ::core::stringify!($binding), ::core::stringify!($predicate), index, (&$crate::__private::MaybeDebug(&element)).maybe_debug())
+        }
+    }};
+    ($collection:expr, $binding:ident => $predicate:expr, $($arg:tt)+) => {{
+        #[allow(unused_imports)]
+        use $crate::__private::{BorrowCollection as _, IsDebug as _, IsNotDebug as _, TakeIterator as _, ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+        if let ::core::option::Option::Some((index, element)) = (&&&&&$collection.wrap_collection()).view().first_failing(|$binding| $predicate) {
+            ::core::panic!(r#"assertion failed, expected all elements to satisfy predicate
+    predicate: `{} => {}`,
+    index: `{}`,
+    element: `{:?}`: {}"#, ::core::stringify!($binding), ::core::stringify!($predicate), index, (&$crate::__private::MaybeDebug(&element)).maybe_debug(), ::core::format_args!($($arg)+))
+        }
+    }};
+    ($collection:expr, $predicate:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::__private::{BorrowCollection as _, IsDebug as _, IsNotDebug as _, TakeIterator as _, ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+        if let ::core::option::Option::Some((index, element)) = (&&&&&$collection.wrap_collection()).view().first_failing($predicate) {
+            ::core::panic!(r#"assertion failed, expected all elements to satisfy predicate
+    predicate: `{}`,
+    index: `{}`,
+    element: `{:?}`"#, ::core::stringify!($predicate), index, (&$crate::__private::MaybeDebug(&element)).maybe_debug())
+        }
+    }};
+    ($collection:expr, $predicate:expr, $($arg:tt)+) => {{
+        #[allow(unused_imports)]
+        use $crate::__private::{BorrowCollection as _, IsDebug as _, IsNotDebug as _, TakeIterator as _, ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+        if let ::core::option::Option::Some((index, element)) = (&&&&&$collection.wrap_collection()).view().first_failing($predicate) {
+            ::core::panic!(r#"assertion failed, expected all elements to satisfy predicate
+    predicate: `{}`,
+    index: `{}`,
+    element: `{:?}`: {}"#, ::core::stringify!($predicate), index, (&$crate::__private::MaybeDebug(&element)).maybe_debug(), ::core::format_args!($($arg)+))
+        }
+    }};
+}
+
+/// Asserts that every element of an iterator satisfies the predicate on debug builds.
+///
+/// This macro behaves the same as [`assert_all!`] on debug builds. On release builds it is a
+/// no-op.
+#[macro_export]
+macro_rules! debug_assert_all {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_all!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, Cursor},
+        vec,
+        vec::Vec,
+    };
+
+    #[derive(Debug)]
+    struct Item {
+        valid: bool,
+    }
+
+    struct NonDebugItem {
+        valid: bool,
+    }
+
+    #[test]
+    fn all_satisfy() {
+        let items = vec![Item { valid: true }, Item { valid: true }];
+        assert_all!(&items, |item| item.valid);
+    }
+
+    #[test]
+    fn all_satisfy_binding() {
+        let items = vec![Item { valid: true }, Item { valid: true }];
+        assert_all!(&items, item => item.valid);
+    }
+
+    #[test]
+    fn owned_elements() {
+        assert_all!(vec![2, 4, 6], |x| x % 2 == 0);
+    }
+
+    #[test]
+    fn not_moved() {
+        let values = vec![2, 4, 6];
+        assert_all!(values, |x| x % 2 == 0);
+        assert_eq!(values.len(), 3);
+    }
+
+    #[test]
+    fn iterator() {
+        assert_all!((0..10).map(|x| x * 2), x => x % 2 == 0);
+    }
+
+    #[test]
+    fn non_clone_iterator() {
+        let reader = Cursor::new("loading\nstarted\nready");
+        assert_all!(reader.lines(), |line| line.is_ok());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected all elements to satisfy predicate\n    predicate: `line => line.as_ref().unwrap() != \"started\"`,\n    index: `1`,\n    element: `Ok(\"started\")`"
+    )]
+    fn non_clone_iterator_not_all_satisfy() {
+        let reader = Cursor::new("loading\nstarted\nready");
+        assert_all!(reader.lines(), line => line.as_ref().unwrap() != "started");
+    }
+
+    #[test]
+    fn empty() {
+        let items: Vec<Item> = Vec::new();
+        assert_all!(&items, |item| item.valid);
+    }
+
+    #[test]
+    fn function_predicate() {
+        fn is_even(x: &u32) -> bool {
+            *x % 2 == 0
+        }
+        assert_all!(&[2, 4, 6], is_even);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected all elements to satisfy predicate\n    predicate: `x => check(x)`,\n    index: `1`,\n    element: `2`"
+    )]
+    fn stops_at_first_failure() {
+        let check = |x: &u32| {
+            assert_ne!(*x, 3, "predicate called after the first failure");
+            *x < 2
+        };
+        assert_all!(&[1, 2, 3], x => check(x));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected all elements to satisfy predicate\n    predicate: `is_valid`,\n    index: `1`,\n    element: `Item { valid: false }`"
+    )]
+    fn not_all_satisfy() {
+        let is_valid = |item: &Item| item.valid;
+        let items = vec![Item { valid: true }, Item { valid: false }];
+        assert_all!(&items, is_valid);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected all elements to satisfy predicate\n    predicate: `item => item.valid`,\n    index: `0`,\n    element: `Item { valid: false }`"
+    )]
+    fn not_all_satisfy_binding() {
+        let items = vec![Item { valid: false }, Item { valid: true }];
+        assert_all!(&items, item => item.valid);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected all elements to satisfy predicate\n    predicate: `item => item.valid`,\n    index: `0`,\n    element: `<non-Debug>`"
+    )]
+    fn not_all_satisfy_non_debug() {
+        let items = vec![NonDebugItem { valid: false }];
+        assert_all!(&items, item => item.valid);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected all elements to satisfy predicate\n    predicate: `is_valid`,\n    index: `1`,\n    element: `Item { valid: false }`: foo"
+    )]
+    fn not_all_satisfy_custom_message() {
+        let is_valid = |item: &Item| item.valid;
+        let items = vec![Item { valid: true }, Item { valid: false }];
+        assert_all!(&items, is_valid, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected all elements to satisfy predicate\n    predicate: `item => item.valid`,\n    index: `1`,\n    element: `Item { valid: false }`: foo"
+    )]
+    fn not_all_satisfy_binding_custom_message() {
+        let items = vec![Item { valid: true }, Item { valid: false }];
+        assert_all!(&items, item => item.valid, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_all_satisfy() {
+        debug_assert_all!(vec![2, 4, 6], |x| x % 2 == 0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected all elements to satisfy predicate\n    predicate: `x => x % 2 == 0`,\n    index: `2`,\n    element: `5`"
+    )]
+    fn debug_not_all_satisfy() {
+        debug_assert_all!(vec![2, 4, 5], x => x % 2 == 0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected all elements to satisfy predicate\n    predicate: `x => x % 2 == 0`,\n    index: `2`,\n    element: `5`: foo"
+    )]
+    fn debug_not_all_satisfy_custom_message() {
+        debug_assert_all!(vec![2, 4, 5], x => x % 2 == 0, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_all_satisfy() {
+        debug_assert_all!(vec![2, 4, 5], x => x % 2 == 0);
+    }
+}
@@ -0,0 +1,166 @@
+/// Asserts that every floating point value produced by the expression is finite.
+///
+/// The expression can be anything implementing [`IntoIterator`] over [`f32`] or [`f64`] values or
+/// references to them, such as a reference to an array or slice. A value is finite if it is
+/// neither `NaN` nor positive or negative infinity. The assertion panics at the first value which
+/// is not finite, reporting its index. An empty iterator passes.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_all_finite!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let values = [1.0, 2.5, -3.0];
+/// assert_all_finite!(&values);
+///
+/// // With a custom message
+/// assert_all_finite!(&values, "Expecting all of {:?} to be finite", values);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let values = [1.0, 0.0 / 0.0, -3.0];
+/// assert_all_finite!(&values);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`f32`]: https://doc.rust-lang.org/core/primitive.f32.html
+/// [`f64`]: https://doc.rust-lang.org/core/primitive.f64.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_all_finite!`]: crate::debug_assert_all_finite!
+#[macro_export]
+macro_rules! assert_all_finite {
+    ($values:expr $(,)?) => {
+        for (index, value) in ::core::iter::Iterator::enumerate(::core::iter::IntoIterator::into_iter($values)) {
+            if !$crate::__private::Finite::is_finite(&value) {
+                ::core::panic!("assertion failed, expected all values to be finite, got {:?} at index {}", value, index);
+            }
+        }
+    };
+    ($values:expr, $($arg:tt)+) => {
+        for (index, value) in ::core::iter::Iterator::enumerate(::core::iter::IntoIterator::into_iter($values)) {
+            if !$crate::__private::Finite::is_finite(&value) {
+                ::core::panic!("assertion failed, expected all values to be finite, got {:?} at index {}: {}", value, index, ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that every floating point value produced by the expression is finite on debug builds.
+///
+/// This macro behaves the same as [`assert_all_finite!`] on debug builds. On release builds it is a
+/// no-op.
+#[macro_export]
+macro_rules! debug_assert_all_finite {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_all_finite!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn all_finite() {
+        assert_all_finite!(&[1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn all_finite_slice() {
+        let values: &[f32] = &[1.0, core::f32::MIN_POSITIVE / 2.0];
+        assert_all_finite!(values);
+    }
+
+    #[test]
+    fn all_finite_by_value() {
+        assert_all_finite!([1.0, 2.0, 3.0].iter().map(|value| value * 2.0));
+    }
+
+    #[test]
+    fn empty() {
+        let values: [f64; 0] = [];
+        assert_all_finite!(&values);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected all values to be finite, got NaN at index 0"
+    )]
+    fn nan_first() {
+        assert_all_finite!(&[core::f64::NAN, 2.0, 3.0]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected all values to be finite, got inf at index 1"
+    )]
+    fn infinity_middle() {
+        assert_all_finite!(&[1.0, core::f64::INFINITY, core::f64::NAN]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected all values to be finite, got -inf at index 2: foo"
+    )]
+    fn negative_infinity_custom_message() {
+        assert_all_finite!(&[1.0, 2.0, core::f64::NEG_INFINITY], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_all_finite() {
+        debug_assert_all_finite!(&[1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected all values to be finite, got NaN at index 0"
+    )]
+    fn debug_nan_first() {
+        debug_assert_all_finite!(&[core::f64::NAN, 2.0, 3.0]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected all values to be finite, got NaN at index 0: foo"
+    )]
+    fn debug_nan_first_custom_message() {
+        debug_assert_all_finite!(&[core::f64::NAN, 2.0, 3.0], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_nan_first() {
+        debug_assert_all_finite!(&[core::f64::NAN, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_all_finite!(&[1.0, 2.0]);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_returns_unit() {
+        let () = debug_assert_all_finite!(&[core::f64::NAN]);
+    }
+}
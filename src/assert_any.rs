@@ -0,0 +1,292 @@
+/// Asserts that at least one element of an iterator satisfies the predicate.
+///
+/// Works with anything that can be iterated over by reference, such as arrays, slices, and `Vec`s,
+/// which are only borrowed, as well as with any iterator, such as `str::lines()` or
+/// `BufRead::lines()`, which is consumed. The predicate is called with a reference to each element,
+/// and can either be a closure, such as `|x| x.valid`, or a named binding followed by an
+/// expression, such as `x => x.valid`. The assertion passes as soon as the predicate returns `true`
+/// for an element.
+///
+/// If no element satisfies the predicate, the number of elements examined and the elements
+/// themselves are printed. Only the first few elements are shown, followed by a count of the
+/// elements that were left out. An empty iterator fails with a dedicated message.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_any!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let log = "loading\nstarted\nready";
+/// assert_any!(log.lines(), |line| line.contains("started"));
+/// assert_any!(log.lines(), line => line.contains("started"));
+///
+/// // With a custom message
+/// assert_any!(log.lines(), |line| line.contains("started"), "Expecting the server to start");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_any!(&[1, 3, 5], |x| *x % 2 == 0);  // Will panic
+/// # }
+/// ```
+///
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_any!`]: crate::debug_assert_any!
+#[macro_export]
+macro_rules! assert_any {
+    ($collection:expr, $binding:ident => $predicate:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::__private::{BorrowCollection as _, TakeIterator as _, ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+        match (&&&&&$collection.wrap_collection()).view().any_matching(|$binding| $predicate) {
+            ::core::result::Result::Ok(()) => {}
+            ::core::result::Result::Err($crate::__private::NoMatch::Empty) => {
+                ::core::panic!(r#"assertion failed, expected any element to satisfy predicate, but the iterator was empty
+    predicate: `{} => {}`"#, ::core::stringify!($binding), ::core::stringify!($predicate))
+            }
+            ::core::result::Result::Err($crate::__private::NoMatch::Mismatch { examined, elements }) => {
+                ::core::panic!(r#"assertion failed, expected any element to satisfy predicate
+    predicate: `{} => {}`,
+    examined: `{}`,
+    elements: `{:?}`"#, ::core::stringify!($binding), ::core::stringify!($predicate), examined, elements)
+            }
+        }
+    }};
+    ($collection:expr, $binding:ident => $predicate:expr, $($arg:tt)+) => {{
+        #[allow(unused_imports)]
+        use $crate::__private::{BorrowCollection as _, TakeIterator as _, ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+        match (&&&&&$collection.wrap_collection()).view().any_matching(|$binding| $predicate) {
+            ::core::result::Result::Ok(()) => {}
+            ::core::result::Result::Err($crate::__private::NoMatch::Empty) => {
+                ::core::panic!(r#"assertion failed, expected any element to satisfy predicate, but the iterator was empty
+    predicate: `{} => {}`: {}"#, ::core::stringify!($binding), ::core::stringify!($predicate), ::core::format_args!($($arg)+))
+            }
+            ::core::result::Result::Err($crate::__private::NoMatch::Mismatch { examined, elements }) => {
+                ::core::panic!(r#"assertion failed, expected any element to satisfy predicate
+    predicate: `{} => {}`,
+    examined: `{}`,
+    elements: `{:?}`: {}"#, ::core::stringify!($binding), ::core::stringify!($predicate), examined, elements, ::core::format_args!($($arg)+))
+            }
+        }
+    }};
+    ($collection:expr, $predicate:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::__private::{BorrowCollection as _, TakeIterator as _, ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+        match (&&&&&$collection.wrap_collection()).view().any_matching($predicate) {
+            ::core::result::Result::Ok(()) => {}
+            ::core::result::Result::Err($crate::__private::NoMatch::Empty) => {
+                ::core::panic!(r#"assertion failed, expected any element to satisfy predicate, but the iterator was empty
+    predicate: `{}`"#, ::core::stringify!($predicate))
+            }
+            ::core::result::Result::Err($crate::__private::NoMatch::Mismatch { examined, elements }) => {
+                ::core::panic!(r#"assertion failed, expected any element to satisfy predicate
+    predicate: `{}`,
+    examined: `{}`,
+    elements: `{:?}`"#, ::core::stringify!($predicate), examined, elements)
+            }
+        }
+    }};
+    ($collection:expr, $predicate:expr, $($arg:tt)+) => {{
+        #[allow(unused_imports)]
+        use $crate::__private::{BorrowCollection as _, TakeIterator as _, ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+        match (&&&&&$collection.wrap_collection()).view().any_matching($predicate) {
+            ::core::result::Result::Ok(()) => {}
+            ::core::result::Result::Err($crate::__private::NoMatch::Empty) => {
+                ::core::panic!(r#"assertion failed, expected any element to satisfy predicate, but the iterator was empty
+    predicate: `{}`: {}"#, ::core::stringify!($predicate), ::core::format_args!($($arg)+))
+            }
+            ::core::result::Result::Err($crate::__private::NoMatch::Mismatch { examined, elements }) => {
+                ::core::panic!(r#"assertion failed, expected any element to satisfy predicate
+    predicate: `{}`,
+    examined: `{}`,
+    elements: `{:?}`: {}"#, ::core::stringify!($predicate), examined, elements, ::core::format_args!($($arg)+))
+            }
+        }
+    }};
+}
+
+/// Asserts that at least one element of an iterator satisfies the predicate on debug builds.
+///
+/// This macro behaves the same as [`assert_any!`] on debug builds. On release builds it is a
+/// no-op.
+#[macro_export]
+macro_rules! debug_assert_any {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_any!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, Cursor},
+        vec,
+        vec::Vec,
+    };
+
+    #[test]
+    fn any_satisfy() {
+        assert_any!(&[1, 2, 3], |x| *x == 2);
+    }
+
+    #[test]
+    fn any_satisfy_binding() {
+        assert_any!(&[1, 2, 3], x => *x == 2);
+    }
+
+    #[test]
+    fn lines() {
+        let log = "loading\nstarted\nready";
+        assert_any!(log.lines(), |line| line.contains("started"));
+    }
+
+    #[test]
+    fn non_clone_iterator() {
+        let reader = Cursor::new("loading\nstarted\nready");
+        assert_any!(reader.lines(), |line| line.as_ref().unwrap() == "started");
+    }
+
+    #[test]
+    fn iterator_by_mutable_reference() {
+        let mut iter = 1..5;
+        assert_any!(&mut iter, |x| *x == 2);
+        assert_eq!(iter.next(), Some(3));
+    }
+
+    #[test]
+    fn not_moved() {
+        let values = vec![1, 2, 3];
+        assert_any!(values, |x| *x == 2);
+        assert_eq!(values.len(), 3);
+    }
+
+    #[test]
+    fn owned_elements() {
+        assert_any!(vec![1, 2, 3], |x| x % 2 == 0);
+    }
+
+    #[test]
+    fn stops_at_first_match() {
+        let check = |x: &u32| {
+            assert_ne!(*x, 3, "predicate called after the first match");
+            *x == 2
+        };
+        assert_any!(&[1, 2, 3], x => check(x));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected any element to satisfy predicate\n    predicate: `is_even`,\n    examined: `3`,\n    elements: `[1, 3, 5]`"
+    )]
+    fn none_satisfy() {
+        let is_even = |x: &u32| *x % 2 == 0;
+        assert_any!(&[1, 3, 5], is_even);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected any element to satisfy predicate\n    predicate: `x => x % 2 == 0`,\n    examined: `3`,\n    elements: `[1, 3, 5]`"
+    )]
+    fn none_satisfy_binding() {
+        assert_any!(vec![1, 3, 5], x => x % 2 == 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected any element to satisfy predicate\n    predicate: `x => *x > 100`,\n    examined: `20`,\n    elements: `[0, 1, 2, ... (17 more)]`"
+    )]
+    fn none_satisfy_truncated() {
+        assert_any!(0..20, x => *x > 100);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected any element to satisfy predicate\n    predicate: `line => line.as_ref().unwrap() == \"started\"`,\n    examined: `2`,\n    elements: `[Ok(\"loading\"), Ok(\"ready\")]`"
+    )]
+    fn non_clone_iterator_none_satisfy() {
+        let reader = Cursor::new("loading\nready");
+        assert_any!(reader.lines(), line => line.as_ref().unwrap() == "started");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected any element to satisfy predicate, but the iterator was empty\n    predicate: `is_even`"
+    )]
+    fn empty() {
+        let is_even = |x: &u32| *x % 2 == 0;
+        let values: Vec<u32> = Vec::new();
+        assert_any!(&values, is_even);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected any element to satisfy predicate, but the iterator was empty\n    predicate: `x => x % 2 == 0`"
+    )]
+    fn empty_binding() {
+        let values: Vec<u32> = Vec::new();
+        assert_any!(values, x => x % 2 == 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected any element to satisfy predicate\n    predicate: `is_even`,\n    examined: `3`,\n    elements: `[1, 3, 5]`: foo"
+    )]
+    fn none_satisfy_custom_message() {
+        let is_even = |x: &u32| *x % 2 == 0;
+        assert_any!(&[1, 3, 5], is_even, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected any element to satisfy predicate, but the iterator was empty\n    predicate: `x => x % 2 == 0`: foo"
+    )]
+    fn empty_custom_message() {
+        let values: Vec<u32> = Vec::new();
+        assert_any!(values, x => x % 2 == 0, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_any_satisfy() {
+        debug_assert_any!(vec![1, 2, 3], |x| x % 2 == 0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected any element to satisfy predicate\n    predicate: `x => x % 2 == 0`,\n    examined: `3`,\n    elements: `[1, 3, 5]`"
+    )]
+    fn debug_none_satisfy() {
+        debug_assert_any!(vec![1, 3, 5], x => x % 2 == 0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected any element to satisfy predicate\n    predicate: `x => x % 2 == 0`,\n    examined: `3`,\n    elements: `[1, 3, 5]`: foo"
+    )]
+    fn debug_none_satisfy_custom_message() {
+        debug_assert_any!(vec![1, 3, 5], x => x % 2 == 0, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_none_satisfy() {
+        debug_assert_any!(vec![1, 3, 5], x => x % 2 == 0);
+    }
+}
@@ -0,0 +1,180 @@
+/// Asserts that all bits in the mask are clear in the value, returning the value.
+///
+/// Works with all primitive unsigned integer types. The assertion passes if `value & mask == 0`. On
+/// failure, the bits of the mask which are set in the value are printed in both binary and
+/// hexadecimal alongside the value and the mask, making the offending bits obvious. See
+/// [`assert_bits_set!`] for the opposite check.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_bits_clear!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let flags: u8 = 0b1001;
+/// assert_bits_clear!(flags, 0b0110);
+///
+/// // With a custom message
+/// assert_bits_clear!(flags, 0b0110, "Expecting {:#b} to have bits {:#b} clear", flags, 0b0110);
+/// # }
+/// ```
+///
+/// The value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let value = assert_bits_clear!(0b1001u8, 0b0110);
+/// assert_eq!(value, 0b1001);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_bits_clear!(0b1011u8, 0b0110);  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_bits_set!`]: crate::assert_bits_set!
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_bits_clear!`]: crate::debug_assert_bits_clear!
+#[macro_export]
+macro_rules! assert_bits_clear {
+    ($value:expr, $mask:expr $(,)?) => {
+        match ($value, $mask) {
+            (value, mask) => {
+                let differing = $crate::__private::Bits::unexpected_bits(&value, &mask);
+                if !$crate::__private::Bits::is_zero(&differing) {
+                    ::core::panic!(r#"assertion failed: `(value & mask == 0)`
+    value: `{:?}` ({:?}),
+    mask: `{:?}` ({:?}),
+    differing: `{:?}` ({:?})"#, $crate::__private::Bin(&value), $crate::__private::Hex(&value), $crate::__private::Bin(&mask), $crate::__private::Hex(&mask), $crate::__private::Bin(&differing), $crate::__private::Hex(&differing));
+                }
+                value
+            }
+        }
+    };
+    ($value:expr, $mask:expr, $($arg:tt)+) => {
+        match ($value, $mask) {
+            (value, mask) => {
+                let differing = $crate::__private::Bits::unexpected_bits(&value, &mask);
+                if !$crate::__private::Bits::is_zero(&differing) {
+                    ::core::panic!(r#"assertion failed: `(value & mask == 0)`
+    value: `{:?}` ({:?}),
+    mask: `{:?}` ({:?}),
+    differing: `{:?}` ({:?}): {}"#, $crate::__private::Bin(&value), $crate::__private::Hex(&value), $crate::__private::Bin(&mask), $crate::__private::Hex(&mask), $crate::__private::Bin(&differing), $crate::__private::Hex(&differing), ::core::format_args!($($arg)+));
+                }
+                value
+            }
+        }
+    };
+}
+
+/// Asserts that all bits in the mask are clear in the value on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_bits_clear!`] on debug builds, although it does
+/// not return the value. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_bits_clear {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_bits_clear!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn bits_clear_u8() {
+        assert_bits_clear!(0b1001u8, 0b0110);
+    }
+
+    #[test]
+    fn bits_clear_u64() {
+        assert_bits_clear!(0u64, 1 << 63);
+    }
+
+    #[test]
+    fn empty_mask() {
+        assert_bits_clear!(0b1010u8, 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(value & mask == 0)`\n    value: `0b00001011` (0x0b),\n    mask: `0b00000110` (0x06),\n    differing: `0b00000010` (0x02)"
+    )]
+    fn not_bits_clear_u8() {
+        assert_bits_clear!(0b1011u8, 0b0110);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(value & mask == 0)`\n    value: `0b0000000000000000000000010000000000000000000000000000000000000000` (0x0000010000000000),\n    mask: `0b0000000000000000000000010000000000000000000000000000000000000000` (0x0000010000000000),\n    differing: `0b0000000000000000000000010000000000000000000000000000000000000000` (0x0000010000000000)"
+    )]
+    fn not_bits_clear_u64() {
+        assert_bits_clear!(1u64 << 40, 1 << 40);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(value & mask == 0)`\n    value: `0b00001011` (0x0b),\n    mask: `0b00000110` (0x06),\n    differing: `0b00000010` (0x02): foo"
+    )]
+    fn not_bits_clear_custom_message() {
+        assert_bits_clear!(0b1011u8, 0b0110, "foo");
+    }
+
+    #[test]
+    fn value_returned() {
+        let value = assert_bits_clear!(0b1001u8, 0b0110);
+        assert_eq!(value, 0b1001u8);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_bits_clear() {
+        debug_assert_bits_clear!(0b1001u8, 0b0110);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(value & mask == 0)`\n    value: `0b00001011` (0x0b),\n    mask: `0b00000110` (0x06),\n    differing: `0b00000010` (0x02)"
+    )]
+    fn debug_not_bits_clear() {
+        debug_assert_bits_clear!(0b1011u8, 0b0110);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(value & mask == 0)`\n    value: `0b00001011` (0x0b),\n    mask: `0b00000110` (0x06),\n    differing: `0b00000010` (0x02): foo"
+    )]
+    fn debug_not_bits_clear_custom_message() {
+        debug_assert_bits_clear!(0b1011u8, 0b0110, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_bits_clear() {
+        debug_assert_bits_clear!(0b1011u8, 0b0110);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_bits_clear!(0b1001u8, 0b0110);
+    }
+}
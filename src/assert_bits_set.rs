@@ -0,0 +1,218 @@
+use crate::assert_eq_hex::Radix;
+
+/// Asserts that all bits in the mask are set in the value, returning the value.
+///
+/// Works with all primitive unsigned integer types. The assertion passes if `value & mask == mask`.
+/// On failure, the bits of the mask which are not set in the value are printed in both binary and
+/// hexadecimal alongside the value and the mask, making the offending bits obvious. See
+/// [`assert_bits_clear!`] for the opposite check.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_bits_set!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let flags: u8 = 0b1110;
+/// assert_bits_set!(flags, 0b0110);
+///
+/// // With a custom message
+/// assert_bits_set!(flags, 0b0110, "Expecting {:#b} to have bits {:#b} set", flags, 0b0110);
+/// # }
+/// ```
+///
+/// The value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let value = assert_bits_set!(0b1110u8, 0b0110);
+/// assert_eq!(value, 0b1110);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_bits_set!(0b1010u8, 0b0110);  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_bits_clear!`]: crate::assert_bits_clear!
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_bits_set!`]: crate::debug_assert_bits_set!
+#[macro_export]
+macro_rules! assert_bits_set {
+    ($value:expr, $mask:expr $(,)?) => {
+        match ($value, $mask) {
+            (value, mask) => {
+                let differing = $crate::__private::Bits::missing_bits(&value, &mask);
+                if !$crate::__private::Bits::is_zero(&differing) {
+                    ::core::panic!(r#"assertion failed: `(value & mask == mask)`
+    value: `{:?}` ({:?}),
+    mask: `{:?}` ({:?}),
+    differing: `{:?}` ({:?})"#, $crate::__private::Bin(&value), $crate::__private::Hex(&value), $crate::__private::Bin(&mask), $crate::__private::Hex(&mask), $crate::__private::Bin(&differing), $crate::__private::Hex(&differing));
+                }
+                value
+            }
+        }
+    };
+    ($value:expr, $mask:expr, $($arg:tt)+) => {
+        match ($value, $mask) {
+            (value, mask) => {
+                let differing = $crate::__private::Bits::missing_bits(&value, &mask);
+                if !$crate::__private::Bits::is_zero(&differing) {
+                    ::core::panic!(r#"assertion failed: `(value & mask == mask)`
+    value: `{:?}` ({:?}),
+    mask: `{:?}` ({:?}),
+    differing: `{:?}` ({:?}): {}"#, $crate::__private::Bin(&value), $crate::__private::Hex(&value), $crate::__private::Bin(&mask), $crate::__private::Hex(&mask), $crate::__private::Bin(&differing), $crate::__private::Hex(&differing), ::core::format_args!($($arg)+));
+                }
+                value
+            }
+        }
+    };
+}
+
+/// Asserts that all bits in the mask are set in the value on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_bits_set!`] on debug builds, although it does not
+/// return the value. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_bits_set {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_bits_set!($($arg)*);
+        }
+    }};
+}
+
+/// Unsigned integer types whose bits can be checked against a mask.
+///
+/// This is an implementation detail of [`assert_bits_set!`] and [`assert_bits_clear!`] and is not
+/// part of the public API.
+#[doc(hidden)]
+pub trait Bits: Radix + Sized {
+    /// Returns the bits of `mask` which are not set in `self`.
+    fn missing_bits(&self, mask: &Self) -> Self;
+
+    /// Returns the bits of `mask` which are set in `self`.
+    fn unexpected_bits(&self, mask: &Self) -> Self;
+
+    fn is_zero(&self) -> bool;
+}
+
+macro_rules! impl_bits {
+    ($($ty:ident)*) => {
+        $(
+            impl Bits for $ty {
+                fn missing_bits(&self, mask: &$ty) -> $ty {
+                    mask & !self
+                }
+
+                fn unexpected_bits(&self, mask: &$ty) -> $ty {
+                    self & mask
+                }
+
+                fn is_zero(&self) -> bool {
+                    *self == 0
+                }
+            }
+        )*
+    };
+}
+
+impl_bits! {u8 u16 u32 u64 u128 usize}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn bits_set_u8() {
+        assert_bits_set!(0b0111u8, 0b0110);
+    }
+
+    #[test]
+    fn bits_set_u64() {
+        assert_bits_set!(u64::max_value(), 1 << 63);
+    }
+
+    #[test]
+    fn empty_mask() {
+        assert_bits_set!(0b1010u8, 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(value & mask == mask)`\n    value: `0b00000010` (0x02),\n    mask: `0b00000110` (0x06),\n    differing: `0b00000100` (0x04)"
+    )]
+    fn not_bits_set_u8() {
+        assert_bits_set!(0b0010u8, 0b0110);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(value & mask == mask)`\n    value: `0b0000000000000000000000000000000000000000000000000000000000000000` (0x0000000000000000),\n    mask: `0b0000000000000000000000010000000000000000000000000000000000000000` (0x0000010000000000),\n    differing: `0b0000000000000000000000010000000000000000000000000000000000000000` (0x0000010000000000)"
+    )]
+    fn not_bits_set_u64() {
+        assert_bits_set!(0u64, 1 << 40);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(value & mask == mask)`\n    value: `0b00000010` (0x02),\n    mask: `0b00000110` (0x06),\n    differing: `0b00000100` (0x04): foo"
+    )]
+    fn not_bits_set_custom_message() {
+        assert_bits_set!(0b0010u8, 0b0110, "foo");
+    }
+
+    #[test]
+    fn value_returned() {
+        let value = assert_bits_set!(0b0111u8, 0b0110);
+        assert_eq!(value, 0b0111u8);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_bits_set() {
+        debug_assert_bits_set!(0b0111u8, 0b0110);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(value & mask == mask)`\n    value: `0b00000010` (0x02),\n    mask: `0b00000110` (0x06),\n    differing: `0b00000100` (0x04)"
+    )]
+    fn debug_not_bits_set() {
+        debug_assert_bits_set!(0b0010u8, 0b0110);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(value & mask == mask)`\n    value: `0b00000010` (0x02),\n    mask: `0b00000110` (0x06),\n    differing: `0b00000100` (0x04): foo"
+    )]
+    fn debug_not_bits_set_custom_message() {
+        debug_assert_bits_set!(0b0010u8, 0b0110, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_bits_set() {
+        debug_assert_bits_set!(0b0010u8, 0b0110);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_bits_set!(0b0111u8, 0b0110);
+    }
+}
@@ -0,0 +1,291 @@
+use core::fmt;
+
+/// The number of bytes printed on each row of the hexdump.
+const ROW_LEN: usize = 8;
+
+/// The number of rows printed before and after the row containing the first difference.
+const CONTEXT_ROWS: usize = 2;
+
+/// Asserts that two byte sequences are equal, printing a hexdump of where they differ on failure.
+///
+/// Both expressions can be anything implementing [`AsRef`]`<[u8]>`, such as `&[u8]`,
+/// `Vec<u8>`, or byte arrays, and the two sides do not need to be of the same type.
+///
+/// On failure, the offset of the first differing byte is printed, followed by a side-by-side
+/// hexdump of the actual and expected bytes around that offset: up to 16 bytes before and after
+/// the row containing the difference. Offsets are printed in hexadecimal, and each differing byte
+/// is followed by a `*`. Bytes past the end of the shorter sequence are printed as `--`, and a
+/// note is included stating both lengths when they differ.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_bytes_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let encoded: Vec<u8> = vec![0xde, 0xad, 0xbe, 0xef];
+///
+/// assert_bytes_eq!(encoded, [0xde, 0xad, 0xbe, 0xef]);
+///
+/// // With a custom message
+/// assert_bytes_eq!(encoded, b"\xde\xad\xbe\xef", "Expecting a {}-byte frame", 4);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_bytes_eq!(b"\xde\xad\xbe\xef", [0xde, 0xad, 0xbe, 0xee]);  // Will panic
+/// # }
+/// ```
+///
+/// [`AsRef`]: https://doc.rust-lang.org/core/convert/trait.AsRef.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_bytes_eq!`]: crate::debug_assert_bytes_eq!
+#[macro_export]
+macro_rules! assert_bytes_eq {
+    ($actual:expr, $expected:expr $(,)?) => {
+        match (&$actual, &$expected) {
+            (actual_val, expected_val) => {
+                if let ::core::option::Option::Some(diff) = $crate::__private::bytes_diff(
+                    ::core::convert::AsRef::<[u8]>::as_ref(actual_val),
+                    ::core::convert::AsRef::<[u8]>::as_ref(expected_val),
+                ) {
+                    ::core::panic!(r#"assertion failed, expected bytes to be equal
+{}"#, diff)
+                }
+            }
+        }
+    };
+    ($actual:expr, $expected:expr, $($arg:tt)+) => {
+        match (&$actual, &$expected) {
+            (actual_val, expected_val) => {
+                if let ::core::option::Option::Some(diff) = $crate::__private::bytes_diff(
+                    ::core::convert::AsRef::<[u8]>::as_ref(actual_val),
+                    ::core::convert::AsRef::<[u8]>::as_ref(expected_val),
+                ) {
+                    ::core::panic!(r#"assertion failed, expected bytes to be equal
+{}: {}"#, diff, ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two byte sequences are equal, printing a hexdump of where they differ on failure,
+/// on debug builds.
+///
+/// This macro behaves the same as [`assert_bytes_eq!`] on debug builds. On release builds it is a
+/// no-op.
+#[macro_export]
+macro_rules! debug_assert_bytes_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_bytes_eq!($($arg)*);
+        }
+    }};
+}
+
+/// The differences between two byte sequences, formatted as a hexdump of the window around the
+/// first difference.
+#[doc(hidden)]
+pub struct BytesDiff<'a> {
+    actual: &'a [u8],
+    expected: &'a [u8],
+    offset: usize,
+}
+
+impl BytesDiff<'_> {
+    fn fmt_cell(
+        formatter: &mut fmt::Formatter,
+        byte: Option<&u8>,
+        other: Option<&u8>,
+    ) -> fmt::Result {
+        match byte {
+            Some(byte) => write!(formatter, "{:02x}", byte)?,
+            None => formatter.write_str("--")?,
+        }
+        if byte != other {
+            formatter.write_str("*")
+        } else {
+            formatter.write_str(" ")
+        }
+    }
+}
+
+impl fmt::Display for BytesDiff<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "    first difference at offset: `{:#x}`,",
+            self.offset
+        )?;
+        if self.actual.len() != self.expected.len() {
+            write!(
+                formatter,
+                "\n    note: lengths differ, actual is `{}` bytes and expected is `{}` bytes,",
+                self.actual.len(),
+                self.expected.len()
+            )?;
+        }
+        formatter.write_str("\n    hexdump (actual | expected):")?;
+
+        let len = core::cmp::max(self.actual.len(), self.expected.len());
+        let row = self.offset / ROW_LEN;
+        let rows = (len + ROW_LEN - 1) / ROW_LEN;
+        for row in row.saturating_sub(CONTEXT_ROWS)..core::cmp::min(row + CONTEXT_ROWS + 1, rows) {
+            let start = row * ROW_LEN;
+            let end = core::cmp::min(start + ROW_LEN, len);
+            write!(formatter, "\n    {:08x}  ", start)?;
+            for index in start..start + ROW_LEN {
+                if index < end {
+                    Self::fmt_cell(formatter, self.actual.get(index), self.expected.get(index))?;
+                } else {
+                    formatter.write_str("   ")?;
+                }
+            }
+            formatter.write_str("| ")?;
+            for index in start..end {
+                let byte = self.expected.get(index);
+                let other = self.actual.get(index);
+                if index + 1 == end && byte == other {
+                    // Avoid trailing whitespace at the end of the row.
+                    match byte {
+                        Some(byte) => write!(formatter, "{:02x}", byte)?,
+                        None => formatter.write_str("--")?,
+                    }
+                } else {
+                    Self::fmt_cell(formatter, byte, other)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the hexdump of the differences between the two byte sequences, or `None` if they are
+/// equal.
+///
+/// This is an implementation detail of [`assert_bytes_eq!`] and is not part of the public API.
+#[doc(hidden)]
+pub fn bytes_diff<'a>(actual: &'a [u8], expected: &'a [u8]) -> Option<BytesDiff<'a>> {
+    if actual == expected {
+        return None;
+    }
+    let offset = actual
+        .iter()
+        .zip(expected)
+        .position(|(a, e)| a != e)
+        .unwrap_or_else(|| core::cmp::min(actual.len(), expected.len()));
+    Some(BytesDiff {
+        actual,
+        expected,
+        offset,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{vec, vec::Vec};
+
+    #[test]
+    fn equal() {
+        assert_bytes_eq!(vec![0xde, 0xad, 0xbe, 0xef], [0xde, 0xad, 0xbe, 0xef]);
+    }
+
+    #[test]
+    fn equal_slices() {
+        let bytes: &[u8] = &[1, 2, 3];
+        assert_bytes_eq!(bytes, b"\x01\x02\x03");
+    }
+
+    #[test]
+    fn equal_empty() {
+        assert_bytes_eq!(Vec::<u8>::new(), []);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected bytes to be equal\n    first difference at offset: `0x3`,\n    hexdump (actual | expected):\n    00000000  de ad be ef*            | de ad be ee*"
+    )]
+    fn not_equal() {
+        assert_bytes_eq!(vec![0xde, 0xad, 0xbe, 0xef], [0xde, 0xad, 0xbe, 0xee]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected bytes to be equal\n    first difference at offset: `0x1c`,\n    hexdump (actual | expected):\n    00000008  08 09 0a 0b 0c 0d 0e 0f | 08 09 0a 0b 0c 0d 0e 0f\n    00000010  10 11 12 13 14 15 16 17 | 10 11 12 13 14 15 16 17\n    00000018  18 19 1a 1b ff*1d ff*1f | 18 19 1a 1b 1c*1d 1e*1f\n    00000020  20 21 22 23 24 25 26 27 | 20 21 22 23 24 25 26 27\n    00000028  28 29 2a 2b 2c 2d 2e 2f | 28 29 2a 2b 2c 2d 2e 2f"
+    )]
+    fn not_equal_window() {
+        let expected: Vec<u8> = (0..64).collect();
+        let mut actual = expected.clone();
+        actual[0x1c] = 0xff;
+        actual[0x1e] = 0xff;
+        assert_bytes_eq!(actual, expected);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected bytes to be equal\n    first difference at offset: `0x4`,\n    note: lengths differ, actual is `4` bytes and expected is `6` bytes,\n    hexdump (actual | expected):\n    00000000  00 01 02 03 --*--*      | 00 01 02 03 04*05*"
+    )]
+    fn actual_shorter() {
+        assert_bytes_eq!([0, 1, 2, 3], [0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected bytes to be equal\n    first difference at offset: `0x2`,\n    note: lengths differ, actual is `3` bytes and expected is `2` bytes,\n    hexdump (actual | expected):\n    00000000  00 01 02*               | 00 01 --*"
+    )]
+    fn expected_shorter() {
+        assert_bytes_eq!([0, 1, 2], [0, 1]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected bytes to be equal\n    first difference at offset: `0x3`,\n    hexdump (actual | expected):\n    00000000  de ad be ef*            | de ad be ee*: foo"
+    )]
+    fn not_equal_custom_message() {
+        assert_bytes_eq!([0xde, 0xad, 0xbe, 0xef], [0xde, 0xad, 0xbe, 0xee], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_equal() {
+        debug_assert_bytes_eq!([1, 2, 3], vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected bytes to be equal\n    first difference at offset: `0x3`,\n    hexdump (actual | expected):\n    00000000  de ad be ef*            | de ad be ee*"
+    )]
+    fn debug_not_equal() {
+        debug_assert_bytes_eq!([0xde, 0xad, 0xbe, 0xef], [0xde, 0xad, 0xbe, 0xee]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected bytes to be equal\n    first difference at offset: `0x3`,\n    hexdump (actual | expected):\n    00000000  de ad be ef*            | de ad be ee*: foo"
+    )]
+    fn debug_not_equal_custom_message() {
+        debug_assert_bytes_eq!([0xde, 0xad, 0xbe, 0xef], [0xde, 0xad, 0xbe, 0xee], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_equal() {
+        debug_assert_bytes_eq!([0xde, 0xad, 0xbe, 0xef], [0xde, 0xad, 0xbe, 0xee]);
+    }
+}
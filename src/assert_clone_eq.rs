@@ -0,0 +1,177 @@
+/// Asserts that cloning the expression produces a value equal to the original.
+///
+/// This checks the basic contract of [`Clone`], which is useful when testing custom `Clone`
+/// implementations. The expression is only borrowed, and its type must implement [`Clone`],
+/// [`PartialEq`], and [`Debug`]. On failure, both the original value and the clone are printed.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_clone_eq!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let values = vec![1, 2, 3];
+/// assert_clone_eq!(values);
+///
+/// // With a custom message
+/// assert_clone_eq!(values, "Expecting {:?} to be cloned faithfully", values);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # #[derive(Debug, PartialEq)]
+/// # struct Token { id: u32 }
+/// # impl Clone for Token {
+/// #     fn clone(&self) -> Self { Token { id: self.id + 1 } }
+/// # }
+/// # fn main() {
+/// let token = Token { id: 1 };
+/// assert_clone_eq!(token);  // Will panic
+/// # }
+/// ```
+///
+/// [`Clone`]: https://doc.rust-lang.org/core/clone/trait.Clone.html
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_clone_eq!`]: crate::debug_assert_clone_eq!
+#[macro_export]
+macro_rules! assert_clone_eq {
+    ($value:expr $(,)?) => {
+        match &$value {
+            value => {
+                let clone = ::core::clone::Clone::clone(value);
+                if !(*value == clone) {
+                    ::core::panic!(r#"assertion failed: `(value.clone() == value)`
+    value: `{:?}`,
+    clone: `{:?}`"#, value, &clone)
+                }
+            }
+        }
+    };
+    ($value:expr, $($arg:tt)+) => {
+        match &$value {
+            value => {
+                let clone = ::core::clone::Clone::clone(value);
+                if !(*value == clone) {
+                    ::core::panic!(r#"assertion failed: `(value.clone() == value)`
+    value: `{:?}`,
+    clone: `{:?}`: {}"#, value, &clone, ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that cloning the expression produces a value equal to the original on debug builds.
+///
+/// This macro behaves the same as [`assert_clone_eq!`] on debug builds. On release builds it is a
+/// no-op.
+#[macro_export]
+macro_rules! debug_assert_clone_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_clone_eq!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{string::ToString, vec};
+
+    #[derive(Debug, PartialEq)]
+    struct Token {
+        id: u32,
+    }
+
+    impl Clone for Token {
+        fn clone(&self) -> Self {
+            Token { id: self.id + 1 }
+        }
+    }
+
+    #[test]
+    fn primitive() {
+        assert_clone_eq!(42);
+    }
+
+    #[test]
+    fn vec() {
+        let values = vec![1, 2, 3];
+        assert_clone_eq!(values);
+        assert_eq!(values.len(), 3);
+    }
+
+    #[test]
+    fn string() {
+        assert_clone_eq!("foo".to_string());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(value.clone() == value)`\n    value: `Token { id: 1 }`,\n    clone: `Token { id: 2 }`"
+    )]
+    fn broken_clone() {
+        assert_clone_eq!(Token { id: 1 });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(value.clone() == value)`\n    value: `NaN`,\n    clone: `NaN`"
+    )]
+    fn nan() {
+        assert_clone_eq!(core::f64::NAN);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(value.clone() == value)`\n    value: `Token { id: 1 }`,\n    clone: `Token { id: 2 }`: foo"
+    )]
+    fn broken_clone_custom_message() {
+        assert_clone_eq!(Token { id: 1 }, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_vec() {
+        debug_assert_clone_eq!(vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(value.clone() == value)`\n    value: `Token { id: 1 }`,\n    clone: `Token { id: 2 }`"
+    )]
+    fn debug_broken_clone() {
+        debug_assert_clone_eq!(Token { id: 1 });
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(value.clone() == value)`\n    value: `Token { id: 1 }`,\n    clone: `Token { id: 2 }`: foo"
+    )]
+    fn debug_broken_clone_custom_message() {
+        debug_assert_clone_eq!(Token { id: 1 }, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_broken_clone() {
+        debug_assert_clone_eq!(Token { id: 1 });
+    }
+}
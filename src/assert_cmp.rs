@@ -0,0 +1,381 @@
+/// Asserts that two expressions satisfy the given comparison operator, returning the first.
+///
+/// The operator is passed as a token between the two expressions, and can be any of `<`, `<=`,
+/// `>`, `>=`, `==`, or `!=`. This is useful when the operator itself varies across a set of
+/// parametrized tests.
+///
+/// The ordering operators delegate to [`assert_lt!`], [`assert_le!`], [`assert_gt!`], and
+/// [`assert_ge!`], so a failing `assert_cmp!(a, <, b)` panics with exactly the same message as
+/// `assert_lt!(a, b)`. The equality operators produce a message in the same format. Neither
+/// expression is required to implement [`Debug`].
+///
+/// Every operator has the same ownership semantics as those macros: both expressions are only
+/// borrowed, and a reference to the first is returned once the comparison has passed.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_cmp!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_cmp!(1, <, 2);
+/// assert_cmp!(2, >=, 2);
+/// assert_cmp!(3, !=, 4);
+///
+/// // With a custom message
+/// assert_cmp!(1, <=, 2, "Expecting {} to be less than or equal to {}", 1, 2);
+/// # }
+/// ```
+///
+/// The first value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let count = 5;
+/// let value = assert_cmp!(count, >, 3);
+/// assert_eq!(*value, 5);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_cmp!(5, <, 5);  // Will panic
+/// # }
+/// ```
+///
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_lt!`]: crate::assert_lt!
+/// [`assert_le!`]: crate::assert_le!
+/// [`assert_gt!`]: crate::assert_gt!
+/// [`assert_ge!`]: crate::assert_ge!
+/// [`debug_assert_cmp!`]: crate::debug_assert_cmp!
+#[macro_export]
+macro_rules! assert_cmp {
+    ($left:expr, <, $right:expr $(,)?) => {
+        $crate::assert_lt!($left, $right)
+    };
+    ($left:expr, <, $right:expr, $($arg:tt)+) => {
+        $crate::assert_lt!($left, $right, $($arg)+)
+    };
+    ($left:expr, <=, $right:expr $(,)?) => {
+        $crate::assert_le!($left, $right)
+    };
+    ($left:expr, <=, $right:expr, $($arg:tt)+) => {
+        $crate::assert_le!($left, $right, $($arg)+)
+    };
+    ($left:expr, >, $right:expr $(,)?) => {
+        $crate::assert_gt!($left, $right)
+    };
+    ($left:expr, >, $right:expr, $($arg:tt)+) => {
+        $crate::assert_gt!($left, $right, $($arg)+)
+    };
+    ($left:expr, >=, $right:expr $(,)?) => {
+        $crate::assert_ge!($left, $right)
+    };
+    ($left:expr, >=, $right:expr, $($arg:tt)+) => {
+        $crate::assert_ge!($left, $right, $($arg)+)
+    };
+    ($left:expr, ==, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed: `(left == right)`
+    left: `{:?}`,
+    right: `{:?}`"#, (&$crate::__private::MaybeDebug(&*left_val)).maybe_debug(), (&$crate::__private::MaybeDebug(&*right_val)).maybe_debug())
+                }
+                left_val
+            }
+        }
+    };
+    ($left:expr, ==, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed: `(left == right)`
+    left: `{:?}`,
+    right: `{:?}`: {}"#, (&$crate::__private::MaybeDebug(&*left_val)).maybe_debug(), (&$crate::__private::MaybeDebug(&*right_val)).maybe_debug(), ::core::format_args!($($arg)+))
+                }
+                left_val
+            }
+        }
+    };
+    ($left:expr, !=, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val != *right_val) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed: `(left != right)`
+    left: `{:?}`,
+    right: `{:?}`"#, (&$crate::__private::MaybeDebug(&*left_val)).maybe_debug(), (&$crate::__private::MaybeDebug(&*right_val)).maybe_debug())
+                }
+                left_val
+            }
+        }
+    };
+    ($left:expr, !=, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val != *right_val) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed: `(left != right)`
+    left: `{:?}`,
+    right: `{:?}`: {}"#, (&$crate::__private::MaybeDebug(&*left_val)).maybe_debug(), (&$crate::__private::MaybeDebug(&*right_val)).maybe_debug(), ::core::format_args!($($arg)+))
+                }
+                left_val
+            }
+        }
+    };
+}
+
+/// Asserts that two expressions satisfy the given comparison operator on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_cmp!`] on debug builds, although it does not
+/// return the first expression. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_cmp {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_cmp!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn less_than() {
+        assert_cmp!(1, <, 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left < right)`\n    left: `2`,\n    right: `2`,\n    difference: `0`"
+    )]
+    fn not_less_than() {
+        assert_cmp!(2, <, 2);
+    }
+
+    #[test]
+    fn less_than_or_equal() {
+        assert_cmp!(2, <=, 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left <= right)`\n    left: `3`,\n    right: `2`,\n    difference: `1`"
+    )]
+    fn not_less_than_or_equal() {
+        assert_cmp!(3, <=, 2);
+    }
+
+    #[test]
+    fn greater_than() {
+        assert_cmp!(3, >, 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left > right)`\n    left: `2`,\n    right: `2`,\n    difference: `0`"
+    )]
+    fn not_greater_than() {
+        assert_cmp!(2, >, 2);
+    }
+
+    #[test]
+    fn greater_than_or_equal() {
+        assert_cmp!(2, >=, 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left >= right)`\n    left: `1`,\n    right: `2`,\n    difference: `1`"
+    )]
+    fn not_greater_than_or_equal() {
+        assert_cmp!(1, >=, 2);
+    }
+
+    #[test]
+    fn equal() {
+        assert_cmp!(2, ==, 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left == right)`\n    left: `1`,\n    right: `2`"
+    )]
+    fn not_equal() {
+        assert_cmp!(1, ==, 2);
+    }
+
+    #[test]
+    fn not_equal_passes() {
+        assert_cmp!(1, !=, 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left != right)`\n    left: `2`,\n    right: `2`"
+    )]
+    fn equal_fails_not_equal() {
+        assert_cmp!(2, !=, 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left < right)`\n    left: `2`,\n    right: `2`,\n    difference: `0`: foo"
+    )]
+    fn not_less_than_custom_message() {
+        assert_cmp!(2, <, 2, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: left and right are not comparable (partial_cmp returned None)\n    left: `1.0`,\n    right: `NaN`"
+    )]
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    fn not_comparable() {
+        assert_cmp!(1.0, <=, core::f64::NAN);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left == right)`\n    left: `1`,\n    right: `2`: foo"
+    )]
+    fn not_equal_custom_message() {
+        assert_cmp!(1, ==, 2, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left != right)`\n    left: `2`,\n    right: `2`: foo"
+    )]
+    fn equal_fails_not_equal_custom_message() {
+        assert_cmp!(2, !=, 2, "foo");
+    }
+
+    #[test]
+    fn value_returned() {
+        assert_eq!(*assert_cmp!(1, <, 2), 1);
+        assert_eq!(*assert_cmp!(2, <=, 2), 2);
+        assert_eq!(*assert_cmp!(3, >, 2), 3);
+        assert_eq!(*assert_cmp!(2, >=, 2), 2);
+        assert_eq!(*assert_cmp!(2, ==, 2), 2);
+        assert_eq!(*assert_cmp!(1, !=, 2), 1);
+    }
+
+    #[test]
+    fn non_copy_ownership_same_for_all_operators() {
+        #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+        struct NonCopy(usize);
+
+        let left = NonCopy(1);
+        let right = NonCopy(2);
+        assert_eq!(assert_cmp!(left, <, right), &NonCopy(1));
+        assert_eq!(assert_cmp!(left, <=, right), &NonCopy(1));
+        assert_eq!(assert_cmp!(right, >, left), &NonCopy(2));
+        assert_eq!(assert_cmp!(right, >=, left), &NonCopy(2));
+        assert_eq!(assert_cmp!(left, ==, NonCopy(1)), &NonCopy(1));
+        assert_eq!(assert_cmp!(left, !=, right), &NonCopy(1));
+        assert_eq!(left, NonCopy(1));
+        assert_eq!(right, NonCopy(2));
+    }
+
+    #[test]
+    fn does_not_require_operands_to_impl_debug() {
+        #[derive(PartialEq)]
+        struct Key(usize);
+
+        assert_cmp!(Key(1), ==, Key(1));
+        assert_cmp!(Key(1), !=, Key(2));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left == right)`\n    left: `<non-Debug>`,\n    right: `<non-Debug>`"
+    )]
+    fn non_debug_operands() {
+        #[derive(PartialEq)]
+        struct Key(usize);
+
+        assert_cmp!(Key(1), ==, Key(2));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_all_operators() {
+        debug_assert_cmp!(1, <, 2);
+        debug_assert_cmp!(2, <=, 2);
+        debug_assert_cmp!(3, >, 2);
+        debug_assert_cmp!(2, >=, 2);
+        debug_assert_cmp!(2, ==, 2);
+        debug_assert_cmp!(1, !=, 2);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(left < right)`\n    left: `2`,\n    right: `2`,\n    difference: `0`"
+    )]
+    fn debug_not_less_than() {
+        debug_assert_cmp!(2, <, 2);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(left != right)`\n    left: `2`,\n    right: `2`"
+    )]
+    fn debug_equal_fails_not_equal() {
+        debug_assert_cmp!(2, !=, 2);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(left >= right)`\n    left: `1`,\n    right: `2`,\n    difference: `1`: foo"
+    )]
+    fn debug_not_greater_than_or_equal_custom_message() {
+        debug_assert_cmp!(1, >=, 2, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_less_than() {
+        debug_assert_cmp!(2, <, 2);
+    }
+
+    #[test]
+    fn debug_does_not_move() {
+        #[derive(Debug, PartialEq)]
+        struct NonCopy(usize);
+
+        let left = NonCopy(1);
+        debug_assert_cmp!(left, ==, NonCopy(1));
+        assert_eq!(left, NonCopy(1));
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_cmp!(1, <, 2);
+    }
+}
@@ -0,0 +1,358 @@
+use core::{cell::Cell, fmt};
+
+/// Asserts that a collection contains the given element.
+///
+/// Works with anything that can be iterated over by reference, such as arrays, slices, `Vec`s,
+/// `HashSet`s, and `BTreeSet`s, as well as with cloneable iterators. The collection is only
+/// borrowed, and the needle is compared with [`PartialEq`] against a reference to each element, so
+/// it is typically given as a reference, such as `&3` or `"key"`.
+///
+/// The ranges `a..b`, `a..=b`, and `a..` are not iterated over. Instead, the needle is checked
+/// against their bounds using [`RangeBounds::contains`], so that even an unbounded range is handled.
+///
+/// A string, such as a `&str` or a `String`, is searched for the needle as a substring using
+/// [`str::contains`], so the needle must be a string as well.
+///
+/// On failure, the needle and the collection are printed. Only the first 16 elements of the
+/// collection are shown, followed by a count of the elements that were left out. For an iterator
+/// which does not know its own length, the count stops at 1024.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_contains!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # use std::collections::HashSet;
+/// # fn main() {
+/// let values = vec![1, 2, 3];
+/// assert_contains!(values, &3);
+///
+/// let keys: HashSet<String> = ["key".to_string()].iter().cloned().collect();
+/// assert_contains!(keys, "key");
+///
+/// assert_contains!(0..10, &7);
+/// assert_contains!(5.., &7);
+///
+/// // With a custom message
+/// assert_contains!(values, &3, "Expecting {:?} to contain 3", values);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_contains!([1, 2, 3], &4);  // Will panic
+/// # }
+/// ```
+///
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`RangeBounds::contains`]: https://doc.rust-lang.org/core/ops/trait.RangeBounds.html#method.contains
+/// [`str::contains`]: https://doc.rust-lang.org/core/primitive.str.html#method.contains
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_contains!`]: crate::debug_assert_contains!
+#[macro_export]
+macro_rules! assert_contains {
+    ($collection:expr, $needle:expr $(,)?) => {
+        match (&$collection, &$needle) {
+            (collection_val, needle_val) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewRange as _, ViewString as _, ViewTakenIterator as _};
+                if let ::core::result::Result::Err(collection) = (&&&&&$crate::__private::Collection(collection_val)).view().search(needle_val) {
+                    ::core::panic!(r#"assertion failed, expected collection to contain needle
+    needle: `{:?}`,
+    collection: `{:?}`"#, needle_val, collection)
+                }
+            }
+        }
+    };
+    ($collection:expr, $needle:expr, $($arg:tt)+) => {
+        match (&$collection, &$needle) {
+            (collection_val, needle_val) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewRange as _, ViewString as _, ViewTakenIterator as _};
+                if let ::core::result::Result::Err(collection) = (&&&&&$crate::__private::Collection(collection_val)).view().search(needle_val) {
+                    ::core::panic!(r#"assertion failed, expected collection to contain needle
+    needle: `{:?}`,
+    collection: `{:?}`: {}"#, needle_val, collection, ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that a collection contains the given element on debug builds.
+///
+/// This macro behaves the same as [`assert_contains!`] on debug builds. On release builds it is a
+/// no-op.
+#[macro_export]
+macro_rules! debug_assert_contains {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_contains!($($arg)*);
+        }
+    }};
+}
+
+/// The number of elements printed before the rest of a collection is left out.
+const TRUNCATE_AFTER: usize = 16;
+
+/// The number of left out elements counted when the length of the iterator is not known upfront.
+const COUNT_LIMIT: usize = 1024;
+
+/// Wrapper printing the elements of an iterator as a list, leaving out all but the first few.
+///
+/// The list ends with the number of elements left out. If the iterator does not report its exact
+/// length, at most `COUNT_LIMIT` of them are counted, and the count is printed as a lower bound.
+#[doc(hidden)]
+pub struct Truncated<I>(Cell<Option<I>>);
+
+impl<I> Truncated<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self(Cell::new(Some(iter)))
+    }
+}
+
+impl<I> fmt::Debug for Truncated<I>
+where
+    I: Iterator,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut iter = match self.0.take() {
+            Some(iter) => iter,
+            None => return formatter.write_str("[..]"),
+        };
+        formatter.write_str("[")?;
+        for (index, element) in (&mut iter).take(TRUNCATE_AFTER).enumerate() {
+            if index > 0 {
+                formatter.write_str(", ")?;
+            }
+            write!(formatter, "{:?}", element)?;
+        }
+        match iter.size_hint() {
+            (0, Some(0)) => {}
+            (lower, Some(upper)) if lower == upper => write!(formatter, ", ... ({} more)", lower)?,
+            _ => {
+                // The rest is only counted up to a limit, as the iterator may be very long or
+                // infinite.
+                let remaining = iter.take(COUNT_LIMIT + 1).count();
+                if remaining > COUNT_LIMIT {
+                    write!(formatter, ", ... (at least {} more)", remaining)?;
+                } else if remaining > 0 {
+                    write!(formatter, ", ... ({} more)", remaining)?;
+                }
+            }
+        }
+        formatter.write_str("]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{BTreeSet, HashSet},
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+
+    #[test]
+    fn array() {
+        assert_contains!([1, 2, 3], &2);
+    }
+
+    #[test]
+    fn slice() {
+        let values: &[u32] = &[1, 2, 3];
+        assert_contains!(values, &3);
+    }
+
+    #[test]
+    fn vec() {
+        let values = vec![1, 2, 3];
+        assert_contains!(values, &1);
+        assert_eq!(values.len(), 3);
+    }
+
+    #[test]
+    fn vec_by_reference() {
+        let values = vec![1, 2, 3];
+        assert_contains!(&values, &1);
+    }
+
+    #[test]
+    fn hash_set() {
+        let keys: HashSet<String> = ["key".to_string(), "other".to_string()]
+            .iter()
+            .cloned()
+            .collect();
+        assert_contains!(keys, "key");
+    }
+
+    #[test]
+    fn btree_set() {
+        let keys: BTreeSet<u32> = [4, 8, 15].iter().copied().collect();
+        assert_contains!(keys, &8);
+    }
+
+    #[test]
+    fn range() {
+        assert_contains!(0..10, &7);
+    }
+
+    #[test]
+    fn range_inclusive() {
+        assert_contains!(0..=10, &10);
+    }
+
+    #[test]
+    fn range_from() {
+        assert_contains!(5.., &7);
+    }
+
+    #[test]
+    fn string() {
+        assert_contains!(String::from("hello world"), "o w");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain needle\n    needle: `3`,\n    collection: `5..`"
+    )]
+    fn range_from_missing() {
+        assert_contains!(5.., &3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain needle\n    needle: `4`,\n    collection: `[1, 2, 3]`"
+    )]
+    fn array_missing() {
+        assert_contains!([1, 2, 3], &4);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain needle\n    needle: `4`,\n    collection: `[1, 2, 3]`"
+    )]
+    fn slice_missing() {
+        let values: &[u32] = &[1, 2, 3];
+        assert_contains!(values, &4);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain needle\n    needle: `4`,\n    collection: `[1, 2, 3]`"
+    )]
+    fn vec_missing() {
+        assert_contains!(vec![1, 2, 3], &4);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain needle\n    needle: `\"missing\"`,\n    collection: `[\"key\"]`"
+    )]
+    fn hash_set_missing() {
+        let keys: HashSet<String> = ["key".to_string()].iter().cloned().collect();
+        assert_contains!(keys, "missing");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain needle\n    needle: `10`,\n    collection: `0..10`"
+    )]
+    fn range_missing() {
+        assert_contains!(0..10, &10);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain needle\n    needle: `\"world\"`,\n    collection: `\"hello\"`"
+    )]
+    fn string_missing() {
+        assert_contains!("hello", "world");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain needle\n    needle: `100`,\n    collection: `[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, ... (84 more)]`"
+    )]
+    fn truncated() {
+        let values: Vec<u32> = (0..100).collect();
+        assert_contains!(values, &100);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain needle\n    needle: `20`,\n    collection: `[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, ... (4 more)]`"
+    )]
+    fn truncated_with_count() {
+        let values: Vec<u32> = (0..20).collect();
+        assert_contains!(values, &20);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain needle\n    needle: `1`,\n    collection: `[0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30, ... (34 more)]`"
+    )]
+    fn truncated_iterator_with_count() {
+        assert_contains!((0..100).filter(|x| x % 2 == 0), &1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain needle\n    needle: `1`,\n    collection: `[0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30, ... (at least 1025 more)]`"
+    )]
+    fn truncated_iterator_with_count_limit() {
+        assert_contains!((0..5000).filter(|x| x % 2 == 0), &1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain needle\n    needle: `4`,\n    collection: `[1, 2, 3]`: foo"
+    )]
+    fn missing_custom_message() {
+        assert_contains!([1, 2, 3], &4, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_vec() {
+        debug_assert_contains!(vec![1, 2, 3], &1);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain needle\n    needle: `4`,\n    collection: `[1, 2, 3]`"
+    )]
+    fn debug_vec_missing() {
+        debug_assert_contains!(vec![1, 2, 3], &4);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain needle\n    needle: `4`,\n    collection: `[1, 2, 3]`: foo"
+    )]
+    fn debug_vec_missing_custom_message() {
+        debug_assert_contains!(vec![1, 2, 3], &4, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_vec_missing() {
+        debug_assert_contains!(vec![1, 2, 3], &4);
+    }
+}
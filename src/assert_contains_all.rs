@@ -0,0 +1,324 @@
+use core::fmt;
+
+/// Asserts that a collection contains all of the given elements.
+///
+/// This behaves like [`assert_contains!`] called once for each needle, except that every needle is
+/// checked before panicking, and all of the missing needles are reported together. The haystack
+/// is handled the same way as by [`assert_contains!`]: it can be anything that can be iterated
+/// over by reference, such as an array, slice, `Vec`, or set, or a cloneable iterator, such as a
+/// range. The needles can be given as anything that can be iterated over by reference, such as an
+/// array or a `Vec`. The elements of the haystack are compared against references to the needles,
+/// so a needle `n` is found under the same conditions as `assert_contains!(haystack, &n)`.
+///
+/// The haystack can also be a string, such as a `&str` or a `String`, in which case the needles
+/// must be strings as well, and are searched for as substrings using [`str::contains`].
+///
+/// On failure, the missing needles are printed along with the haystack. Only the first few
+/// elements of the haystack, or the first few characters of a string haystack, are printed.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_contains_all!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let response = vec!["alpha", "beta", "gamma", "delta"];
+///
+/// assert_contains_all!(response, ["alpha", "beta", "gamma"]);
+///
+/// // With a custom message
+/// assert_contains_all!(response, ["alpha", "delta"], "Expecting the required items");
+///
+/// // Searching a string for substrings
+/// let message = String::from("error: file not found");
+/// assert_contains_all!(message, ["error", "not found"]);
+/// # }
+/// ```
+///
+/// Missing needles will panic, reporting all of them at once:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let response = vec!["alpha", "delta"];
+///
+/// assert_contains_all!(response, ["alpha", "beta", "gamma"]);  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_contains!`]: crate::assert_contains!
+/// [`str::contains`]: https://doc.rust-lang.org/core/primitive.str.html#method.contains
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_contains_all!`]: crate::debug_assert_contains_all!
+#[macro_export]
+macro_rules! assert_contains_all {
+    ($collection:expr, $needles:expr $(,)?) => {
+        match (&$collection, &$needles) {
+            (collection_val, needles_val) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewRange as _, ViewString as _, ViewTakenIterator as _};
+                if let ::core::option::Option::Some((missing, collection)) = $crate::__private::find_missing(needles_val, |needle| (&&&&&$crate::__private::Collection(collection_val)).view().search(needle)) {
+                    ::core::panic!(r#"assertion failed, expected collection to contain all needles
+    missing: `{:?}`,
+    collection: `{:?}`"#, missing, collection)
+                }
+            }
+        }
+    };
+    ($collection:expr, $needles:expr, $($arg:tt)+) => {
+        match (&$collection, &$needles) {
+            (collection_val, needles_val) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewRange as _, ViewString as _, ViewTakenIterator as _};
+                if let ::core::option::Option::Some((missing, collection)) = $crate::__private::find_missing(needles_val, |needle| (&&&&&$crate::__private::Collection(collection_val)).view().search(needle)) {
+                    ::core::panic!(r#"assertion failed, expected collection to contain all needles
+    missing: `{:?}`,
+    collection: `{:?}`: {}"#, missing, collection, ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that a collection contains all of the given elements on debug builds.
+///
+/// This macro behaves the same as [`assert_contains_all!`] on debug builds. On release builds it is
+/// a no-op.
+#[macro_export]
+macro_rules! debug_assert_contains_all {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_contains_all!($($arg)*);
+        }
+    }};
+}
+
+/// Wrapper printing the needles which were not found, searching for each of them again.
+///
+/// This avoids having to collect the missing needles.
+#[doc(hidden)]
+pub struct Missing<'a, C: ?Sized, F> {
+    needles: &'a C,
+    search: F,
+}
+
+impl<'a, C, F, T> fmt::Debug for Missing<'a, C, F>
+where
+    C: ?Sized,
+    &'a C: IntoIterator,
+    <&'a C as IntoIterator>::Item: fmt::Debug,
+    F: Fn(&<&'a C as IntoIterator>::Item) -> Result<(), T>,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_list()
+            .entries(
+                self.needles
+                    .into_iter()
+                    .filter(|needle| (self.search)(needle).is_err()),
+            )
+            .finish()
+    }
+}
+
+/// Searches for each of the needles, returning the missing needles along with the haystack if any
+/// needle was not found.
+///
+/// This is an implementation detail of [`assert_contains_all!`] and is not part of the public API.
+#[doc(hidden)]
+pub fn find_missing<'a, C, F, T>(needles: &'a C, search: F) -> Option<(Missing<'a, C, F>, T)>
+where
+    C: ?Sized,
+    &'a C: IntoIterator,
+    F: Fn(&<&'a C as IntoIterator>::Item) -> Result<(), T>,
+{
+    let haystack = needles
+        .into_iter()
+        .find_map(|needle| search(&needle).err())?;
+    Some((Missing { needles, search }, haystack))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{BTreeSet, HashSet},
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+
+    #[test]
+    fn array() {
+        assert_contains_all!([1, 2, 3], [3, 1]);
+    }
+
+    #[test]
+    fn vec() {
+        let values = vec!["alpha", "beta", "gamma"];
+        assert_contains_all!(values, ["alpha", "beta", "gamma"]);
+        assert_eq!(values.len(), 3);
+    }
+
+    #[test]
+    fn vec_needles() {
+        let values = vec![1, 2, 3];
+        let needles = vec![2, 3];
+        assert_contains_all!(&values, needles);
+    }
+
+    #[test]
+    fn hash_set() {
+        let keys: HashSet<String> = ["key".to_string(), "other".to_string()]
+            .iter()
+            .cloned()
+            .collect();
+        assert_contains_all!(keys, ["key", "other"]);
+    }
+
+    #[test]
+    fn btree_set() {
+        let keys: BTreeSet<u32> = [4, 8, 15].iter().copied().collect();
+        assert_contains_all!(keys, [8, 15]);
+    }
+
+    #[test]
+    fn range() {
+        assert_contains_all!(0..10, [0, 7, 9]);
+    }
+
+    #[test]
+    fn string() {
+        let message = String::from("alpha beta");
+        assert_contains_all!(message, ["alpha", "beta"]);
+        assert_contains_all!(String::from("alpha beta"), ["alpha", "beta"]);
+    }
+
+    #[test]
+    fn str() {
+        assert_contains_all!("alpha beta", vec!["a b", "beta"]);
+        assert_contains_all!(&String::from("alpha beta"), [String::from("pha")]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain all needles\n    missing: `[\"gamma\", \"delta\"]`,\n    collection: `\"alpha beta\"`"
+    )]
+    fn string_missing() {
+        assert_contains_all!(String::from("alpha beta"), ["gamma", "beta", "delta"]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain all needles\n    missing: `[\"y\"]`,\n    collection: `\"xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx\"... (36 more)`"
+    )]
+    fn string_truncated() {
+        assert_contains_all!("x".repeat(100), ["y"]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain all needles\n    missing: `[\"gamma\"]`,\n    collection: `\"alpha beta\"`: foo"
+    )]
+    fn str_missing_custom_message() {
+        assert_contains_all!("alpha beta", ["gamma"], "foo");
+    }
+
+    #[test]
+    fn no_needles() {
+        let needles: [u32; 0] = [];
+        assert_contains_all!([1, 2, 3], needles);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain all needles\n    missing: `[\"beta\", \"gamma\"]`,\n    collection: `[\"alpha\", \"delta\"]`"
+    )]
+    fn multiple_missing() {
+        let values = vec!["alpha", "delta"];
+        assert_contains_all!(values, ["alpha", "beta", "gamma"]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain all needles\n    missing: `[4]`,\n    collection: `[1, 2, 3]`"
+    )]
+    fn single_missing() {
+        assert_contains_all!([1, 2, 3], [1, 4]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain all needles\n    missing: `[\"missing\"]`,\n    collection: `[\"key\"]`"
+    )]
+    fn hash_set_missing() {
+        let keys: HashSet<String> = ["key".to_string()].iter().cloned().collect();
+        assert_contains_all!(keys, ["key", "missing"]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain all needles\n    missing: `[10, 11]`,\n    collection: `0..10`"
+    )]
+    fn range_missing() {
+        assert_contains_all!(0..10, [10, 5, 11]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain all needles\n    missing: `[100]`,\n    collection: `[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, ... (84 more)]`"
+    )]
+    fn truncated() {
+        let values: Vec<u32> = (0..100).collect();
+        assert_contains_all!(values, [50, 100]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain all needles\n    missing: `[4]`,\n    collection: `[1, 2, 3]`: foo"
+    )]
+    fn missing_custom_message() {
+        assert_contains_all!([1, 2, 3], [1, 4], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_vec() {
+        debug_assert_contains_all!(vec![1, 2, 3], [1, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain all needles\n    missing: `[4, 5]`,\n    collection: `[1, 2, 3]`"
+    )]
+    fn debug_vec_missing() {
+        debug_assert_contains_all!(vec![1, 2, 3], [4, 1, 5]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain all needles\n    missing: `[4]`,\n    collection: `[1, 2, 3]`: foo"
+    )]
+    fn debug_vec_missing_custom_message() {
+        debug_assert_contains_all!(vec![1, 2, 3], [4], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_vec_missing() {
+        debug_assert_contains_all!(vec![1, 2, 3], [4]);
+    }
+}
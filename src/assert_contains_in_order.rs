@@ -0,0 +1,257 @@
+/// Asserts that a collection contains the given elements in the same relative order, possibly with
+/// other elements in between.
+///
+/// This is a subsequence check rather than a contiguous one: every expected element must be
+/// matched by an element of the haystack that comes after the one matching the previous expected
+/// element. Both expressions can be anything implementing [`IntoIterator`], and are consumed. The
+/// haystack's elements are compared against the expected elements using [`PartialEq`], so the two
+/// do not need to be of the same type: a `&Vec<String>` can be checked against a `&[&str]`, for
+/// example. An empty list of expected elements is always matched.
+///
+/// On failure, the first expected element that could not be matched is printed along with its
+/// position in the list of expected elements, and the range of haystack indices that were searched
+/// for it, which starts right after the element matching its predecessor. The expected elements
+/// must implement [`Debug`].
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_contains_in_order!`] for assertions that are not enabled in release builds
+/// by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let lines: Vec<String> = vec![
+///     "connect".to_string(),
+///     "send hello".to_string(),
+///     "auth ok".to_string(),
+///     "disconnect".to_string(),
+/// ];
+///
+/// assert_contains_in_order!(&lines, &["connect", "auth ok", "disconnect"]);
+///
+/// // With a custom message
+/// assert_contains_in_order!(&lines, &["connect", "disconnect"], "Expecting a clean session");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_contains_in_order!(vec![1, 2, 3], vec![3, 1]);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_contains_in_order!`]: crate::debug_assert_contains_in_order!
+#[macro_export]
+macro_rules! assert_contains_in_order {
+    ($haystack:expr, $expected:expr $(,)?) => {
+        if let ::core::result::Result::Err(unmatched) = $crate::__private::find_in_order($haystack, $expected) {
+            ::core::panic!(r#"assertion failed, expected collection to contain elements in order
+    missing: `{:?}`,
+    position: `{}`,
+    searched: `{}..{}`"#, unmatched.element, unmatched.position, unmatched.start, unmatched.end)
+        }
+    };
+    ($haystack:expr, $expected:expr, $($arg:tt)+) => {
+        if let ::core::result::Result::Err(unmatched) = $crate::__private::find_in_order($haystack, $expected) {
+            ::core::panic!(r#"assertion failed, expected collection to contain elements in order
+    missing: `{:?}`,
+    position: `{}`,
+    searched: `{}..{}`: {}"#, unmatched.element, unmatched.position, unmatched.start, unmatched.end, ::core::format_args!($($arg)+))
+        }
+    };
+}
+
+/// Asserts that a collection contains the given elements in the same relative order, possibly with
+/// other elements in between, on debug builds.
+///
+/// This macro behaves the same as [`assert_contains_in_order!`] on debug builds. On release builds
+/// it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_contains_in_order {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_contains_in_order!($($arg)*);
+        }
+    }};
+}
+
+/// An expected element that could not be matched.
+#[doc(hidden)]
+pub struct Unmatched<T> {
+    /// The expected element.
+    pub element: T,
+    /// The position of the element within the expected elements.
+    pub position: usize,
+    /// The index in the haystack at which the search for the element started.
+    pub start: usize,
+    /// The length of the haystack.
+    pub end: usize,
+}
+
+/// Checks that the haystack contains the expected elements as a subsequence, returning the first
+/// expected element that could not be matched otherwise.
+///
+/// This is an implementation detail of [`assert_contains_in_order!`] and is not part of the public
+/// API.
+#[doc(hidden)]
+pub fn find_in_order<H, E>(haystack: H, expected: E) -> Result<(), Unmatched<E::Item>>
+where
+    H: IntoIterator,
+    E: IntoIterator,
+    H::Item: PartialEq<E::Item>,
+{
+    let mut haystack = haystack.into_iter();
+    let mut index = 0;
+    for (position, element) in expected.into_iter().enumerate() {
+        let start = index;
+        loop {
+            match haystack.next() {
+                Some(candidate) => {
+                    index += 1;
+                    if candidate == element {
+                        break;
+                    }
+                }
+                None => {
+                    return Err(Unmatched {
+                        element,
+                        position,
+                        start,
+                        end: index,
+                    })
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+
+    fn lines() -> Vec<String> {
+        vec![
+            "connect".to_string(),
+            "send hello".to_string(),
+            "auth ok".to_string(),
+            "recv data".to_string(),
+            "disconnect".to_string(),
+        ]
+    }
+
+    #[test]
+    fn in_order() {
+        assert_contains_in_order!(vec![1, 2, 3, 4, 5], vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn in_order_contiguous() {
+        assert_contains_in_order!(vec![1, 2, 3], vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn in_order_cross_type() {
+        assert_contains_in_order!(&lines(), &["connect", "auth ok", "disconnect"]);
+    }
+
+    #[test]
+    fn in_order_duplicates() {
+        assert_contains_in_order!(vec![1, 2, 1, 2], vec![1, 1, 2]);
+    }
+
+    #[test]
+    fn in_order_empty_expected() {
+        assert_contains_in_order!(Vec::<u32>::new(), Vec::<u32>::new());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain elements in order\n    missing: `1`,\n    position: `1`,\n    searched: `3..3`"
+    )]
+    fn out_of_order() {
+        assert_contains_in_order!(vec![1, 2, 3], vec![3, 1]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain elements in order\n    missing: `\"auth ok\"`,\n    position: `3`,\n    searched: `3..5`"
+    )]
+    fn out_of_order_cross_type() {
+        assert_contains_in_order!(&lines(), &["connect", "send hello", "auth ok", "auth ok"]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain elements in order\n    missing: `4`,\n    position: `0`,\n    searched: `0..3`"
+    )]
+    fn missing_first() {
+        assert_contains_in_order!(vec![1, 2, 3], vec![4]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain elements in order\n    missing: `1`,\n    position: `0`,\n    searched: `0..0`"
+    )]
+    fn empty_haystack() {
+        assert_contains_in_order!(Vec::<u32>::new(), vec![1]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain elements in order\n    missing: `1`,\n    position: `1`,\n    searched: `3..3`: foo"
+    )]
+    fn out_of_order_custom_message() {
+        assert_contains_in_order!(vec![1, 2, 3], vec![3, 1], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_in_order() {
+        debug_assert_contains_in_order!(vec![1, 2, 3], vec![1, 3]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain elements in order\n    missing: `1`,\n    position: `1`,\n    searched: `3..3`"
+    )]
+    fn debug_out_of_order() {
+        debug_assert_contains_in_order!(vec![1, 2, 3], vec![3, 1]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected collection to contain elements in order\n    missing: `1`,\n    position: `1`,\n    searched: `3..3`: foo"
+    )]
+    fn debug_out_of_order_custom_message() {
+        debug_assert_contains_in_order!(vec![1, 2, 3], vec![3, 1], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_out_of_order() {
+        debug_assert_contains_in_order!(vec![1, 2, 3], vec![3, 1]);
+    }
+}
@@ -0,0 +1,251 @@
+use crate::assert_contains::Truncated;
+use core::slice;
+
+/// Asserts that a slice contains the given subslice as a contiguous run of elements, returning the
+/// index of its first occurrence.
+///
+/// Both expressions can be anything that can be viewed as a slice through [`AsRef`], such as
+/// slices, `Vec`s, and arrays, and they do not need to be of the same type. They are only
+/// borrowed. The elements are compared using [`PartialEq`], and must implement [`Debug`].
+///
+/// An empty needle is contained in every slice, including an empty one, and is found at index
+/// `0`.
+///
+/// On failure, the needle and the haystack are printed. Only the first 16 elements of the haystack
+/// are shown, followed by a count of the elements that were left out.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_contains_subslice!`] for assertions that are not enabled in release builds
+/// by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let buffer: Vec<u8> = vec![0x00, 0x01, 0xde, 0xad, 0xbe, 0xef, 0x02];
+///
+/// assert_contains_subslice!(buffer, [0xbe, 0xef]);
+///
+/// // With a custom message
+/// assert_contains_subslice!(buffer, &[0xde, 0xad], "Expecting a marker");
+/// # }
+/// ```
+///
+/// The index of the first occurrence will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let index = assert_contains_subslice!([1, 2, 3, 2, 3], [2, 3]);
+/// assert_eq!(index, 1);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_contains_subslice!([1, 2, 3], [3, 2]);  // Will panic
+/// # }
+/// ```
+///
+/// [`AsRef`]: https://doc.rust-lang.org/core/convert/trait.AsRef.html
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_contains_subslice!`]: crate::debug_assert_contains_subslice!
+#[macro_export]
+macro_rules! assert_contains_subslice {
+    ($haystack:expr, $needle:expr $(,)?) => {
+        match (&$haystack, &$needle) {
+            (haystack_val, needle_val) => {
+                let needle_val = ::core::convert::AsRef::<[_]>::as_ref(needle_val);
+                match $crate::__private::find_subslice(::core::convert::AsRef::<[_]>::as_ref(haystack_val), needle_val) {
+                    ::core::result::Result::Ok(index) => index,
+                    ::core::result::Result::Err(haystack) => {
+                        ::core::panic!(r#"assertion failed, expected slice to contain subslice
+    needle: `{:?}`,
+    haystack: `{:?}`"#, needle_val, haystack)
+                    }
+                }
+            }
+        }
+    };
+    ($haystack:expr, $needle:expr, $($arg:tt)+) => {
+        match (&$haystack, &$needle) {
+            (haystack_val, needle_val) => {
+                let needle_val = ::core::convert::AsRef::<[_]>::as_ref(needle_val);
+                match $crate::__private::find_subslice(::core::convert::AsRef::<[_]>::as_ref(haystack_val), needle_val) {
+                    ::core::result::Result::Ok(index) => index,
+                    ::core::result::Result::Err(haystack) => {
+                        ::core::panic!(r#"assertion failed, expected slice to contain subslice
+    needle: `{:?}`,
+    haystack: `{:?}`: {}"#, needle_val, haystack, ::core::format_args!($($arg)+))
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that a slice contains the given subslice as a contiguous run of elements on debug
+/// builds.
+///
+/// This macro behaves nearly the same as [`assert_contains_subslice!`] on debug builds, although
+/// it does not return the index of the subslice. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_contains_subslice {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_contains_subslice!($($arg)*);
+        }
+    }};
+}
+
+/// Returns the index of the first occurrence of the needle in the haystack, or the haystack's
+/// elements for printing if it does not occur.
+///
+/// This is an implementation detail of [`assert_contains_subslice!`] and is not part of the public
+/// API.
+#[doc(hidden)]
+pub fn find_subslice<'a, T>(
+    haystack: &'a [T],
+    needle: &[T],
+) -> Result<usize, Truncated<slice::Iter<'a, T>>>
+where
+    T: PartialEq,
+{
+    if needle.is_empty() {
+        return Ok(0);
+    }
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .ok_or_else(|| Truncated::new(haystack.iter()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{vec, vec::Vec};
+
+    #[test]
+    fn contains_subslice() {
+        assert_contains_subslice!(vec![0, 1, 0xde, 0xad, 2], [0xde, 0xad]);
+    }
+
+    #[test]
+    fn contains_subslice_slices() {
+        let haystack: &[u32] = &[1, 2, 3];
+        let needle: &[u32] = &[2, 3];
+        assert_contains_subslice!(haystack, needle);
+    }
+
+    #[test]
+    fn contains_whole_slice() {
+        assert_contains_subslice!([1, 2, 3], [1, 2, 3]);
+    }
+
+    #[test]
+    fn contains_empty_needle() {
+        assert_eq!(assert_contains_subslice!([1, 2, 3], []), 0);
+    }
+
+    #[test]
+    fn empty_haystack_contains_empty_needle() {
+        assert_eq!(assert_contains_subslice!(Vec::<u32>::new(), []), 0);
+    }
+
+    #[test]
+    fn index_returned() {
+        assert_eq!(assert_contains_subslice!([1, 2, 3, 2, 3], [2, 3]), 1);
+    }
+
+    #[test]
+    fn index_returned_end() {
+        assert_eq!(assert_contains_subslice!([1, 2, 3, 4], [3, 4]), 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected slice to contain subslice\n    needle: `[3, 2]`,\n    haystack: `[1, 2, 3]`"
+    )]
+    fn not_contains_subslice() {
+        assert_contains_subslice!([1, 2, 3], [3, 2]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected slice to contain subslice\n    needle: `[1, 3]`,\n    haystack: `[1, 2, 3]`"
+    )]
+    fn not_contains_non_contiguous() {
+        assert_contains_subslice!([1, 2, 3], [1, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected slice to contain subslice\n    needle: `[1, 2, 3]`,\n    haystack: `[1, 2]`"
+    )]
+    fn needle_longer_than_haystack() {
+        assert_contains_subslice!([1, 2], [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected slice to contain subslice\n    needle: `[20, 21]`,\n    haystack: `[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, ... (4 more)]`"
+    )]
+    fn not_contains_subslice_truncated() {
+        assert_contains_subslice!((0..20).collect::<Vec<_>>(), [20, 21]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected slice to contain subslice\n    needle: `[3, 2]`,\n    haystack: `[1, 2, 3]`: foo"
+    )]
+    fn not_contains_subslice_custom_message() {
+        assert_contains_subslice!([1, 2, 3], [3, 2], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_contains_subslice() {
+        debug_assert_contains_subslice!([1, 2, 3], [2, 3]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected slice to contain subslice\n    needle: `[3, 2]`,\n    haystack: `[1, 2, 3]`"
+    )]
+    fn debug_not_contains_subslice() {
+        debug_assert_contains_subslice!([1, 2, 3], [3, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected slice to contain subslice\n    needle: `[3, 2]`,\n    haystack: `[1, 2, 3]`: foo"
+    )]
+    fn debug_not_contains_subslice_custom_message() {
+        debug_assert_contains_subslice!([1, 2, 3], [3, 2], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_contains_subslice() {
+        debug_assert_contains_subslice!([1, 2, 3], [3, 2]);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_contains_subslice!([1, 2, 3], [2, 3]);
+    }
+}
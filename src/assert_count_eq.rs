@@ -0,0 +1,200 @@
+/// Asserts that an iterator produces exactly the expected number of elements.
+///
+/// The expression can be anything implementing [`IntoIterator`], and is consumed: its elements
+/// are counted using [`Iterator::count`], without collecting them into a `Vec` first. The expected
+/// count must be a `usize`.
+///
+/// On failure, the actual and expected counts are printed, along with a preview of the first few
+/// elements. Only those elements are kept while counting, in a fixed-size buffer, so no allocation
+/// is needed regardless of the length of the iterator. The elements are printed with their
+/// [`Debug`] implementation if they have one, and the preview is shown as `<non-Debug>` otherwise.
+/// To print all of the elements, use [`assert_len_eq!`] with a cloneable iterator.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_count_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let words = vec!["foo", "bar", "baz", "quux"];
+/// assert_count_eq!(words.iter().filter(|word| word.len() == 3), 3);
+///
+/// // With a custom message
+/// assert_count_eq!(words.iter().filter(|word| word.starts_with('b')), 2, "Expecting two b-words");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_count_eq!((0..10).filter(|n| n % 3 == 0), 3);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`Iterator::count`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#method.count
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`assert_len_eq!`]: crate::assert_len_eq!
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_count_eq!`]: crate::debug_assert_count_eq!
+#[macro_export]
+macro_rules! assert_count_eq {
+    ($iter:expr, $expected:expr $(,)?) => {
+        match ($crate::__private::preview($iter), $expected) {
+            (preview, expected_val) => {
+                let expected_val: usize = expected_val;
+                if preview.count() != expected_val {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected iterator to produce `{}` elements
+    count: `{}`,
+    elements: `{:?}`"#, expected_val, preview.count(), (&$crate::__private::MaybeDebug(&preview)).maybe_debug())
+                }
+            }
+        }
+    };
+    ($iter:expr, $expected:expr, $($arg:tt)+) => {
+        match ($crate::__private::preview($iter), $expected) {
+            (preview, expected_val) => {
+                let expected_val: usize = expected_val;
+                if preview.count() != expected_val {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected iterator to produce `{}` elements
+    count: `{}`,
+    elements: `{:?}`: {}"#, expected_val, preview.count(), (&$crate::__private::MaybeDebug(&preview)).maybe_debug(), ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that an iterator produces exactly the expected number of elements on debug builds.
+///
+/// This macro behaves the same as [`assert_count_eq!`] on debug builds. On release builds it is a
+/// no-op.
+#[macro_export]
+macro_rules! debug_assert_count_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_count_eq!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{iter, vec};
+
+    #[test]
+    fn count_eq() {
+        assert_count_eq!((0..10).filter(|n| n % 2 == 0), 5);
+    }
+
+    #[test]
+    fn count_eq_into_iter() {
+        assert_count_eq!(vec![1, 2, 3], 3);
+    }
+
+    #[test]
+    fn count_eq_empty() {
+        assert_count_eq!(iter::empty::<u32>(), 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to produce `3` elements\n    count: `4`,\n    elements: `[0, 3, 6, ... (1 more)]`"
+    )]
+    fn count_not_eq() {
+        assert_count_eq!((0..10).filter(|n| n % 3 == 0), 3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to produce `1` elements\n    count: `0`,\n    elements: `[]`"
+    )]
+    fn count_not_eq_empty() {
+        assert_count_eq!(iter::empty::<u32>(), 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to produce `3` elements\n    count: `4`,\n    elements: `[0, 3, 6, ... (1 more)]`: foo"
+    )]
+    fn count_not_eq_custom_message() {
+        assert_count_eq!((0..10).filter(|n| n % 3 == 0), 3, "foo");
+    }
+
+    #[test]
+    fn count_eq_take() {
+        assert_count_eq!(iter::repeat(1).take(1000), 1000);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to produce `999` elements\n    count: `1000`,\n    elements: `[0, 1, 2, ... (997 more)]`"
+    )]
+    fn count_not_eq_take() {
+        assert_count_eq!((0..).take(1000), 999);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to produce `0` elements\n    count: `2`,\n    elements: `[Err(\"bad\"), Err(\"worse\")]`"
+    )]
+    fn count_not_eq_short() {
+        let lines: vec::Vec<Result<u32, &str>> = vec![Ok(1), Err("bad"), Ok(2), Err("worse")];
+        assert_count_eq!(lines.into_iter().filter(|line| line.is_err()), 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to produce `0` elements\n    count: `1`,\n    elements: `<non-Debug>`"
+    )]
+    fn count_not_eq_non_debug() {
+        struct NonDebug;
+
+        assert_count_eq!(iter::once(NonDebug), 0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_count_eq() {
+        debug_assert_count_eq!((0..10).filter(|n| n % 2 == 0), 5);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to produce `3` elements\n    count: `4`,\n    elements: `[0, 3, 6, ... (1 more)]`"
+    )]
+    fn debug_count_not_eq() {
+        debug_assert_count_eq!((0..10).filter(|n| n % 3 == 0), 3);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to produce `3` elements\n    count: `4`,\n    elements: `[0, 3, 6, ... (1 more)]`: foo"
+    )]
+    fn debug_count_not_eq_custom_message() {
+        debug_assert_count_eq!((0..10).filter(|n| n % 3 == 0), 3, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_count_not_eq() {
+        debug_assert_count_eq!((0..10).filter(|n| n % 3 == 0), 3);
+    }
+}
@@ -0,0 +1,147 @@
+/// Asserts that an iterator produces at least the given number of elements.
+///
+/// The expression can be anything implementing [`IntoIterator`], and is consumed: its elements
+/// are counted using [`Iterator::count`], without collecting them into a `Vec` first. The bound
+/// must be a `usize`. This is useful for tests such as "at least N results".
+///
+/// On failure, the count and the bound are printed.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_count_ge!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_count_ge!((0..10).filter(|n| n % 3 == 0), 4);
+///
+/// // With a custom message
+/// assert_count_ge!((0..10).filter(|n| n % 3 == 0), 4, "Expecting at least 4 multiples");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_count_ge!(0..3, 4);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`Iterator::count`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#method.count
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_count_ge!`]: crate::debug_assert_count_ge!
+#[macro_export]
+macro_rules! assert_count_ge {
+    ($iter:expr, $bound:expr $(,)?) => {
+        match (::core::iter::Iterator::count(::core::iter::IntoIterator::into_iter($iter)), $bound) {
+            (count, bound_val) => {
+                let bound_val: usize = bound_val;
+                if !(count >= bound_val) {
+                    ::core::panic!(r#"assertion failed, expected iterator to produce at least `{}` elements
+    count: `{}`"#, bound_val, count)
+                }
+            }
+        }
+    };
+    ($iter:expr, $bound:expr, $($arg:tt)+) => {
+        match (::core::iter::Iterator::count(::core::iter::IntoIterator::into_iter($iter)), $bound) {
+            (count, bound_val) => {
+                let bound_val: usize = bound_val;
+                if !(count >= bound_val) {
+                    ::core::panic!(r#"assertion failed, expected iterator to produce at least `{}` elements
+    count: `{}`: {}"#, bound_val, count, ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that an iterator produces at least the given number of elements on debug builds.
+///
+/// This macro behaves the same as [`assert_count_ge!`] on debug builds. On release builds it is a
+/// no-op.
+#[macro_export]
+macro_rules! debug_assert_count_ge {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_count_ge!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{iter, vec};
+
+    #[test]
+    fn count_ge() {
+        assert_count_ge!(0..3, 3);
+    }
+
+    #[test]
+    fn count_ge_boundary() {
+        assert_count_ge!(vec![1, 2, 3], 0);
+    }
+
+    #[test]
+    fn count_ge_empty() {
+        assert_count_ge!(iter::empty::<u32>(), 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to produce at least `4` elements\n    count: `3`"
+    )]
+    fn count_not_ge() {
+        assert_count_ge!(0..3, 4);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to produce at least `4` elements\n    count: `3`: foo"
+    )]
+    fn count_not_ge_custom_message() {
+        assert_count_ge!(0..3, 4, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_count_ge() {
+        debug_assert_count_ge!(0..3, 3);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to produce at least `4` elements\n    count: `3`"
+    )]
+    fn debug_count_not_ge() {
+        debug_assert_count_ge!(0..3, 4);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to produce at least `4` elements\n    count: `3`: foo"
+    )]
+    fn debug_count_not_ge_custom_message() {
+        debug_assert_count_ge!(0..3, 4, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_count_not_ge() {
+        debug_assert_count_ge!(0..3, 4);
+    }
+}
@@ -0,0 +1,158 @@
+/// Asserts that an iterator does not produce exactly the given number of elements.
+///
+/// The expression can be anything implementing [`IntoIterator`], and is consumed: its elements
+/// are counted using [`Iterator::count`], without collecting them into a `Vec` first. The
+/// unexpected count must be a `usize`. This is useful for checking that filtering actually
+/// changed the number of elements.
+///
+/// On failure, the count is printed.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_count_ne!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let words = vec!["foo", "bar", "baz", "quux"];
+/// assert_count_ne!(words.iter().filter(|word| word.len() == 3), words.len());
+///
+/// // With a custom message
+/// assert_count_ne!(words.iter().filter(|word| word.starts_with('b')), 0, "Expecting b-words");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_count_ne!((0..10).filter(|n| n % 3 == 0), 4);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`Iterator::count`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#method.count
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_count_ne!`]: crate::debug_assert_count_ne!
+#[macro_export]
+macro_rules! assert_count_ne {
+    ($iter:expr, $unexpected:expr $(,)?) => {
+        match (::core::iter::Iterator::count(::core::iter::IntoIterator::into_iter($iter)), $unexpected) {
+            (count, unexpected_val) => {
+                let unexpected_val: usize = unexpected_val;
+                if count == unexpected_val {
+                    ::core::panic!(r#"assertion failed, expected iterator not to produce `{}` elements
+    count: `{}`"#, unexpected_val, count)
+                }
+            }
+        }
+    };
+    ($iter:expr, $unexpected:expr, $($arg:tt)+) => {
+        match (::core::iter::Iterator::count(::core::iter::IntoIterator::into_iter($iter)), $unexpected) {
+            (count, unexpected_val) => {
+                let unexpected_val: usize = unexpected_val;
+                if count == unexpected_val {
+                    ::core::panic!(r#"assertion failed, expected iterator not to produce `{}` elements
+    count: `{}`: {}"#, unexpected_val, count, ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that an iterator does not produce exactly the given number of elements on debug
+/// builds.
+///
+/// This macro behaves the same as [`assert_count_ne!`] on debug builds. On release builds it is a
+/// no-op.
+#[macro_export]
+macro_rules! debug_assert_count_ne {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_count_ne!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{iter, vec};
+
+    #[test]
+    fn count_ne() {
+        assert_count_ne!((0..10).filter(|n| n % 2 == 0), 10);
+    }
+
+    #[test]
+    fn count_ne_into_iter() {
+        assert_count_ne!(vec![1, 2, 3], 2);
+    }
+
+    #[test]
+    fn count_ne_empty() {
+        assert_count_ne!(iter::empty::<u32>(), 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator not to produce `4` elements\n    count: `4`"
+    )]
+    fn count_not_ne() {
+        assert_count_ne!((0..10).filter(|n| n % 3 == 0), 4);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator not to produce `0` elements\n    count: `0`"
+    )]
+    fn count_not_ne_empty() {
+        assert_count_ne!(iter::empty::<u32>(), 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator not to produce `4` elements\n    count: `4`: foo"
+    )]
+    fn count_not_ne_custom_message() {
+        assert_count_ne!((0..10).filter(|n| n % 3 == 0), 4, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_count_ne() {
+        debug_assert_count_ne!((0..10).filter(|n| n % 2 == 0), 10);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected iterator not to produce `4` elements\n    count: `4`"
+    )]
+    fn debug_count_not_ne() {
+        debug_assert_count_ne!((0..10).filter(|n| n % 3 == 0), 4);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected iterator not to produce `4` elements\n    count: `4`: foo"
+    )]
+    fn debug_count_not_ne_custom_message() {
+        debug_assert_count_ne!((0..10).filter(|n| n % 3 == 0), 4, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_count_not_ne() {
+        debug_assert_count_ne!((0..10).filter(|n| n % 3 == 0), 4);
+    }
+}
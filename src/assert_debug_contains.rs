@@ -0,0 +1,205 @@
+use core::fmt::Debug;
+use std::{format, string::String};
+
+/// Asserts that the [`Debug`] output of the expression contains the given substring.
+///
+/// This is useful for testing the formatting of complex types, where the full [`Debug`] output is
+/// too fragile to compare against but specific parts of it must be present. The expression is only
+/// borrowed, and is formatted with `{:?}`. On failure, the full [`Debug`] output and the expected
+/// substring are printed.
+///
+/// This macro requires the `std` feature.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_debug_contains!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// #[derive(Debug)]
+/// struct Config {
+///     name: String,
+///     retries: u32,
+/// }
+///
+/// let config = Config { name: "server".to_string(), retries: 3 };
+/// assert_debug_contains!(config, "retries: 3");
+///
+/// // With a custom message
+/// assert_debug_contains!(config, "retries: 3", "Expecting {} retries", 3);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_debug_contains!(Some(42), "None");  // Will panic
+/// # }
+/// ```
+///
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_debug_contains!`]: crate::debug_assert_debug_contains!
+#[macro_export]
+macro_rules! assert_debug_contains {
+    ($value:expr, $substring:expr $(,)?) => {
+        match (&$value, &$substring) {
+            (value_val, substring_val) => {
+                if let ::core::result::Result::Err(debug) = $crate::__private::debug_contains(value_val, substring_val) {
+                    ::core::panic!(r#"assertion failed, expected Debug output to contain substring
+    debug: `{}`,
+    substring: `{}`"#, debug, substring_val)
+                }
+            }
+        }
+    };
+    ($value:expr, $substring:expr, $($arg:tt)+) => {
+        match (&$value, &$substring) {
+            (value_val, substring_val) => {
+                if let ::core::result::Result::Err(debug) = $crate::__private::debug_contains(value_val, substring_val) {
+                    ::core::panic!(r#"assertion failed, expected Debug output to contain substring
+    debug: `{}`,
+    substring: `{}`: {}"#, debug, substring_val, ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that the [`Debug`] output of the expression contains the given substring on debug
+/// builds.
+///
+/// This macro behaves the same as [`assert_debug_contains!`] on debug builds. On release builds it
+/// is a no-op.
+///
+/// This macro requires the `std` feature.
+///
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+#[macro_export]
+macro_rules! debug_assert_debug_contains {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_debug_contains!($($arg)*);
+        }
+    }};
+}
+
+/// Formats the value with [`Debug`], returning the output if it does not contain the substring.
+///
+/// This is an implementation detail of [`assert_debug_contains!`] and is not part of the public
+/// API.
+#[doc(hidden)]
+pub fn debug_contains<T, S>(value: &T, substring: &S) -> Result<(), String>
+where
+    T: Debug + ?Sized,
+    S: AsRef<str> + ?Sized,
+{
+    let debug = format!("{:?}", value);
+    if debug.contains(substring.as_ref()) {
+        Ok(())
+    } else {
+        Err(debug)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        string::{String, ToString},
+        vec,
+    };
+
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    struct Config {
+        name: String,
+        retries: u32,
+    }
+
+    fn config() -> Config {
+        Config {
+            name: "server".to_string(),
+            retries: 3,
+        }
+    }
+
+    #[test]
+    fn contains() {
+        assert_debug_contains!(config(), "retries: 3");
+        assert_debug_contains!(config(), "name: \"server\"");
+    }
+
+    #[test]
+    fn contains_string_substring() {
+        let substring = "retries".to_string();
+        assert_debug_contains!(config(), substring);
+    }
+
+    #[test]
+    fn contains_entire_output() {
+        assert_debug_contains!(vec![1, 2, 3], "[1, 2, 3]");
+    }
+
+    #[test]
+    fn empty_substring() {
+        assert_debug_contains!((), "");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Debug output to contain substring\n    debug: `Config { name: \"server\", retries: 3 }`,\n    substring: `retries: 4`"
+    )]
+    fn not_contains() {
+        assert_debug_contains!(config(), "retries: 4");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Debug output to contain substring\n    debug: `Some(42)`,\n    substring: `None`: foo"
+    )]
+    fn not_contains_custom_message() {
+        assert_debug_contains!(Some(42), "None", "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_contains() {
+        debug_assert_debug_contains!(Some(42), "42");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Debug output to contain substring\n    debug: `Some(42)`,\n    substring: `None`"
+    )]
+    fn debug_not_contains() {
+        debug_assert_debug_contains!(Some(42), "None");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Debug output to contain substring\n    debug: `Some(42)`,\n    substring: `None`: foo"
+    )]
+    fn debug_not_contains_custom_message() {
+        debug_assert_debug_contains!(Some(42), "None", "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_contains() {
+        debug_assert_debug_contains!(Some(42), "None");
+    }
+}
@@ -0,0 +1,238 @@
+/// Asserts that the expression is equal to the default value of its type, returning the value.
+///
+/// The default value is obtained through [`Default::default()`], with the type inferred from the
+/// expression, so no type annotation or import is needed. The value's type must implement
+/// [`Default`], [`PartialEq`], and [`Debug`]. On failure, both the value and the default are
+/// printed.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled. See
+/// [`debug_assert_default_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # #[derive(Debug, Default, PartialEq)]
+/// # struct Counter { count: u32 }
+/// # impl Counter {
+/// #     fn new() -> Self { Counter { count: 10 } }
+/// #     fn reset(&mut self) { self.count = 0; }
+/// # }
+/// # fn main() {
+/// let mut counter = Counter::new();
+/// counter.reset();
+/// assert_default_eq!(counter);
+///
+/// // With a custom message
+/// assert_default_eq!(0u32, "Expecting the counter to be reset");
+/// # }
+/// ```
+///
+/// The value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let value = assert_default_eq!(0u32);
+/// assert_eq!(value, 0u32);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # #[derive(Debug, Default, PartialEq)]
+/// # struct Counter { count: u32 }
+/// # impl Counter {
+/// #     fn new() -> Self { Counter { count: 10 } }
+/// #     fn reset(&mut self) { self.count = 0; }
+/// # }
+/// # fn main() {
+/// let counter = Counter::new();
+/// assert_default_eq!(counter);  // Will panic
+/// # }
+/// ```
+///
+/// [`Default::default()`]: https://doc.rust-lang.org/core/default/trait.Default.html#tymethod.default
+/// [`Default`]: https://doc.rust-lang.org/core/default/trait.Default.html
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_default_eq!`]: crate::debug_assert_default_eq!
+#[macro_export]
+macro_rules! assert_default_eq {
+    ($value:expr $(,)?) => {
+        match $value {
+            value => {
+                let default = $crate::__private::DefaultOf::default_of(&value);
+                if !(value == default) {
+                    ::core::panic!(r#"assertion failed: `(value == Default::default())`
+    value: `{:?}`,
+    default: `{:?}`"#, &value, &default)
+                }
+                value
+            }
+        }
+    };
+    ($value:expr, $($arg:tt)+) => {
+        match $value {
+            value => {
+                let default = $crate::__private::DefaultOf::default_of(&value);
+                if !(value == default) {
+                    ::core::panic!(r#"assertion failed: `(value == Default::default())`
+    value: `{:?}`,
+    default: `{:?}`: {}"#, &value, &default, ::core::format_args!($($arg)+))
+                }
+                value
+            }
+        }
+    };
+}
+
+/// Asserts that the expression is equal to the default value of its type on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_default_eq!`] on debug builds, although it does
+/// not return the value. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_default_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_default_eq!($($arg)*);
+        }
+    }};
+}
+
+/// Types whose default value can be produced from a reference to a value of the same type.
+///
+/// This allows the default value to be inferred from the asserted expression, without any type
+/// annotations.
+///
+/// This is an implementation detail of [`assert_default_eq!`] and [`assert_default_ne!`] and is
+/// not part of the public API.
+#[doc(hidden)]
+pub trait DefaultOf {
+    fn default_of(&self) -> Self;
+}
+
+impl<T> DefaultOf for T
+where
+    T: Default,
+{
+    fn default_of(&self) -> T {
+        T::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug, PartialEq)]
+    struct Config {
+        retries: u32,
+        name: &'static str,
+    }
+
+    impl Default for Config {
+        fn default() -> Self {
+            Config {
+                retries: 3,
+                name: "default",
+            }
+        }
+    }
+
+    #[test]
+    fn default() {
+        assert_default_eq!(Config::default());
+    }
+
+    #[test]
+    fn default_primitive() {
+        assert_default_eq!(0u32);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(value == Default::default())`\n    value: `Config { retries: 5, name: \"custom\" }`,\n    default: `Config { retries: 3, name: \"default\" }`"
+    )]
+    fn not_default() {
+        assert_default_eq!(Config {
+            retries: 5,
+            name: "custom"
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(value == Default::default())`\n    value: `Config { retries: 5, name: \"custom\" }`,\n    default: `Config { retries: 3, name: \"default\" }`: foo"
+    )]
+    fn not_default_custom_message() {
+        assert_default_eq!(
+            Config {
+                retries: 5,
+                name: "custom"
+            },
+            "foo"
+        );
+    }
+
+    #[test]
+    fn value_returned() {
+        let value = assert_default_eq!(Config::default());
+        assert_eq!(value, Config::default());
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_default() {
+        debug_assert_default_eq!(Config::default());
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(value == Default::default())`\n    value: `Config { retries: 5, name: \"custom\" }`,\n    default: `Config { retries: 3, name: \"default\" }`"
+    )]
+    fn debug_not_default() {
+        debug_assert_default_eq!(Config {
+            retries: 5,
+            name: "custom"
+        });
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(value == Default::default())`\n    value: `Config { retries: 5, name: \"custom\" }`,\n    default: `Config { retries: 3, name: \"default\" }`: foo"
+    )]
+    fn debug_not_default_custom_message() {
+        debug_assert_default_eq!(
+            Config {
+                retries: 5,
+                name: "custom"
+            },
+            "foo"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_default() {
+        debug_assert_default_eq!(Config {
+            retries: 5,
+            name: "custom"
+        });
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_default_eq!(Config::default());
+    }
+}
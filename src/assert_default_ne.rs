@@ -0,0 +1,213 @@
+/// Asserts that the expression is not equal to the default value of its type, returning the
+/// value.
+///
+/// This is useful for checking that something was actually configured. The default value is
+/// obtained through [`Default::default()`], with the type inferred from the expression, so no type
+/// annotation or import is needed. The value's type must implement [`Default`], [`PartialEq`], and
+/// [`Debug`].
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled. See
+/// [`debug_assert_default_ne!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # #[derive(Debug, Default, PartialEq)]
+/// # struct Counter { count: u32 }
+/// # impl Counter {
+/// #     fn new() -> Self { Counter { count: 10 } }
+/// #     fn reset(&mut self) { self.count = 0; }
+/// # }
+/// # fn main() {
+/// let counter = Counter::new();
+/// assert_default_ne!(counter);
+///
+/// // With a custom message
+/// assert_default_ne!(42u32, "Expecting the counter to be configured");
+/// # }
+/// ```
+///
+/// The value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let value = assert_default_ne!(42u32);
+/// assert_eq!(value, 42u32);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # #[derive(Debug, Default, PartialEq)]
+/// # struct Counter { count: u32 }
+/// # impl Counter {
+/// #     fn new() -> Self { Counter { count: 10 } }
+/// #     fn reset(&mut self) { self.count = 0; }
+/// # }
+/// # fn main() {
+/// let mut counter = Counter::new();
+/// counter.reset();
+/// assert_default_ne!(counter);  // Will panic
+/// # }
+/// ```
+///
+/// [`Default::default()`]: https://doc.rust-lang.org/core/default/trait.Default.html#tymethod.default
+/// [`Default`]: https://doc.rust-lang.org/core/default/trait.Default.html
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_default_ne!`]: crate::debug_assert_default_ne!
+#[macro_export]
+macro_rules! assert_default_ne {
+    ($value:expr $(,)?) => {
+        match $value {
+            value => {
+                let default = $crate::__private::DefaultOf::default_of(&value);
+                if !(value != default) {
+                    ::core::panic!(r#"assertion failed: `(value != Default::default())`
+    value: `{:?}`"#, &value)
+                }
+                value
+            }
+        }
+    };
+    ($value:expr, $($arg:tt)+) => {
+        match $value {
+            value => {
+                let default = $crate::__private::DefaultOf::default_of(&value);
+                if !(value != default) {
+                    ::core::panic!(r#"assertion failed: `(value != Default::default())`
+    value: `{:?}`: {}"#, &value, ::core::format_args!($($arg)+))
+                }
+                value
+            }
+        }
+    };
+}
+
+/// Asserts that the expression is not equal to the default value of its type on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_default_ne!`] on debug builds, although it does
+/// not return the value. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_default_ne {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_default_ne!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug, PartialEq)]
+    struct Config {
+        retries: u32,
+        name: &'static str,
+    }
+
+    impl Default for Config {
+        fn default() -> Self {
+            Config {
+                retries: 3,
+                name: "default",
+            }
+        }
+    }
+
+    #[test]
+    fn not_default() {
+        assert_default_ne!(Config {
+            retries: 5,
+            name: "custom"
+        });
+    }
+
+    #[test]
+    fn not_default_primitive() {
+        assert_default_ne!(42u32);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(value != Default::default())`\n    value: `Config { retries: 3, name: \"default\" }`"
+    )]
+    fn default() {
+        assert_default_ne!(Config::default());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(value != Default::default())`\n    value: `Config { retries: 3, name: \"default\" }`: foo"
+    )]
+    fn default_custom_message() {
+        assert_default_ne!(Config::default(), "foo");
+    }
+
+    #[test]
+    fn value_returned() {
+        let value = assert_default_ne!(Config {
+            retries: 5,
+            name: "custom"
+        });
+        assert_eq!(
+            value,
+            Config {
+                retries: 5,
+                name: "custom"
+            }
+        );
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_not_default() {
+        debug_assert_default_ne!(Config {
+            retries: 5,
+            name: "custom"
+        });
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(value != Default::default())`\n    value: `Config { retries: 3, name: \"default\" }`"
+    )]
+    fn debug_default() {
+        debug_assert_default_ne!(Config::default());
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(value != Default::default())`\n    value: `Config { retries: 3, name: \"default\" }`: foo"
+    )]
+    fn debug_default_custom_message() {
+        debug_assert_default_ne!(Config::default(), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_default() {
+        debug_assert_default_ne!(Config::default());
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_default_ne!(Config {
+            retries: 5,
+            name: "custom"
+        });
+    }
+}
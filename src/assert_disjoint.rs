@@ -0,0 +1,196 @@
+/// Asserts that two collections have no elements in common.
+///
+/// Both expressions can be anything implementing [`IntoIterator`], as long as they produce the
+/// same element type. To compare a `Vec` against an array, for example, pass both by reference.
+/// Elements are looked up in a [`HashSet`] when they implement [`Hash`] and [`Eq`]. Elements that
+/// only implement [`Ord`] are looked up in a [`BTreeSet`] instead. Either way, the elements must
+/// also implement [`Debug`].
+///
+/// An empty collection is disjoint from any collection.
+///
+/// On failure, the elements found in both collections are printed once each, in the order they
+/// first appear in the left collection, along with how many of them there are.
+///
+/// This macro requires the `std` feature.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_disjoint!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let web_ports = vec![80, 443];
+/// let database_ports = vec![5432, 6379];
+///
+/// assert_disjoint!(&web_ports, &database_ports);
+///
+/// // With a custom message
+/// assert_disjoint!(&web_ports, &database_ports, "Expecting no port conflicts");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_disjoint!(&vec![80, 443], &[443, 8080]);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`HashSet`]: https://doc.rust-lang.org/std/collections/struct.HashSet.html
+/// [`BTreeSet`]: https://doc.rust-lang.org/std/collections/struct.BTreeSet.html
+/// [`Hash`]: https://doc.rust-lang.org/core/hash/trait.Hash.html
+/// [`Eq`]: https://doc.rust-lang.org/core/cmp/trait.Eq.html
+/// [`Ord`]: https://doc.rust-lang.org/core/cmp/trait.Ord.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_disjoint!`]: crate::debug_assert_disjoint!
+#[macro_export]
+macro_rules! assert_disjoint {
+    ($left:expr, $right:expr $(,)?) => {
+        match ($crate::__private::collect($left), $crate::__private::collect($right)) {
+            (left_val, right_val) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{HashElements as _, OrdElements as _};
+                let common = (&$crate::__private::Elements(&left_val, &right_val)).common();
+                if !common.is_empty() {
+                    ::core::panic!(r#"assertion failed, expected collections to be disjoint
+    common: `{:?}`,
+    count: `{}`"#, common, common.len())
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match ($crate::__private::collect($left), $crate::__private::collect($right)) {
+            (left_val, right_val) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{HashElements as _, OrdElements as _};
+                let common = (&$crate::__private::Elements(&left_val, &right_val)).common();
+                if !common.is_empty() {
+                    ::core::panic!(r#"assertion failed, expected collections to be disjoint
+    common: `{:?}`,
+    count: `{}`: {}"#, common, common.len(), ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two collections have no elements in common on debug builds.
+///
+/// This macro behaves the same as [`assert_disjoint!`] on debug builds. On release builds it is a
+/// no-op.
+///
+/// This macro requires the `std` feature.
+#[macro_export]
+macro_rules! debug_assert_disjoint {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_disjoint!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{vec, vec::Vec};
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Port(u16);
+
+    #[test]
+    fn disjoint() {
+        assert_disjoint!(&vec![80, 443], &[5432, 6379]);
+    }
+
+    #[test]
+    fn disjoint_ord_only() {
+        assert_disjoint!(vec![Port(80)], vec![Port(443)]);
+    }
+
+    #[test]
+    fn empty_left() {
+        let empty: Vec<u16> = Vec::new();
+        assert_disjoint!(&empty, &[80, 443]);
+    }
+
+    #[test]
+    fn empty_right() {
+        let empty: Vec<u16> = Vec::new();
+        assert_disjoint!(&[80, 443], &empty);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collections to be disjoint\n    common: `[443]`,\n    count: `1`"
+    )]
+    fn single_common_element() {
+        assert_disjoint!(&vec![80, 443], &[443, 8080]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collections to be disjoint\n    common: `[8080, 80]`,\n    count: `2`"
+    )]
+    fn multiple_common_elements() {
+        assert_disjoint!(vec![8080, 443, 80, 8080], vec![80, 8080, 22, 80]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collections to be disjoint\n    common: `[Port(443)]`,\n    count: `1`"
+    )]
+    fn common_element_ord_only() {
+        assert_disjoint!(vec![Port(80), Port(443)], vec![Port(443)]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collections to be disjoint\n    common: `[443]`,\n    count: `1`: foo"
+    )]
+    fn common_element_custom_message() {
+        assert_disjoint!(&vec![80, 443], &[443, 8080], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_disjoint() {
+        debug_assert_disjoint!(&vec![80, 443], &[5432, 6379]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected collections to be disjoint\n    common: `[443]`,\n    count: `1`"
+    )]
+    fn debug_common_element() {
+        debug_assert_disjoint!(&vec![80, 443], &[443, 8080]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected collections to be disjoint\n    common: `[443]`,\n    count: `1`: foo"
+    )]
+    fn debug_common_element_custom_message() {
+        debug_assert_disjoint!(&vec![80, 443], &[443, 8080], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_common_element() {
+        debug_assert_disjoint!(&vec![80, 443], &[443, 8080]);
+    }
+}
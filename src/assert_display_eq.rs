@@ -0,0 +1,231 @@
+use core::fmt::{self, Display, Write};
+
+/// Asserts that the [`Display`] output of the expression equals the expected string.
+///
+/// This is equivalent to `assert_eq!(value.to_string(), expected)`, but does not require
+/// allocation, so it can be used to test [`Display`] implementations in `no_std` environments. The
+/// expression is only borrowed, and the expected value can be anything implementing `AsRef<str>`.
+/// On failure, both the actual output and the expected string are printed.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_display_eq!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # use std::fmt;
+/// # fn main() {
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// impl fmt::Display for Point {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "({}, {})", self.x, self.y)
+///     }
+/// }
+///
+/// assert_display_eq!(Point { x: 1, y: 2 }, "(1, 2)");
+///
+/// // With a custom message
+/// assert_display_eq!(Point { x: 1, y: 2 }, "(1, 2)", "Expecting the point to be formatted");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_display_eq!(42, "24");  // Will panic
+/// # }
+/// ```
+///
+/// [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_display_eq!`]: crate::debug_assert_display_eq!
+#[macro_export]
+macro_rules! assert_display_eq {
+    ($value:expr, $expected:expr $(,)?) => {
+        match (&$value, &$expected) {
+            (value_val, expected_val) => {
+                if !$crate::__private::display_eq(value_val, expected_val) {
+                    ::core::panic!(r#"assertion failed, expected Display output to equal expected string
+    display: `{}`,
+    expected: `{}`"#, value_val, ::core::convert::AsRef::<str>::as_ref(expected_val))
+                }
+            }
+        }
+    };
+    ($value:expr, $expected:expr, $($arg:tt)+) => {
+        match (&$value, &$expected) {
+            (value_val, expected_val) => {
+                if !$crate::__private::display_eq(value_val, expected_val) {
+                    ::core::panic!(r#"assertion failed, expected Display output to equal expected string
+    display: `{}`,
+    expected: `{}`: {}"#, value_val, ::core::convert::AsRef::<str>::as_ref(expected_val), ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that the [`Display`] output of the expression equals the expected string on debug
+/// builds.
+///
+/// This macro behaves the same as [`assert_display_eq!`] on debug builds. On release builds it is
+/// a no-op.
+///
+/// [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
+#[macro_export]
+macro_rules! debug_assert_display_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_display_eq!($($arg)*);
+        }
+    }};
+}
+
+/// Writer that checks formatted output against an expected string as it is written.
+struct Matcher<'a> {
+    remaining: &'a str,
+}
+
+impl Write for Matcher<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.remaining.starts_with(s) {
+            self.remaining = &self.remaining[s.len()..];
+            Ok(())
+        } else {
+            Err(fmt::Error)
+        }
+    }
+}
+
+/// Returns whether the value's [`Display`] output equals the expected string.
+///
+/// The output is compared as it is written, so no allocation is needed.
+///
+/// This is an implementation detail of [`assert_display_eq!`] and is not part of the public API.
+#[doc(hidden)]
+pub fn display_eq<T, S>(value: &T, expected: &S) -> bool
+where
+    T: Display + ?Sized,
+    S: AsRef<str> + ?Sized,
+{
+    let mut matcher = Matcher {
+        remaining: expected.as_ref(),
+    };
+    write!(matcher, "{}", value).is_ok() && matcher.remaining.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt;
+    use std::string::ToString;
+
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    impl fmt::Display for Point {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "({}, {})", self.x, self.y)
+        }
+    }
+
+    #[test]
+    fn equal() {
+        assert_display_eq!(Point { x: 1, y: 2 }, "(1, 2)");
+    }
+
+    #[test]
+    fn equal_primitive() {
+        assert_display_eq!(42, "42");
+        assert_display_eq!("foo", "foo");
+    }
+
+    #[test]
+    fn equal_string_expected() {
+        let expected = "(1, 2)".to_string();
+        assert_display_eq!(Point { x: 1, y: 2 }, expected);
+    }
+
+    #[test]
+    fn equal_empty() {
+        assert_display_eq!("", "");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Display output to equal expected string\n    display: `(1, 2)`,\n    expected: `(2, 1)`"
+    )]
+    fn not_equal() {
+        assert_display_eq!(Point { x: 1, y: 2 }, "(2, 1)");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Display output to equal expected string\n    display: `(1, 2)`,\n    expected: `(1, 2) and more`"
+    )]
+    fn output_is_prefix() {
+        assert_display_eq!(Point { x: 1, y: 2 }, "(1, 2) and more");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Display output to equal expected string\n    display: `(1, 2)`,\n    expected: `(1, `"
+    )]
+    fn expected_is_prefix() {
+        assert_display_eq!(Point { x: 1, y: 2 }, "(1, ");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Display output to equal expected string\n    display: `42`,\n    expected: `24`: foo"
+    )]
+    fn not_equal_custom_message() {
+        assert_display_eq!(42, "24", "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_equal() {
+        debug_assert_display_eq!(42, "42");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Display output to equal expected string\n    display: `42`,\n    expected: `24`"
+    )]
+    fn debug_not_equal() {
+        debug_assert_display_eq!(42, "24");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Display output to equal expected string\n    display: `42`,\n    expected: `24`: foo"
+    )]
+    fn debug_not_equal_custom_message() {
+        debug_assert_display_eq!(42, "24", "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_equal() {
+        debug_assert_display_eq!(42, "24");
+    }
+}
@@ -0,0 +1,220 @@
+use core::any::Any;
+use std::string::String;
+
+/// Asserts that calling the closure does not panic, returning the closure's result.
+///
+/// The closure is called through [`catch_unwind`]. If it panics, the panic is caught and this
+/// macro panics in turn, with the original panic message included in its own. Unlike
+/// `#[should_panic]`, this allows checking that only a specific part of a test is panic-free. The
+/// closure is not required to be [`UnwindSafe`].
+///
+/// If the panic payload is neither a `&str` nor a `String`, as can happen with
+/// [`std::panic::resume_unwind`], the original message is shown as `Box<dyn Any>`.
+///
+/// This macro requires the `std` feature.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_does_not_panic!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let values = vec![1, 2, 3];
+/// assert_does_not_panic!(|| values[0]);
+///
+/// // With a custom message
+/// assert_does_not_panic!(|| values[2], "Expecting index 2 to be in bounds");
+/// # }
+/// ```
+///
+/// The value returned by the closure will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let sum = assert_does_not_panic!(|| 1 + 1);
+/// assert_eq!(sum, 2);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_does_not_panic!(|| panic!("out of fuel"));  // Will panic
+/// # }
+/// ```
+///
+/// [`catch_unwind`]: https://doc.rust-lang.org/std/panic/fn.catch_unwind.html
+/// [`UnwindSafe`]: https://doc.rust-lang.org/std/panic/trait.UnwindSafe.html
+/// [`std::panic::resume_unwind`]: https://doc.rust-lang.org/std/panic/fn.resume_unwind.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_does_not_panic!`]: crate::debug_assert_does_not_panic!
+#[macro_export]
+macro_rules! assert_does_not_panic {
+    ($closure:expr $(,)?) => {
+        match $crate::__private::catch_unwind($closure) {
+            ::core::result::Result::Ok(value) => value,
+            ::core::result::Result::Err(payload) => {
+                ::core::panic!(r#"assertion failed, expected no panic
+    panic message: `{}`"#, $crate::__private::panic_message(&*payload));
+            }
+        }
+    };
+    ($closure:expr, $($arg:tt)+) => {
+        match $crate::__private::catch_unwind($closure) {
+            ::core::result::Result::Ok(value) => value,
+            ::core::result::Result::Err(payload) => {
+                ::core::panic!(r#"assertion failed, expected no panic
+    panic message: `{}`: {}"#, $crate::__private::panic_message(&*payload), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that calling the closure does not panic on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_does_not_panic!`] on debug builds, although it
+/// does not return the closure's result. On release builds it is a no-op, and the closure is not
+/// called.
+///
+/// This macro requires the `std` feature.
+#[macro_export]
+macro_rules! debug_assert_does_not_panic {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_does_not_panic!($($arg)*);
+        }
+    }};
+}
+
+/// Returns the message of a panic payload, if it has one.
+///
+/// This is an implementation detail of [`assert_does_not_panic!`] and is not part of the public
+/// API.
+#[doc(hidden)]
+pub fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "Box<dyn Any>"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{boxed::Box, cell::Cell, panic, vec::Vec};
+
+    #[test]
+    fn does_not_panic() {
+        assert_does_not_panic!(|| 1 + 1);
+    }
+
+    #[test]
+    fn not_unwind_safe() {
+        let counter = Cell::new(0);
+        assert_does_not_panic!(|| counter.set(counter.get() + 1));
+        assert_eq!(counter.get(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected no panic\n    panic message: `foo`")]
+    fn panics() {
+        assert_does_not_panic!(|| panic!("foo"));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected no panic\n    panic message: `foo 42`")]
+    fn panics_formatted_message() {
+        let value = 42;
+        assert_does_not_panic!(|| panic!("foo {}", value));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected no panic\n    panic message: `index out of bounds"
+    )]
+    fn panics_index_out_of_bounds() {
+        let values: Vec<u32> = Vec::new();
+        assert_does_not_panic!(|| values[0]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected no panic\n    panic message: `Box<dyn Any>`"
+    )]
+    fn panics_non_string_payload() {
+        assert_does_not_panic!(|| panic::resume_unwind(Box::new(42u32)));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected no panic\n    panic message: `foo`: bar")]
+    fn panics_custom_message() {
+        assert_does_not_panic!(|| panic!("foo"), "bar");
+    }
+
+    #[test]
+    fn value_returned() {
+        let values = [1, 2, 3];
+        let value = assert_does_not_panic!(|| values[1]);
+        assert_eq!(value, 2);
+    }
+
+    #[test]
+    fn value_returned_custom_message() {
+        let value = assert_does_not_panic!(|| 1 + 1, "foo");
+        assert_eq!(value, 2);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_does_not_panic() {
+        debug_assert_does_not_panic!(|| 1 + 1);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, expected no panic\n    panic message: `foo`")]
+    fn debug_panics() {
+        debug_assert_does_not_panic!(|| panic!("foo"));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, expected no panic\n    panic message: `foo`: bar")]
+    fn debug_panics_custom_message() {
+        debug_assert_does_not_panic!(|| panic!("foo"), "bar");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_panics() {
+        debug_assert_does_not_panic!(|| panic!("foo"));
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_closure_not_called() {
+        let counter = Cell::new(0);
+        debug_assert_does_not_panic!(|| counter.set(counter.get() + 1));
+        assert_eq!(counter.get(), 0);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_does_not_panic!(|| 1 + 1);
+    }
+}
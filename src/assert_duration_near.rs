@@ -0,0 +1,262 @@
+use core::time::Duration;
+
+/// Asserts that a [`Duration`] is within a tolerance of an expected [`Duration`], returning the
+/// first.
+///
+/// The assertion passes if the absolute difference between the two durations is no greater than
+/// the tolerance, which makes it suitable for timing measurements that can't be compared exactly.
+/// On failure, all durations are printed in a human-readable form, such as `103.2ms`.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_duration_near!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # use std::time::Duration;
+/// # fn main() {
+/// let elapsed = Duration::from_micros(103_200);
+/// assert_duration_near!(elapsed, Duration::from_millis(100), Duration::from_millis(15));
+///
+/// // With a custom message
+/// assert_duration_near!(
+///     elapsed,
+///     Duration::from_millis(100),
+///     Duration::from_millis(15),
+///     "Expecting the retry to fire after roughly 100ms"
+/// );
+/// # }
+/// ```
+///
+/// The first duration will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # use std::time::Duration;
+/// # fn main() {
+/// let elapsed = assert_duration_near!(
+///     Duration::from_millis(98),
+///     Duration::from_millis(100),
+///     Duration::from_millis(15),
+/// );
+/// assert_eq!(elapsed, Duration::from_millis(98));
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # use std::time::Duration;
+/// # fn main() {
+/// assert_duration_near!(Duration::from_millis(120), Duration::from_millis(100), Duration::from_millis(15));  // Will panic
+/// # }
+/// ```
+///
+/// [`Duration`]: https://doc.rust-lang.org/core/time/struct.Duration.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_duration_near!`]: crate::debug_assert_duration_near!
+#[macro_export]
+macro_rules! assert_duration_near {
+    ($actual:expr, $expected:expr, $tolerance:expr $(,)?) => {
+        match ($actual, $expected, $tolerance) {
+            (actual, expected, tolerance) => {
+                let difference = $crate::__private::duration_abs_diff(actual, expected);
+                if difference > tolerance {
+                    ::core::panic!(r#"assertion failed: `(|actual - expected| <= tolerance)`
+    actual: `{:?}`,
+    expected: `{:?}`,
+    difference: `{:?}`,
+    tolerance: `{:?}`"#, actual, expected, difference, tolerance);
+                }
+                actual
+            }
+        }
+    };
+    ($actual:expr, $expected:expr, $tolerance:expr, $($arg:tt)+) => {
+        match ($actual, $expected, $tolerance) {
+            (actual, expected, tolerance) => {
+                let difference = $crate::__private::duration_abs_diff(actual, expected);
+                if difference > tolerance {
+                    ::core::panic!(r#"assertion failed: `(|actual - expected| <= tolerance)`
+    actual: `{:?}`,
+    expected: `{:?}`,
+    difference: `{:?}`,
+    tolerance: `{:?}`: {}"#, actual, expected, difference, tolerance, ::core::format_args!($($arg)+));
+                }
+                actual
+            }
+        }
+    };
+}
+
+/// Asserts that a [`Duration`] is within a tolerance of an expected [`Duration`] on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_duration_near!`] on debug builds, although it
+/// does not return the first duration. On release builds it is a no-op.
+///
+/// [`Duration`]: https://doc.rust-lang.org/core/time/struct.Duration.html
+#[macro_export]
+macro_rules! debug_assert_duration_near {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_duration_near!($($arg)*);
+        }
+    }};
+}
+
+/// Returns the absolute difference between two durations.
+///
+/// This is an implementation detail of [`assert_duration_near!`] and is not part of the public API.
+#[doc(hidden)]
+pub fn duration_abs_diff(actual: Duration, expected: Duration) -> Duration {
+    if actual > expected {
+        actual - expected
+    } else {
+        expected - actual
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    #[test]
+    fn equal() {
+        assert_duration_near!(
+            Duration::from_millis(100),
+            Duration::from_millis(100),
+            Duration::from_millis(0)
+        );
+    }
+
+    #[test]
+    fn just_inside_above() {
+        assert_duration_near!(
+            Duration::from_millis(115),
+            Duration::from_millis(100),
+            Duration::from_millis(15)
+        );
+    }
+
+    #[test]
+    fn just_inside_below() {
+        assert_duration_near!(
+            Duration::from_millis(85),
+            Duration::from_millis(100),
+            Duration::from_millis(15)
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|actual - expected| <= tolerance)`\n    actual: `115.000001ms`,\n    expected: `100ms`,\n    difference: `15.000001ms`,\n    tolerance: `15ms`"
+    )]
+    fn just_outside_above() {
+        assert_duration_near!(
+            Duration::from_nanos(115_000_001),
+            Duration::from_millis(100),
+            Duration::from_millis(15)
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|actual - expected| <= tolerance)`\n    actual: `84.999999ms`,\n    expected: `100ms`,\n    difference: `15.000001ms`,\n    tolerance: `15ms`"
+    )]
+    fn just_outside_below() {
+        assert_duration_near!(
+            Duration::from_nanos(84_999_999),
+            Duration::from_millis(100),
+            Duration::from_millis(15)
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|actual - expected| <= tolerance)`\n    actual: `103.2ms`,\n    expected: `100ms`,\n    difference: `3.2ms`,\n    tolerance: `1ms`: foo"
+    )]
+    fn outside_custom_message() {
+        assert_duration_near!(
+            Duration::from_micros(103_200),
+            Duration::from_millis(100),
+            Duration::from_millis(1),
+            "foo"
+        );
+    }
+
+    #[test]
+    fn value_returned() {
+        let actual = assert_duration_near!(
+            Duration::from_millis(98),
+            Duration::from_millis(100),
+            Duration::from_millis(15)
+        );
+        assert_eq!(actual, Duration::from_millis(98));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_just_inside() {
+        debug_assert_duration_near!(
+            Duration::from_millis(115),
+            Duration::from_millis(100),
+            Duration::from_millis(15)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(|actual - expected| <= tolerance)`\n    actual: `115.000001ms`,\n    expected: `100ms`,\n    difference: `15.000001ms`,\n    tolerance: `15ms`"
+    )]
+    fn debug_just_outside() {
+        debug_assert_duration_near!(
+            Duration::from_nanos(115_000_001),
+            Duration::from_millis(100),
+            Duration::from_millis(15)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(|actual - expected| <= tolerance)`\n    actual: `115.000001ms`,\n    expected: `100ms`,\n    difference: `15.000001ms`,\n    tolerance: `15ms`: foo"
+    )]
+    fn debug_just_outside_custom_message() {
+        debug_assert_duration_near!(
+            Duration::from_nanos(115_000_001),
+            Duration::from_millis(100),
+            Duration::from_millis(15),
+            "foo"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_just_outside() {
+        debug_assert_duration_near!(
+            Duration::from_nanos(115_000_001),
+            Duration::from_millis(100),
+            Duration::from_millis(15)
+        );
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_duration_near!(
+            Duration::from_millis(100),
+            Duration::from_millis(100),
+            Duration::from_millis(15)
+        );
+    }
+}
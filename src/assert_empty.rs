@@ -0,0 +1,303 @@
+/// Asserts that a collection, string, or iterator is empty.
+///
+/// Works with anything that can be iterated over by reference, such as arrays, slices, `Vec`s,
+/// maps, and sets, with cloneable iterators, and with strings. The expression is only borrowed.
+///
+/// On failure, the length and the leftover elements are printed. Only the first 16 elements are
+/// shown, followed by a count of the elements that were left out. Strings, including `&str` and
+/// `String`, are printed in full instead, with their length in bytes.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_empty!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # use std::collections::HashMap;
+/// # fn main() {
+/// let values: Vec<u32> = Vec::new();
+/// assert_empty!(values);
+///
+/// let map: HashMap<String, u32> = HashMap::new();
+/// assert_empty!(map);
+///
+/// let stderr = String::new();
+/// assert_empty!(stderr);
+///
+/// assert_empty!(values.iter().filter(|value| **value > 3));
+///
+/// // With a custom message
+/// assert_empty!(values, "Expecting {:?} to be empty", values);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_empty!(vec![1, 2, 3]);  // Will panic
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_empty!("error: file not found");  // Will panic
+/// # }
+/// ```
+///
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_empty!`]: crate::debug_assert_empty!
+#[macro_export]
+macro_rules! assert_empty {
+    ($collection:expr $(,)?) => {
+        match &$collection {
+            collection_val => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+                // Only the first element is looked at on success. The whole collection is only
+                // counted and printed once the assertion has failed.
+                if (&&&&&$crate::__private::Collection(collection_val)).view().first().is_ok() {
+                    let description = (&&&&&$crate::__private::Collection(collection_val)).view().describe();
+                    ::core::panic!(r#"assertion failed, expected {} to be empty
+    length: `{}`,
+    {}: `{:?}`"#, description.kind, description.length, description.kind, description.contents)
+                }
+            }
+        }
+    };
+    ($collection:expr, $($arg:tt)+) => {
+        match &$collection {
+            collection_val => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+                // Only the first element is looked at on success. The whole collection is only
+                // counted and printed once the assertion has failed.
+                if (&&&&&$crate::__private::Collection(collection_val)).view().first().is_ok() {
+                    let description = (&&&&&$crate::__private::Collection(collection_val)).view().describe();
+                    ::core::panic!(r#"assertion failed, expected {} to be empty
+    length: `{}`,
+    {}: `{:?}`: {}"#, description.kind, description.length, description.kind, description.contents, ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that a collection, string, or iterator is empty on debug builds.
+///
+/// This macro behaves the same as [`assert_empty!`] on debug builds. On release builds it is a
+/// no-op.
+#[macro_export]
+macro_rules! debug_assert_empty {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_empty!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{BTreeMap, HashMap, HashSet},
+        iter,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+
+    #[test]
+    fn array() {
+        let values: [u32; 0] = [];
+        assert_empty!(values);
+    }
+
+    #[test]
+    fn slice() {
+        let values: &[u32] = &[];
+        assert_empty!(values);
+    }
+
+    #[test]
+    fn vec() {
+        let values: Vec<u32> = Vec::new();
+        assert_empty!(values);
+        assert_eq!(values.len(), 0);
+    }
+
+    #[test]
+    fn vec_by_reference() {
+        let values: Vec<u32> = Vec::new();
+        assert_empty!(&values);
+    }
+
+    #[test]
+    fn hash_map() {
+        let map: HashMap<String, u32> = HashMap::new();
+        assert_empty!(map);
+    }
+
+    #[test]
+    fn hash_set() {
+        let set: HashSet<u32> = HashSet::new();
+        assert_empty!(set);
+    }
+
+    #[test]
+    fn str() {
+        assert_empty!("");
+    }
+
+    #[test]
+    fn string() {
+        let string = String::new();
+        assert_empty!(string);
+        assert_empty!(&string);
+    }
+
+    #[test]
+    fn iterator() {
+        assert_empty!(iter::empty::<u32>());
+        assert_empty!(0..0);
+    }
+
+    #[test]
+    fn iterator_filtered() {
+        let values = [1, 2, 3];
+        assert_empty!(values.iter().filter(|value| **value > 3));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to be empty\n    length: `3`,\n    collection: `[1, 2, 3]`"
+    )]
+    fn array_not_empty() {
+        assert_empty!([1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to be empty\n    length: `3`,\n    collection: `[1, 2, 3]`"
+    )]
+    fn slice_not_empty() {
+        let values: &[u32] = &[1, 2, 3];
+        assert_empty!(values);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to be empty\n    length: `3`,\n    collection: `[1, 2, 3]`"
+    )]
+    fn vec_not_empty() {
+        assert_empty!(vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to be empty\n    length: `3`,\n    collection: `[1, 2, 3]`"
+    )]
+    fn vec_by_reference_not_empty() {
+        let values = vec![1, 2, 3];
+        assert_empty!(&values);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to be empty\n    length: `2`,\n    collection: `[(\"bar\", 2), (\"foo\", 1)]`"
+    )]
+    fn map_not_empty() {
+        let map: BTreeMap<&str, u32> = [("foo", 1), ("bar", 2)].iter().copied().collect();
+        assert_empty!(map);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected string to be empty\n    length: `21`,\n    string: `\"error: file not found\"`"
+    )]
+    fn str_not_empty() {
+        assert_empty!("error: file not found");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected string to be empty\n    length: `3`,\n    string: `\"foo\"`"
+    )]
+    fn string_not_empty() {
+        assert_empty!("foo".to_string());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to be empty\n    length: `2`,\n    iterator: `[3, 4]`"
+    )]
+    fn iterator_not_empty() {
+        let values = [1, 2, 3, 4];
+        assert_empty!(values.iter().filter(|value| **value > 2));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to be empty\n    length: `100`,\n    collection: `[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, ... (84 more)]`"
+    )]
+    fn truncated() {
+        let values: Vec<u32> = (0..100).collect();
+        assert_empty!(values);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to be empty\n    length: `3`,\n    collection: `[1, 2, 3]`: foo"
+    )]
+    fn not_empty_custom_message() {
+        assert_empty!([1, 2, 3], "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected string to be empty\n    length: `3`,\n    string: `\"foo\"`: bar"
+    )]
+    fn string_not_empty_custom_message() {
+        assert_empty!("foo", "bar");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_vec() {
+        let values: Vec<u32> = Vec::new();
+        debug_assert_empty!(values);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected collection to be empty\n    length: `3`,\n    collection: `[1, 2, 3]`"
+    )]
+    fn debug_vec_not_empty() {
+        debug_assert_empty!(vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected collection to be empty\n    length: `3`,\n    collection: `[1, 2, 3]`: foo"
+    )]
+    fn debug_vec_not_empty_custom_message() {
+        debug_assert_empty!(vec![1, 2, 3], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_vec_not_empty() {
+        debug_assert_empty!(vec![1, 2, 3]);
+    }
+}
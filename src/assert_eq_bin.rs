@@ -0,0 +1,178 @@
+use crate::assert_eq_hex::Radix;
+use core::fmt;
+
+/// Asserts that two expressions are equal to each other, printing both in binary on failure.
+///
+/// This macro behaves like [`assert_eq!`], but formats both values with `{:#b}` in the panic
+/// message, zero-padded to the width of the type. Works with all primitive integer types, as well
+/// as byte slices and byte arrays of up to 32 elements, whose bytes are each printed as eight
+/// binary digits. With the `std` feature, `Vec<u8>` is supported as well. Longer arrays can be
+/// compared by slicing them.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_eq_bin!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let flags: u8 = 0b0000_0110;
+/// assert_eq_bin!(flags, 0b0000_0110);
+/// assert_eq_bin!([0b1010_1010, 0b0101_0101], [0b1010_1010, 0b0101_0101]);
+///
+/// // With a custom message
+/// assert_eq_bin!(flags, 0b0000_0110, "Expecting flags {:#b}", 0b0000_0110);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_eq_bin!(0b0000_0110u8, 0b0000_0100);  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_eq!`]: https://doc.rust-lang.org/core/macro.assert_eq.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_eq_bin!`]: crate::debug_assert_eq_bin!
+#[macro_export]
+macro_rules! assert_eq_bin {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    ::core::panic!(r#"assertion failed: `(left == right)`
+    left: `{:?}`,
+    right: `{:?}`"#, $crate::__private::Bin(left_val), $crate::__private::Bin(right_val))
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    ::core::panic!(r#"assertion failed: `(left == right)`
+    left: `{:?}`,
+    right: `{:?}`: {}"#, $crate::__private::Bin(left_val), $crate::__private::Bin(right_val), ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two expressions are equal to each other, printing both in binary on failure, on
+/// debug builds.
+///
+/// This macro behaves the same as [`assert_eq_bin!`] on debug builds. On release builds it is a
+/// no-op.
+#[macro_export]
+macro_rules! debug_assert_eq_bin {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_eq_bin!($($arg)*);
+        }
+    }};
+}
+
+/// Wrapper formatting a value in binary through its [`Debug`] implementation.
+///
+/// This is an implementation detail of [`assert_eq_bin!`] and is not part of the public API.
+///
+/// [`Debug`]: core::fmt::Debug
+#[doc(hidden)]
+pub struct Bin<'a, T: ?Sized>(pub &'a T);
+
+impl<T> fmt::Debug for Bin<'_, T>
+where
+    T: Radix + ?Sized,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt_bin(formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn equal() {
+        assert_eq_bin!(0b0110u32, 0b0110);
+    }
+
+    #[test]
+    fn equal_bytes() {
+        assert_eq_bin!([0xde, 0xad, 0xbe, 0xef], [0xde, 0xad, 0xbe, 0xef]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left == right)`\n    left: `0b00000000000000000000000000000110`,\n    right: `0b00000000000000000000000000000100`"
+    )]
+    fn not_equal() {
+        assert_eq_bin!(0b0110u32, 0b0100);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left == right)`\n    left: `0b00000110`,\n    right: `0b10000000`"
+    )]
+    fn not_equal_u8() {
+        assert_eq_bin!(0b0110u8, 0b1000_0000);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left == right)`\n    left: `[0b11011110, 0b10101101, 0b10111110, 0b11101111]`,\n    right: `[0b11011110, 0b10101101, 0b00001011, 0b11101111]`"
+    )]
+    fn not_equal_bytes() {
+        assert_eq_bin!([0xde, 0xad, 0xbe, 0xef], [0xde, 0xad, 0x0b, 0xef]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left == right)`\n    left: `0b00000110`,\n    right: `0b10000000`: foo"
+    )]
+    fn not_equal_custom_message() {
+        assert_eq_bin!(0b0110u8, 0b1000_0000, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_equal() {
+        debug_assert_eq_bin!(0b0110u32, 0b0110);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(left == right)`\n    left: `0b00000110`,\n    right: `0b10000000`"
+    )]
+    fn debug_not_equal() {
+        debug_assert_eq_bin!(0b0110u8, 0b1000_0000);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(left == right)`\n    left: `0b00000110`,\n    right: `0b10000000`: foo"
+    )]
+    fn debug_not_equal_custom_message() {
+        debug_assert_eq_bin!(0b0110u8, 0b1000_0000, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_equal() {
+        debug_assert_eq_bin!(0b0110u8, 0b1000_0000);
+    }
+}
@@ -0,0 +1,321 @@
+use core::fmt;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Asserts that two expressions are equal to each other, printing both in hexadecimal on failure.
+///
+/// This macro behaves like [`assert_eq!`], but formats both values with `{:#x}` in the panic
+/// message, zero-padded to the width of the type. Works with all primitive integer types, as well
+/// as byte slices and byte arrays of up to 32 elements, whose bytes are each printed as a
+/// hexadecimal pair. With the `std` feature, `Vec<u8>` is supported as well. Longer arrays can be
+/// compared by slicing them.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_eq_hex!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let opcode: u8 = 0x2a;
+/// assert_eq_hex!(opcode, 0x2a);
+/// assert_eq_hex!([0xde, 0xad, 0xbe, 0xef], [0xde, 0xad, 0xbe, 0xef]);
+///
+/// // With a custom message
+/// assert_eq_hex!(opcode, 0x2a, "Expecting opcode {:#x}", 0x2a);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_eq_hex!(0xdeadbeefu32, 0xdeadbeee);  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_eq!`]: https://doc.rust-lang.org/core/macro.assert_eq.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_eq_hex!`]: crate::debug_assert_eq_hex!
+#[macro_export]
+macro_rules! assert_eq_hex {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    ::core::panic!(r#"assertion failed: `(left == right)`
+    left: `{:?}`,
+    right: `{:?}`"#, $crate::__private::Hex(left_val), $crate::__private::Hex(right_val))
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    ::core::panic!(r#"assertion failed: `(left == right)`
+    left: `{:?}`,
+    right: `{:?}`: {}"#, $crate::__private::Hex(left_val), $crate::__private::Hex(right_val), ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two expressions are equal to each other, printing both in hexadecimal on failure,
+/// on debug builds.
+///
+/// This macro behaves the same as [`assert_eq_hex!`] on debug builds. On release builds it is a
+/// no-op.
+#[macro_export]
+macro_rules! debug_assert_eq_hex {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_eq_hex!($($arg)*);
+        }
+    }};
+}
+
+/// Values which can be formatted in hexadecimal and binary, zero-padded to the width of the type.
+///
+/// This is an implementation detail of [`assert_eq_hex!`] and [`assert_eq_bin!`] and is not part of
+/// the public API.
+#[doc(hidden)]
+pub trait Radix {
+    fn fmt_hex(&self, formatter: &mut fmt::Formatter) -> fmt::Result;
+
+    fn fmt_bin(&self, formatter: &mut fmt::Formatter) -> fmt::Result;
+}
+
+macro_rules! impl_radix_int {
+    ($($ty:ident)*) => {
+        $(
+            impl Radix for $ty {
+                fn fmt_hex(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    write!(formatter, "{:#0width$x}", self, width = 2 + 2 * core::mem::size_of::<$ty>())
+                }
+
+                fn fmt_bin(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    write!(formatter, "{:#0width$b}", self, width = 2 + 8 * core::mem::size_of::<$ty>())
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_int! {u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize}
+
+fn fmt_bytes(
+    bytes: &[u8],
+    formatter: &mut fmt::Formatter,
+    fmt_byte: fn(&u8, &mut fmt::Formatter) -> fmt::Result,
+) -> fmt::Result {
+    formatter.write_str("[")?;
+    for (index, byte) in bytes.iter().enumerate() {
+        if index > 0 {
+            formatter.write_str(", ")?;
+        }
+        fmt_byte(byte, formatter)?;
+    }
+    formatter.write_str("]")
+}
+
+impl Radix for [u8] {
+    fn fmt_hex(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        fmt_bytes(self, formatter, u8::fmt_hex)
+    }
+
+    fn fmt_bin(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        fmt_bytes(self, formatter, u8::fmt_bin)
+    }
+}
+
+macro_rules! impl_radix_array {
+    ($($len:literal)*) => {
+        $(
+            impl Radix for [u8; $len] {
+                fn fmt_hex(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    fmt_bytes(self, formatter, u8::fmt_hex)
+                }
+
+                fn fmt_bin(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    fmt_bytes(self, formatter, u8::fmt_bin)
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_array! {
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16
+    17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+}
+
+#[cfg(feature = "std")]
+impl Radix for Vec<u8> {
+    fn fmt_hex(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        fmt_bytes(self, formatter, u8::fmt_hex)
+    }
+
+    fn fmt_bin(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        fmt_bytes(self, formatter, u8::fmt_bin)
+    }
+}
+
+impl<T> Radix for &T
+where
+    T: Radix + ?Sized,
+{
+    fn fmt_hex(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        T::fmt_hex(*self, formatter)
+    }
+
+    fn fmt_bin(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        T::fmt_bin(*self, formatter)
+    }
+}
+
+/// Wrapper formatting a value in hexadecimal through its [`Debug`] implementation.
+///
+/// This is an implementation detail of [`assert_eq_hex!`] and is not part of the public API.
+///
+/// [`Debug`]: core::fmt::Debug
+#[doc(hidden)]
+pub struct Hex<'a, T: ?Sized>(pub &'a T);
+
+impl<T> fmt::Debug for Hex<'_, T>
+where
+    T: Radix + ?Sized,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt_hex(formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn equal() {
+        assert_eq_hex!(0xdeadbeefu32, 0xdeadbeef);
+    }
+
+    #[test]
+    fn equal_bytes() {
+        assert_eq_hex!([0xde, 0xad, 0xbe, 0xef], [0xde, 0xad, 0xbe, 0xef]);
+    }
+
+    #[test]
+    fn equal_byte_slices() {
+        let left: &[u8] = &[0xde, 0xad];
+        let right: &[u8] = &[0xde, 0xad];
+        assert_eq_hex!(left, right);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left == right)`\n    left: `0xdeadbeef`,\n    right: `0x0000beef`"
+    )]
+    fn not_equal() {
+        assert_eq_hex!(0xdeadbeefu32, 0xbeef);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left == right)`\n    left: `0x0f`,\n    right: `0xf0`"
+    )]
+    fn not_equal_u8() {
+        assert_eq_hex!(0x0fu8, 0xf0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left == right)`\n    left: `0xffffffff`,\n    right: `0x00000000`"
+    )]
+    fn not_equal_signed() {
+        assert_eq_hex!(-1i32, 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left == right)`\n    left: `[0xde, 0xad, 0xbe, 0xef]`,\n    right: `[0xde, 0xad, 0x0b, 0xef]`"
+    )]
+    fn not_equal_bytes() {
+        assert_eq_hex!([0xde, 0xad, 0xbe, 0xef], [0xde, 0xad, 0x0b, 0xef]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left == right)`\n    left: `[0xde, 0xad]`,\n    right: `[0xde]`"
+    )]
+    fn not_equal_byte_slices() {
+        let left: &[u8] = &[0xde, 0xad];
+        let right: &[u8] = &[0xde];
+        assert_eq_hex!(left, right);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[should_panic(
+        expected = "assertion failed: `(left == right)`\n    left: `[0xde, 0xad]`,\n    right: `[0xde, 0x0d]`"
+    )]
+    fn not_equal_byte_vecs() {
+        assert_eq_hex!(std::vec![0xdeu8, 0xad], std::vec![0xde, 0x0d]);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: `(left == right)`\n    left: `[0x00, 0x00, 0x00,")]
+    fn not_equal_long_arrays_sliced() {
+        let left = [0u8; 64];
+        let mut right = [0u8; 64];
+        right[63] = 0xff;
+        assert_eq_hex!(left[..], right[..]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left == right)`\n    left: `0xdeadbeef`,\n    right: `0x0000beef`: foo"
+    )]
+    fn not_equal_custom_message() {
+        assert_eq_hex!(0xdeadbeefu32, 0xbeef, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_equal() {
+        debug_assert_eq_hex!(0xdeadbeefu32, 0xdeadbeef);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(left == right)`\n    left: `0xdeadbeef`,\n    right: `0x0000beef`"
+    )]
+    fn debug_not_equal() {
+        debug_assert_eq_hex!(0xdeadbeefu32, 0xbeef);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(left == right)`\n    left: `0xdeadbeef`,\n    right: `0x0000beef`: foo"
+    )]
+    fn debug_not_equal_custom_message() {
+        debug_assert_eq_hex!(0xdeadbeefu32, 0xbeef, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_equal() {
+        debug_assert_eq_hex!(0xdeadbeefu32, 0xbeef);
+    }
+}
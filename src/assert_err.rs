@@ -1,5 +1,8 @@
 /// Asserts that the expression matches an [`Err(_)`] variant, returning the contained value.
 ///
+/// On failure, the `Ok` value is printed with its [`Debug`] implementation if it has one, and as
+/// `<non-Debug>` otherwise.
+///
 /// ## Uses
 ///
 /// Assertions are always checked in both debug and release builds, and cannot be disabled.
@@ -37,19 +40,28 @@
 ///
 /// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
 /// [`Err(_)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
 /// [`debug_assert_err!`]: crate::debug_assert_err!
 #[macro_export]
 macro_rules! assert_err {
     ($cond:expr $(,)?) => {
         match $cond {
             ::core::result::Result::Err(e) => e,
-            ::core::result::Result::Ok(t) => ::core::panic!("assertion failed, expected Err(_), got Ok({:?})", t),
+            ::core::result::Result::Ok(t) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!("assertion failed, expected Err(_), got Ok({:?})", (&$crate::__private::MaybeDebug(&t)).maybe_debug());
+            }
         }
     };
     ($cond:expr, $($arg:tt)+) => {
         match $cond {
             ::core::result::Result::Err(e) => e,
-            ::core::result::Result::Ok(t) => ::core::panic!("assertion failed, expected Err(_), got Ok({:?}): {}", t, ::core::format_args!($($arg)+)),
+            ::core::result::Result::Ok(t) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!("assertion failed, expected Err(_), got Ok({:?}): {}", (&$crate::__private::MaybeDebug(&t)).maybe_debug(), ::core::format_args!($($arg)+));
+            }
         }
     };
 }
@@ -62,10 +74,13 @@ macro_rules! assert_err {
 /// [`Err(_)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
 #[macro_export]
 macro_rules! debug_assert_err {
-    ($($arg:tt)*) => {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
         #[cfg(debug_assertions)]
-        $crate::assert_err!($($arg)*);
-    }
+        {
+            $crate::assert_err!($($arg)*);
+        }
+    }};
 }
 
 #[cfg(test)]
@@ -119,6 +134,22 @@ mod tests {
         debug_assert_err!(Ok::<_, ()>(()));
     }
 
+    #[test]
+    fn debug_err_returns_unit() {
+        let () = debug_assert_err!(Err::<(), _>(42));
+    }
+
+    #[test]
+    fn debug_err_returns_unit_custom_message() {
+        let () = debug_assert_err!(Err::<(), _>(42), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_err_returns_unit() {
+        let () = debug_assert_err!(Ok::<_, ()>(()));
+    }
+
     #[test]
     fn does_not_require_err_to_impl_debug() {
         enum Foo {
@@ -156,4 +187,48 @@ mod tests {
 
         debug_assert_err!(Err::<(), _>(Foo::Bar), "foo");
     }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Err(_), got Ok(<non-Debug>)")]
+    fn does_not_require_ok_to_impl_debug() {
+        enum Foo {
+            Bar,
+        }
+
+        assert_err!(Ok::<_, ()>(Foo::Bar));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, expected Err(_), got Ok(<non-Debug>)")]
+    fn debug_does_not_require_ok_to_impl_debug() {
+        #[allow(dead_code)]
+        enum Foo {
+            Bar,
+        }
+
+        debug_assert_err!(Ok::<_, ()>(Foo::Bar));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Err(_), got Ok(<non-Debug>): foo")]
+    fn does_not_require_ok_to_impl_debug_custom_message() {
+        enum Foo {
+            Bar,
+        }
+
+        assert_err!(Ok::<_, ()>(Foo::Bar), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, expected Err(_), got Ok(<non-Debug>): foo")]
+    fn debug_does_not_require_ok_to_impl_debug_custom_message() {
+        #[allow(dead_code)]
+        enum Foo {
+            Bar,
+        }
+
+        debug_assert_err!(Ok::<_, ()>(Foo::Bar), "foo");
+    }
 }
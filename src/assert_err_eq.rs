@@ -86,10 +86,13 @@ macro_rules! assert_err_eq {
 /// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
 #[macro_export]
 macro_rules! debug_assert_err_eq {
-    ($($arg:tt)*) => {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
         #[cfg(debug_assertions)]
-        $crate::assert_err_eq!($($arg)*);
-    }
+        {
+            $crate::assert_err_eq!($($arg)*);
+        }
+    }};
 }
 
 #[cfg(test)]
@@ -168,4 +171,20 @@ mod tests {
     fn debug_release_not_err() {
         debug_assert_err_eq!(Ok::<_, usize>(()), 42);
     }
+
+    #[test]
+    fn debug_err_eq_returns_unit() {
+        let () = debug_assert_err_eq!(Err::<(), _>(42), 42);
+    }
+
+    #[test]
+    fn debug_err_eq_returns_unit_custom_message() {
+        let () = debug_assert_err_eq!(Err::<(), _>(42), 42, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_err_eq_returns_unit() {
+        let () = debug_assert_err_eq!(Ok::<_, usize>(()), 42);
+    }
 }
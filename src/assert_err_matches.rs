@@ -0,0 +1,227 @@
+/// Asserts that the expression matches an [`Err(_)`] variant whose contained error matches the
+/// provided pattern.
+///
+/// This combines [`assert_err!`] and [`assert_matches!`] into a single call. As with
+/// [`assert_matches!`], the pattern can contain multiple alternatives separated by `|` and can be
+/// followed by an `if` guard, and the contained error must implement [`Debug`] so that it can be
+/// printed when it does not match.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_err_matches!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// #[derive(Debug)]
+/// enum Error {
+///     NotFound(String),
+///     PermissionDenied(String),
+///     Timeout,
+/// }
+///
+/// let res: Result<(), Error> = Err(Error::NotFound("config.toml".to_string()));
+///
+/// assert_err_matches!(&res, Error::NotFound(_));
+/// assert_err_matches!(
+///     &res,
+///     Error::NotFound(path) | Error::PermissionDenied(path) if path.ends_with(".toml")
+/// );
+///
+/// // With a custom message
+/// assert_err_matches!(res, Error::NotFound(_), "Expecting a missing file");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let res: Result<(), Option<i32>> = Err(None);
+///
+/// assert_err_matches!(res, Some(_));  // Will panic
+/// # }
+/// ```
+///
+/// [`Err(_)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_err!`]: crate::assert_err!
+/// [`assert_matches!`]: crate::assert_matches!
+/// [`debug_assert_err_matches!`]: crate::debug_assert_err_matches!
+#[macro_export]
+macro_rules! assert_err_matches {
+    ($cond:expr, $($pattern:pat)|+ $(if $guard:expr)? $(,)?) => {
+        match $cond {
+            ::core::result::Result::Err(e) => {
+                #[allow(unreachable_patterns)]
+                match e {
+                    $($pattern)|+ $(if $guard)? => {},
+                    other => {
+                        ::core::panic!(r#"assertion failed, expected Err(_) to match the given pattern
+    error: `{:?}`,
+    pattern: `{}`"#, other, ::core::stringify!($($pattern)|+ $(if $guard)?));
+                    }
+                }
+            }
+            ::core::result::Result::Ok(_) => {
+                ::core::panic!(r#"assertion failed, expected Err(_), got Ok(_)
+    expression: `{}`"#, ::core::stringify!($cond));
+            }
+        }
+    };
+    ($cond:expr, $($pattern:pat)|+ $(if $guard:expr)?, $($arg:tt)+) => {
+        match $cond {
+            ::core::result::Result::Err(e) => {
+                #[allow(unreachable_patterns)]
+                match e {
+                    $($pattern)|+ $(if $guard)? => {},
+                    other => {
+                        ::core::panic!(r#"assertion failed, expected Err(_) to match the given pattern
+    error: `{:?}`,
+    pattern: `{}`: {}"#, other, ::core::stringify!($($pattern)|+ $(if $guard)?), ::core::format_args!($($arg)+));
+                    }
+                }
+            }
+            ::core::result::Result::Ok(_) => {
+                ::core::panic!(r#"assertion failed, expected Err(_), got Ok(_)
+    expression: `{}`: {}"#, ::core::stringify!($cond), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that the expression matches an [`Err(_)`] variant whose contained error matches the
+/// provided pattern on debug builds.
+///
+/// This macro behaves the same as [`assert_err_matches!`] on debug builds. On release builds it is
+/// a no-op.
+///
+/// [`Err(_)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+#[macro_export]
+macro_rules! debug_assert_err_matches {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_err_matches!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    enum Foo {
+        Bar(usize),
+        Baz(usize),
+    }
+
+    #[test]
+    fn matches() {
+        assert_err_matches!(Err::<(), _>(Foo::Bar(42)), Foo::Bar(_));
+    }
+
+    #[test]
+    fn matches_multiple_variants() {
+        assert_err_matches!(Err::<(), _>(Foo::Baz(42)), Foo::Bar(_) | Foo::Baz(_));
+    }
+
+    #[test]
+    fn matches_with_guard() {
+        assert_err_matches!(Err::<(), _>(Foo::Bar(42)), Foo::Bar(x) if x > 40);
+    }
+
+    #[test]
+    fn matches_multiple_variants_with_guard() {
+        assert_err_matches!(Err::<(), _>(Foo::Baz(42)), Foo::Bar(x) | Foo::Baz(x) if x > 40);
+    }
+
+    #[test]
+    fn matches_reference() {
+        let res: Result<(), Foo> = Err(Foo::Bar(42));
+        assert_err_matches!(&res, Foo::Bar(_));
+        assert_err_matches!(res, Foo::Bar(42));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Err(_) to match the given pattern\n    error: `Baz(42)`,\n    pattern: `Foo::Bar(_)`"
+    )]
+    fn not_matches() {
+        assert_err_matches!(Err::<(), _>(Foo::Baz(42)), Foo::Bar(_));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Err(_) to match the given pattern\n    error: `Bar(42)`,\n    pattern: `Foo::Bar(x) if x > 100`"
+    )]
+    fn not_matches_guard() {
+        assert_err_matches!(Err::<(), _>(Foo::Bar(42)), Foo::Bar(x) if x > 100);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Err(_) to match the given pattern\n    error: `Baz(42)`,\n    pattern: `Foo::Bar(_)`: foo"
+    )]
+    fn not_matches_custom_message() {
+        assert_err_matches!(Err::<(), _>(Foo::Baz(42)), Foo::Bar(_), "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Err(_), got Ok(_)\n    expression: `res`"
+    )]
+    fn not_err() {
+        let res: Result<(), Foo> = Ok(());
+        assert_err_matches!(res, Foo::Bar(_));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Err(_), got Ok(_)\n    expression: `res`: foo"
+    )]
+    fn not_err_custom_message() {
+        let res: Result<(), Foo> = Ok(());
+        assert_err_matches!(res, Foo::Bar(_), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_matches() {
+        debug_assert_err_matches!(Err::<(), _>(Foo::Bar(42)), Foo::Bar(_));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Err(_) to match the given pattern\n    error: `Baz(42)`,\n    pattern: `Foo::Bar(_)`"
+    )]
+    fn debug_not_matches() {
+        debug_assert_err_matches!(Err::<(), _>(Foo::Baz(42)), Foo::Bar(_));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Err(_) to match the given pattern\n    error: `Baz(42)`,\n    pattern: `Foo::Bar(_)`: foo"
+    )]
+    fn debug_not_matches_custom_message() {
+        debug_assert_err_matches!(Err::<(), _>(Foo::Baz(42)), Foo::Bar(_), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_matches() {
+        debug_assert_err_matches!(Err::<(), _>(Foo::Baz(42)), Foo::Bar(_));
+    }
+}
@@ -0,0 +1,312 @@
+/// Asserts that the expression matches an [`Err(_)`] variant whose contained error satisfies the
+/// predicate, returning the contained error.
+///
+/// The predicate is called with a reference to the contained error, and can either be a closure,
+/// such as `|e| e.kind() == ErrorKind::NotFound`, or a named binding followed by an expression,
+/// such as `e => e.is_timeout()`. This allows checking properties of an error without requiring
+/// the error type to implement [`PartialEq`], as [`assert_err_eq!`] does. An enum variant can be
+/// checked by using [`assert_matches!`] within the predicate.
+///
+/// If the error does not satisfy the predicate, the stringified predicate and the error are
+/// printed. The error is printed with its [`Debug`] implementation if it has one, and as
+/// `<non-Debug>` otherwise.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_err_satisfies!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// #[derive(Debug)]
+/// enum Error {
+///     NotFound(String),
+///     Timeout,
+/// }
+///
+/// impl Error {
+///     fn is_timeout(&self) -> bool {
+///         if let Error::Timeout = self { true } else { false }
+///     }
+/// }
+///
+/// let res: Result<(), Error> = Err(Error::NotFound("config.toml".to_string()));
+///
+/// assert_err_satisfies!(&res, |e| match e {
+///     Error::NotFound(path) => path.ends_with(".toml"),
+///     _ => false,
+/// });
+///
+/// // With a custom message
+/// assert_err_satisfies!(res, e => !e.is_timeout(), "Timed out");
+/// # }
+/// ```
+///
+/// The contained error will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let res: Result<(), &str> = Err("not found");
+///
+/// let error = assert_err_satisfies!(res, |e| e.starts_with("not"));
+/// assert_eq!(error, "not found");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let res: Result<(), &str> = Err("timeout");
+///
+/// assert_err_satisfies!(res, |e| e.starts_with("not"));  // Will panic
+/// # }
+/// ```
+///
+/// [`Err(_)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_err_eq!`]: crate::assert_err_eq!
+/// [`assert_matches!`]: crate::assert_matches!
+/// [`debug_assert_err_satisfies!`]: crate::debug_assert_err_satisfies!
+#[macro_export]
+macro_rules! assert_err_satisfies {
+    ($cond:expr, $binding:ident => $predicate:expr $(,)?) => {
+        match $cond {
+            ::core::result::Result::Err(e) => {
+                if !$crate::__private::satisfies(&e, |$binding| $predicate) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected Err(_) to satisfy predicate
+    predicate: `{} => {}`,
+    error: `{:?}`"#, ::core::stringify!($binding), ::core::stringify!($predicate), (&$crate::__private::MaybeDebug(&e)).maybe_debug());
+                }
+                e
+            }
+            ::core::result::Result::Ok(_) => {
+                ::core::panic!(r#"assertion failed, expected Err(_), got Ok(_)
+    expression: `{}`"#, ::core::stringify!($cond));
+            }
+        }
+    };
+    ($cond:expr, $binding:ident => $predicate:expr, $($arg:tt)+) => {
+        match $cond {
+            ::core::result::Result::Err(e) => {
+                if !$crate::__private::satisfies(&e, |$binding| $predicate) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected Err(_) to satisfy predicate
+    predicate: `{} => {}`,
+    error: `{:?}`: {}"#, ::core::stringify!($binding), ::core::stringify!($predicate), (&$crate::__private::MaybeDebug(&e)).maybe_debug(), ::core::format_args!($($arg)+));
+                }
+                e
+            }
+            ::core::result::Result::Ok(_) => {
+                ::core::panic!(r#"assertion failed, expected Err(_), got Ok(_)
+    expression: `{}`: {}"#, ::core::stringify!($cond), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+    ($cond:expr, $predicate:expr $(,)?) => {
+        match $cond {
+            ::core::result::Result::Err(e) => {
+                if !$crate::__private::satisfies(&e, $predicate) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected Err(_) to satisfy predicate
+    predicate: `{}`,
+    error: `{:?}`"#, ::core::stringify!($predicate), (&$crate::__private::MaybeDebug(&e)).maybe_debug());
+                }
+                e
+            }
+            ::core::result::Result::Ok(_) => {
+                ::core::panic!(r#"assertion failed, expected Err(_), got Ok(_)
+    expression: `{}`"#, ::core::stringify!($cond));
+            }
+        }
+    };
+    ($cond:expr, $predicate:expr, $($arg:tt)+) => {
+        match $cond {
+            ::core::result::Result::Err(e) => {
+                if !$crate::__private::satisfies(&e, $predicate) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected Err(_) to satisfy predicate
+    predicate: `{}`,
+    error: `{:?}`: {}"#, ::core::stringify!($predicate), (&$crate::__private::MaybeDebug(&e)).maybe_debug(), ::core::format_args!($($arg)+));
+                }
+                e
+            }
+            ::core::result::Result::Ok(_) => {
+                ::core::panic!(r#"assertion failed, expected Err(_), got Ok(_)
+    expression: `{}`: {}"#, ::core::stringify!($cond), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that the expression matches an [`Err(_)`] variant whose contained error satisfies the
+/// predicate on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_err_satisfies!`] on debug builds, although it
+/// does not return the error contained in the `Err` variant. On release builds it is a no-op.
+///
+/// [`Err(_)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+#[macro_export]
+macro_rules! debug_assert_err_satisfies {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_err_satisfies!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug)]
+    enum Error {
+        NotFound(&'static str),
+        Timeout,
+    }
+
+    #[test]
+    fn err_satisfies() {
+        let res: Result<(), _> = Err(Error::NotFound("foo"));
+        assert_err_satisfies!(res, |e| match e {
+            Error::NotFound(path) => *path == "foo",
+            Error::Timeout => false,
+        });
+    }
+
+    #[test]
+    fn err_satisfies_binding() {
+        let res: Result<(), _> = Err(Error::Timeout);
+        assert_err_satisfies!(res, e => match e {
+            Error::Timeout => true,
+            Error::NotFound(_) => false,
+        });
+    }
+
+    #[test]
+    fn err_satisfies_assert_matches() {
+        let res: Result<(), _> = Err(Error::Timeout);
+        assert_err_satisfies!(res, |e| {
+            crate::assert_matches!(e, Error::Timeout);
+            true
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Err(_) to satisfy predicate\n    predicate: `|e| e.starts_with(\"not\")`,\n    error: `\"timeout\"`"
+    )]
+    fn err_not_satisfies() {
+        let res: Result<(), &str> = Err("timeout");
+        assert_err_satisfies!(res, |e| e.starts_with("not"));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Err(_) to satisfy predicate\n    predicate: `e => e.starts_with(\"not\")`,\n    error: `\"timeout\"`"
+    )]
+    fn err_not_satisfies_binding() {
+        let res: Result<(), &str> = Err("timeout");
+        assert_err_satisfies!(res, e => e.starts_with("not"));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Err(_) to satisfy predicate\n    predicate: `|_| false`,\n    error: `<non-Debug>`"
+    )]
+    fn err_not_satisfies_non_debug() {
+        struct NonDebug;
+
+        assert_err_satisfies!(Err::<(), _>(NonDebug), |_| false);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Err(_) to satisfy predicate\n    predicate: `|e| e.starts_with(\"not\")`,\n    error: `\"timeout\"`: foo"
+    )]
+    fn err_not_satisfies_custom_message() {
+        let res: Result<(), &str> = Err("timeout");
+        assert_err_satisfies!(res, |e| e.starts_with("not"), "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Err(_), got Ok(_)\n    expression: `res`"
+    )]
+    fn not_err() {
+        let res: Result<(), &str> = Ok(());
+        assert_err_satisfies!(res, |e| e.is_empty());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Err(_), got Ok(_)\n    expression: `res`: foo"
+    )]
+    fn not_err_custom_message() {
+        let res: Result<(), &str> = Ok(());
+        assert_err_satisfies!(res, e => e.is_empty(), "foo");
+    }
+
+    #[test]
+    fn error_returned() {
+        let res: Result<(), &str> = Err("not found");
+        let error = assert_err_satisfies!(res, |e| e.starts_with("not"));
+        assert_eq!(error, "not found");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_err_satisfies() {
+        let res: Result<(), &str> = Err("not found");
+        debug_assert_err_satisfies!(res, |e| e.starts_with("not"));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Err(_) to satisfy predicate\n    predicate: `|e| e.starts_with(\"not\")`,\n    error: `\"timeout\"`"
+    )]
+    fn debug_err_not_satisfies() {
+        let res: Result<(), &str> = Err("timeout");
+        debug_assert_err_satisfies!(res, |e| e.starts_with("not"));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Err(_) to satisfy predicate\n    predicate: `|e| e.starts_with(\"not\")`,\n    error: `\"timeout\"`: foo"
+    )]
+    fn debug_err_not_satisfies_custom_message() {
+        let res: Result<(), &str> = Err("timeout");
+        debug_assert_err_satisfies!(res, |e| e.starts_with("not"), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_err_not_satisfies() {
+        let res: Result<(), &str> = Err("timeout");
+        debug_assert_err_satisfies!(res, |e| e.starts_with("not"));
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let res: Result<(), &str> = Err("not found");
+        let () = debug_assert_err_satisfies!(res, |e| e.starts_with("not"));
+    }
+}
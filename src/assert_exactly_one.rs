@@ -0,0 +1,128 @@
+/// Asserts that an iterator produces exactly one element, returning that element.
+///
+/// The expression can be anything implementing [`IntoIterator`], and is consumed. This is a
+/// specialized form of [`assert_count_eq!`]`(iter, 1)` which also returns the single element for
+/// further assertions. It is an alias of [`assert_single!`], and panics with the same message.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_exactly_one!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let users = vec![("alice", 3), ("bob", 5)];
+///
+/// assert_exactly_one!(users.iter().filter(|(name, _)| *name == "bob"));
+///
+/// // With a custom message
+/// assert_exactly_one!(users.iter().filter(|(_, score)| *score > 4), "Expecting one winner");
+/// # }
+/// ```
+///
+/// The element will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let users = vec![("alice", 3), ("bob", 5)];
+///
+/// let (_, score) = assert_exactly_one!(users.iter().filter(|(name, _)| *name == "bob"));
+/// assert_eq!(*score, 5);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_exactly_one!((0..10).filter(|n| n % 5 == 0));  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`assert_count_eq!`]: crate::assert_count_eq!
+/// [`assert_single!`]: crate::assert_single!
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_exactly_one!`]: crate::debug_assert_exactly_one!
+#[macro_export]
+macro_rules! assert_exactly_one {
+    ($($arg:tt)*) => {
+        $crate::assert_single!($($arg)*)
+    };
+}
+
+/// Asserts that an iterator produces exactly one element on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_exactly_one!`] on debug builds, although it does
+/// not return the element. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_exactly_one {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_exactly_one!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{iter, string::String, vec};
+
+    #[test]
+    fn exactly_one() {
+        assert_exactly_one!(iter::once(1));
+    }
+
+    #[test]
+    fn element_returned() {
+        let element = assert_exactly_one!(vec![String::from("foo")]);
+        assert_eq!(element, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected exactly 1 element, got 2\n    expression: `(0..10).filter(|n| n % 5 == 0)`,\n    elements: `[0, 5]`"
+    )]
+    fn two() {
+        assert_exactly_one!((0..10).filter(|n| n % 5 == 0));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected exactly 1 element, got 2\n    expression: `(0..10).filter(|n| n % 5 == 0)`,\n    elements: `[0, 5]`: foo"
+    )]
+    fn two_custom_message() {
+        assert_exactly_one!((0..10).filter(|n| n % 5 == 0), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected exactly 1 element, got 2\n    expression: `(0..10).filter(|n| n % 5 == 0)`,\n    elements: `[0, 5]`"
+    )]
+    fn debug_two() {
+        debug_assert_exactly_one!((0..10).filter(|n| n % 5 == 0));
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_two() {
+        debug_assert_exactly_one!((0..10).filter(|n| n % 5 == 0));
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_exactly_one!(iter::once(1));
+    }
+}
@@ -0,0 +1,247 @@
+/// Asserts that an iterator produces no more elements.
+///
+/// This is intended for checking that nothing trails the expected elements of an iterator after
+/// they have been consumed. The iterator expression is advanced by calling [`Iterator::next()`] on
+/// a mutable reference to it, so it can be passed by name, as `&mut iter`, or as an owned iterator.
+///
+/// By default, the iterator is advanced only once, and on failure the panic message includes the
+/// iterator expression as written in the source along with the unexpected element. When `all` is
+/// given as the second argument, the iterator is instead drained on failure, and the number of
+/// extra elements is reported as well. This will not terminate for infinite iterators. The element
+/// is printed with its [`Debug`] implementation if it has one, and as `<non-Debug>` otherwise.
+///
+/// This performs the same check as [`assert_next_none!`], which is better suited to iterators
+/// driven step by step.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_exhausted!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has another form, where a custom panic message can be provided with or without
+/// arguments for formatting, either directly after the iterator or after `all`. See [`std::fmt`]
+/// for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let mut words = "alpha beta".split(' ');
+/// assert_eq!(words.next(), Some("alpha"));
+/// assert_eq!(words.next(), Some("beta"));
+///
+/// assert_exhausted!(words);
+/// assert_exhausted!(&mut words, all);
+///
+/// // With a custom message
+/// assert_exhausted!(words, "Expecting exactly two words");
+/// assert_exhausted!(words, all, "Expecting exactly two words");
+/// # }
+/// ```
+///
+/// An iterator producing another element will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let mut words = "alpha beta gamma delta".split(' ');
+/// assert_eq!(words.next(), Some("alpha"));
+/// assert_eq!(words.next(), Some("beta"));
+///
+/// assert_exhausted!(words, all);  // Will panic, reporting `2` extra elements
+/// # }
+/// ```
+///
+/// [`Iterator::next()`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#tymethod.next
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_next_none!`]: crate::assert_next_none!
+/// [`debug_assert_exhausted!`]: crate::debug_assert_exhausted!
+#[macro_export]
+macro_rules! assert_exhausted {
+    ($iter:expr, all $(,)?) => {
+        match &mut $iter {
+            iter => {
+                if let ::core::option::Option::Some(next) = ::core::iter::Iterator::next(iter) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected iterator to be exhausted, got `{}` more elements
+    iterator: `{}`,
+    next: `{:?}`"#, 1 + ::core::iter::Iterator::count(iter), ::core::stringify!($iter), (&$crate::__private::MaybeDebug(&next)).maybe_debug());
+                }
+            }
+        }
+    };
+    ($iter:expr, all, $($arg:tt)+) => {
+        match &mut $iter {
+            iter => {
+                if let ::core::option::Option::Some(next) = ::core::iter::Iterator::next(iter) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected iterator to be exhausted, got `{}` more elements
+    iterator: `{}`,
+    next: `{:?}`: {}"#, 1 + ::core::iter::Iterator::count(iter), ::core::stringify!($iter), (&$crate::__private::MaybeDebug(&next)).maybe_debug(), ::core::format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($iter:expr $(,)?) => {
+        if let ::core::option::Option::Some(next) = ::core::iter::Iterator::next(&mut $iter) {
+            #[allow(unused_imports)]
+            use $crate::__private::{IsDebug as _, IsNotDebug as _};
+            ::core::panic!(r#"assertion failed, expected iterator to be exhausted, got another element
+    iterator: `{}`,
+    next: `{:?}`"#, ::core::stringify!($iter), (&$crate::__private::MaybeDebug(&next)).maybe_debug());
+        }
+    };
+    ($iter:expr, $($arg:tt)+) => {
+        if let ::core::option::Option::Some(next) = ::core::iter::Iterator::next(&mut $iter) {
+            #[allow(unused_imports)]
+            use $crate::__private::{IsDebug as _, IsNotDebug as _};
+            ::core::panic!(r#"assertion failed, expected iterator to be exhausted, got another element
+    iterator: `{}`,
+    next: `{:?}`: {}"#, ::core::stringify!($iter), (&$crate::__private::MaybeDebug(&next)).maybe_debug(), ::core::format_args!($($arg)+));
+        }
+    };
+}
+
+/// Asserts that an iterator produces no more elements on debug builds.
+///
+/// This macro behaves the same as [`assert_exhausted!`] on debug builds. On release builds it is a
+/// no-op, and the iterator is not advanced.
+#[macro_export]
+macro_rules! debug_assert_exhausted {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_exhausted!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{iter, vec};
+
+    #[test]
+    fn exhausted() {
+        let mut iter = vec![1].into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_exhausted!(iter);
+        assert_exhausted!(&mut iter);
+    }
+
+    #[test]
+    fn exhausted_owned() {
+        assert_exhausted!(iter::empty::<u32>());
+    }
+
+    #[test]
+    fn exhausted_all() {
+        let mut iter = vec![1].into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_exhausted!(iter, all);
+        assert_exhausted!(&mut iter, all);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to be exhausted, got another element\n    iterator: `iter`,\n    next: `2`"
+    )]
+    fn one_extra() {
+        let mut iter = vec![1, 2].into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_exhausted!(iter);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to be exhausted, got another element\n    iterator: `iter`,\n    next: `2`: foo"
+    )]
+    fn one_extra_custom_message() {
+        let mut iter = vec![1, 2].into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_exhausted!(iter, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to be exhausted, got another element\n    iterator: `iter::once(NonDebug)`,\n    next: `<non-Debug>`"
+    )]
+    fn one_extra_non_debug() {
+        struct NonDebug;
+
+        assert_exhausted!(iter::once(NonDebug));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to be exhausted, got `1` more elements\n    iterator: `iter`,\n    next: `2`"
+    )]
+    fn all_one_extra() {
+        let mut iter = vec![1, 2].into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_exhausted!(iter, all);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to be exhausted, got `3` more elements\n    iterator: `&mut iter`,\n    next: `2`"
+    )]
+    fn all_many_extra() {
+        let mut iter = vec![1, 2, 3, 4].into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_exhausted!(&mut iter, all);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to be exhausted, got `3` more elements\n    iterator: `iter`,\n    next: `2`: foo"
+    )]
+    fn all_many_extra_custom_message() {
+        let mut iter = vec![1, 2, 3, 4].into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_exhausted!(iter, all, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_exhausted() {
+        let mut iter = iter::empty::<u32>();
+        debug_assert_exhausted!(iter);
+        debug_assert_exhausted!(iter, all);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to be exhausted, got another element\n    iterator: `iter`,\n    next: `1`"
+    )]
+    fn debug_one_extra() {
+        let mut iter = iter::once(1);
+        debug_assert_exhausted!(iter);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to be exhausted, got `2` more elements\n    iterator: `iter`,\n    next: `1`: foo"
+    )]
+    fn debug_all_many_extra_custom_message() {
+        let mut iter = vec![1, 2].into_iter();
+        debug_assert_exhausted!(iter, all, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_one_extra() {
+        let mut iter = iter::once(1);
+        debug_assert_exhausted!(iter);
+        assert_eq!(iter.next(), Some(1));
+    }
+}
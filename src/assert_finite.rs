@@ -0,0 +1,215 @@
+/// Asserts that the floating point expression is finite, returning the value.
+///
+/// A value is finite if it is neither `NaN` nor positive or negative infinity. Subnormal values
+/// are finite.
+///
+/// Works with [`f32`] and [`f64`].
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_finite!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_finite!(1.0);
+///
+/// // With a custom message
+/// assert_finite!(1.0, "Expecting {} to be finite", 1.0);
+/// # }
+/// ```
+///
+/// The value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let value = assert_finite!(1.0 / 3.0);
+/// assert_eq!(value, 1.0 / 3.0);
+/// # }
+/// ```
+///
+/// `NaN` and infinite values will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_finite!(1.0 / 0.0);  // Will panic
+/// # }
+/// ```
+///
+/// [`f32`]: https://doc.rust-lang.org/core/primitive.f32.html
+/// [`f64`]: https://doc.rust-lang.org/core/primitive.f64.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_finite!`]: crate::debug_assert_finite!
+#[macro_export]
+macro_rules! assert_finite {
+    ($value:expr $(,)?) => {
+        match $value {
+            value => {
+                if !$crate::__private::Finite::is_finite(&value) {
+                    ::core::panic!("assertion failed, expected finite value, got {:?}", value);
+                }
+                value
+            }
+        }
+    };
+    ($value:expr, $($arg:tt)+) => {
+        match $value {
+            value => {
+                if !$crate::__private::Finite::is_finite(&value) {
+                    ::core::panic!("assertion failed, expected finite value, got {:?}: {}", value, ::core::format_args!($($arg)+));
+                }
+                value
+            }
+        }
+    };
+}
+
+/// Asserts that the floating point expression is finite on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_finite!`] on debug builds, although it does not
+/// return the value. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_finite {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_finite!($($arg)*);
+        }
+    }};
+}
+
+/// Floating point types which can be checked for finiteness.
+///
+/// This is an implementation detail of [`assert_finite!`] and [`assert_all_finite!`] and is not
+/// part of the public API.
+#[doc(hidden)]
+pub trait Finite {
+    fn is_finite(&self) -> bool;
+}
+
+impl Finite for f32 {
+    fn is_finite(&self) -> bool {
+        f32::is_finite(*self)
+    }
+}
+
+impl Finite for f64 {
+    fn is_finite(&self) -> bool {
+        f64::is_finite(*self)
+    }
+}
+
+impl<T> Finite for &T
+where
+    T: Finite + ?Sized,
+{
+    fn is_finite(&self) -> bool {
+        T::is_finite(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn finite() {
+        assert_finite!(1.0);
+    }
+
+    #[test]
+    fn finite_f32() {
+        assert_finite!(1.0f32);
+    }
+
+    #[test]
+    fn subnormal() {
+        assert_finite!(core::f64::MIN_POSITIVE / 2.0);
+    }
+
+    #[test]
+    fn subnormal_f32() {
+        assert_finite!(core::f32::MIN_POSITIVE / 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected finite value, got NaN")]
+    fn nan() {
+        assert_finite!(core::f64::NAN);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected finite value, got inf")]
+    fn positive_infinity() {
+        assert_finite!(core::f64::INFINITY);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected finite value, got -inf")]
+    fn negative_infinity() {
+        assert_finite!(core::f32::NEG_INFINITY);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected finite value, got NaN: foo")]
+    fn nan_custom_message() {
+        assert_finite!(core::f64::NAN, "foo");
+    }
+
+    #[test]
+    fn finite_value_returned() {
+        let value = assert_finite!(42.0);
+        assert_eq!(value, 42.0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_finite() {
+        debug_assert_finite!(1.0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, expected finite value, got NaN")]
+    fn debug_nan() {
+        debug_assert_finite!(core::f64::NAN);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, expected finite value, got NaN: foo")]
+    fn debug_nan_custom_message() {
+        debug_assert_finite!(core::f64::NAN, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_nan() {
+        debug_assert_finite!(core::f64::NAN);
+    }
+
+    #[test]
+    fn debug_finite_returns_unit() {
+        let () = debug_assert_finite!(1.0);
+    }
+
+    #[test]
+    fn debug_finite_returns_unit_custom_message() {
+        let () = debug_assert_finite!(1.0, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_finite_returns_unit() {
+        let () = debug_assert_finite!(core::f64::NAN);
+    }
+}
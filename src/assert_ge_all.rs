@@ -0,0 +1,228 @@
+/// Asserts that the first expression is greater than or equal to every element of the second,
+/// returning the first.
+///
+/// The second expression can be anything implementing [`IntoIterator`], such as a reference to an
+/// array or slice. Each element is compared against the first expression with `>=`. Elements
+/// yielded by reference are borrowed as the type of the first expression, so the first expression
+/// is given by value whether the iterator yields values or references. The assertion panics at the
+/// first element for which the comparison does not hold, reporting the element and its index. An
+/// empty iterator passes.
+///
+/// As with [`assert_ge!`], the first expression is taken by value and returned from the macro
+/// call, while the elements are only borrowed.
+///
+/// Neither the value nor the elements are required to implement [`Debug`]. Values which do not
+/// implement it are displayed as `<non-Debug>` in the panic message.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ge_all!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let candidates = [1, 5, 2];
+/// assert_ge_all!(5, &candidates);
+///
+/// // With a custom message
+/// assert_ge_all!(5, &candidates, "Expecting {} to be greater than or equal to all of {:?}",
+///     5, candidates);
+/// # }
+/// ```
+///
+/// The first value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let value = assert_ge_all!(5, vec![1, 5, 2]);
+/// assert_eq!(value, 5);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_ge_all!(5, &[1, 7, 2]);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`assert_ge!`]: crate::assert_ge!
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_ge_all!`]: crate::debug_assert_ge_all!
+#[macro_export]
+macro_rules! assert_ge_all {
+    ($left:expr, $right:expr $(,)?) => {
+        match $left {
+            left_val => {
+                for (index, element) in ::core::iter::Iterator::enumerate(::core::iter::IntoIterator::into_iter($right)) {
+                    let element = $crate::__private::borrow_element(&left_val, &element);
+                    if !::core::cmp::PartialOrd::ge(&left_val, element) {
+                        #[allow(unused_imports)]
+                        use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                        ::core::panic!(r#"assertion failed: `(left >= right)` for all elements
+    left: `{:?}`,
+    right: `{:?}`,
+    index: `{}`"#, (&$crate::__private::MaybeDebug(&left_val)).maybe_debug(), (&$crate::__private::MaybeDebug(element)).maybe_debug(), index)
+                    }
+                }
+                left_val
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match $left {
+            left_val => {
+                for (index, element) in ::core::iter::Iterator::enumerate(::core::iter::IntoIterator::into_iter($right)) {
+                    let element = $crate::__private::borrow_element(&left_val, &element);
+                    if !::core::cmp::PartialOrd::ge(&left_val, element) {
+                        #[allow(unused_imports)]
+                        use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                        ::core::panic!(r#"assertion failed: `(left >= right)` for all elements
+    left: `{:?}`,
+    right: `{:?}`,
+    index: `{}`: {}"#, (&$crate::__private::MaybeDebug(&left_val)).maybe_debug(), (&$crate::__private::MaybeDebug(element)).maybe_debug(), index, ::core::format_args!($($arg)+))
+                    }
+                }
+                left_val
+            }
+        }
+    };
+}
+
+/// Asserts that the first expression is greater than or equal to every element of the second on debug
+/// builds.
+///
+/// This macro behaves nearly the same as [`assert_ge_all!`] on debug builds, although it does not
+/// return the first value. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_ge_all {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_ge_all!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ge_all() {
+        assert_ge_all!(5, &[1, 5, 2]);
+    }
+
+    #[test]
+    fn ge_all_by_value() {
+        assert_ge_all!(5, [1, 5, 2].iter().copied());
+    }
+
+    #[test]
+    fn empty() {
+        let candidates: [u32; 0] = [];
+        assert_ge_all!(5, &candidates);
+    }
+
+    #[test]
+    fn by_reference() {
+        let value = 5;
+        let returned = assert_ge_all!(&value, &[1, 4, 2]);
+        assert_eq!(returned, &5);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left >= right)` for all elements\n    left: `1`,\n    right: `2`,\n    index: `0`"
+    )]
+    fn violation_first() {
+        assert_ge_all!(1, &[2, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left >= right)` for all elements\n    left: `5`,\n    right: `6`,\n    index: `2`"
+    )]
+    fn violation_middle() {
+        assert_ge_all!(5, &[1, 4, 6, 2]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left >= right)` for all elements\n    left: `5`,\n    right: `6`,\n    index: `2`: foo"
+    )]
+    fn violation_middle_custom_message() {
+        assert_ge_all!(5, &[1, 4, 6, 2], "foo");
+    }
+
+    #[test]
+    fn value_returned() {
+        let value = assert_ge_all!(5, [1, 5, 2].iter().copied());
+        assert_eq!(value, 5);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left >= right)` for all elements\n    left: `<non-Debug>`,\n    right: `<non-Debug>`,\n    index: `0`"
+    )]
+    fn does_not_require_values_to_impl_debug() {
+        #[derive(PartialEq, PartialOrd)]
+        struct Weight(u32);
+
+        assert_ge_all!(Weight(1), &[Weight(2)]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_ge_all() {
+        debug_assert_ge_all!(5, &[1, 5, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(left >= right)` for all elements\n    left: `1`,\n    right: `2`,\n    index: `0`"
+    )]
+    fn debug_violation_first() {
+        debug_assert_ge_all!(1, &[2, 0, 0]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(left >= right)` for all elements\n    left: `5`,\n    right: `6`,\n    index: `2`"
+    )]
+    fn debug_violation_middle() {
+        debug_assert_ge_all!(5, &[1, 4, 6, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(left >= right)` for all elements\n    left: `5`,\n    right: `6`,\n    index: `2`: foo"
+    )]
+    fn debug_violation_middle_custom_message() {
+        debug_assert_ge_all!(5, &[1, 4, 6, 2], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_violation_first() {
+        debug_assert_ge_all!(1, &[2, 0, 0]);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_ge_all!(5, &[1, 5, 2]);
+    }
+}
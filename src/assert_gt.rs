@@ -1,7 +1,20 @@
-/// Asserts that the first expression is greater than the second.
+/// Asserts that the first expression is greater than the second, returning the first.
 ///
 /// Requires that both expressions be comparable with `>`.
 ///
+/// Neither expression is required to implement [`Debug`]. Values which do not implement it are
+/// displayed as `<non-Debug>` in the panic message.
+///
+/// If both values are primitive numbers of the same type, or if they implement [`Sub`] with a
+/// [`Debug`] output, the panic message also includes the difference `right - left`, showing how far
+/// the first value is below the second.
+///
+/// If the values cannot be compared at all, because [`PartialOrd::partial_cmp`] returns `None` for
+/// them, such as when either is `NaN`, the panic message says so instead.
+///
+/// Both expressions are only borrowed, and a reference to the first is returned from the macro call
+/// once the comparison has passed.
+///
 /// ## Uses
 ///
 /// Assertions are always checked in both debug and release builds, and cannot be disabled.
@@ -24,6 +37,17 @@
 /// # }
 /// ```
 ///
+/// The first value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let index = 5;
+/// let value = assert_gt!(index, 3);
+/// assert_eq!(*value, 5);
+/// # }
+/// ```
+///
 /// ```rust,should_panic
 /// # #[macro_use] extern crate claims;
 /// # fn main() {
@@ -34,6 +58,9 @@
 /// # }
 /// ```
 ///
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`Sub`]: https://doc.rust-lang.org/core/ops/trait.Sub.html
+/// [`PartialOrd::partial_cmp`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html#tymethod.partial_cmp
 /// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
 /// [`debug_assert_gt!`]: crate::debug_assert_gt!
 #[macro_export]
@@ -42,13 +69,18 @@ macro_rules! assert_gt {
         match (&$left, &$right) {
             (left_val, right_val) => {
                 if !(*left_val > *right_val) {
-                    // The reborrows below are intentional. Without them, the stack slot for the
-                    // borrow is initialized even before the values are compared, leading to a
-                    // noticeable slow down.
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsAbsDiff as _, IsDebug as _, IsNotDebug as _, IsNotSub as _, IsSub as _};
+                    if ::core::cmp::PartialOrd::partial_cmp(&*left_val, &*right_val).is_none() {
+                        ::core::panic!(r#"assertion failed: left and right are not comparable (partial_cmp returned None)
+    left: `{:?}`,
+    right: `{:?}`"#, (&$crate::__private::MaybeDebug(&*left_val)).maybe_debug(), (&$crate::__private::MaybeDebug(&*right_val)).maybe_debug())
+                    }
                     ::core::panic!(r#"assertion failed: `(left > right)`
     left: `{:?}`,
-    right: `{:?}`"#, &*left_val, &*right_val)
+    right: `{:?}`{}"#, (&$crate::__private::MaybeDebug(&*left_val)).maybe_debug(), (&$crate::__private::MaybeDebug(&*right_val)).maybe_debug(), (&&$crate::__private::MaybeDifference(&*right_val, &*left_val)).difference())
                 }
+                left_val
             }
         }
     };
@@ -56,13 +88,18 @@ macro_rules! assert_gt {
         match (&$left, &$right) {
             (left_val, right_val) => {
                 if !(*left_val > *right_val) {
-                    // The reborrows below are intentional. Without them, the stack slot for the
-                    // borrow is initialized even before the values are compared, leading to a
-                    // noticeable slow down.
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsAbsDiff as _, IsDebug as _, IsNotDebug as _, IsNotSub as _, IsSub as _};
+                    if ::core::cmp::PartialOrd::partial_cmp(&*left_val, &*right_val).is_none() {
+                        ::core::panic!(r#"assertion failed: left and right are not comparable (partial_cmp returned None)
+    left: `{:?}`,
+    right: `{:?}`: {}"#, (&$crate::__private::MaybeDebug(&*left_val)).maybe_debug(), (&$crate::__private::MaybeDebug(&*right_val)).maybe_debug(), ::core::format_args!($($arg)+))
+                    }
                     ::core::panic!(r#"assertion failed: `(left > right)`
     left: `{:?}`,
-    right: `{:?}`: {}"#, &*left_val, &*right_val, ::core::format_args!($($arg)+))
+    right: `{:?}`{}: {}"#, (&$crate::__private::MaybeDebug(&*left_val)).maybe_debug(), (&$crate::__private::MaybeDebug(&*right_val)).maybe_debug(), (&&$crate::__private::MaybeDifference(&*right_val, &*left_val)).difference(), ::core::format_args!($($arg)+))
                 }
+                left_val
             }
         }
     };
@@ -70,37 +107,50 @@ macro_rules! assert_gt {
 
 /// Asserts that the first expression is greater than the second in debug builds.
 ///
-/// This macro behaves the same as [`assert_gt!`] on debug builds. On release builds it is a no-op.
+/// This macro behaves nearly the same as [`assert_gt!`] on debug builds, although it does not
+/// return the first expression. On release builds it is a no-op.
 #[macro_export]
 macro_rules! debug_assert_gt {
-    ($($arg:tt)*) => {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
         #[cfg(debug_assertions)]
-        $crate::assert_gt!($($arg)*);
-    }
+        {
+            $crate::assert_gt!($($arg)*);
+        }
+    }};
 }
 
 #[cfg(test)]
 mod tests {
+    use std::string::String;
+
+    #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+    struct NonCopy(usize);
+
     #[test]
     fn greater_than() {
         assert_gt!(5, 3);
     }
 
     #[test]
-    #[should_panic(expected = "assertion failed: `(left > right)`\n    left: `3`,\n    right: `3`")]
+    #[should_panic(
+        expected = "assertion failed: `(left > right)`\n    left: `3`,\n    right: `3`,\n    difference: `0`"
+    )]
     fn equal() {
         assert_gt!(3, 3);
     }
 
     #[test]
-    #[should_panic(expected = "assertion failed: `(left > right)`\n    left: `1`,\n    right: `3`")]
+    #[should_panic(
+        expected = "assertion failed: `(left > right)`\n    left: `1`,\n    right: `3`,\n    difference: `2`"
+    )]
     fn less_than() {
         assert_gt!(1, 3);
     }
 
     #[test]
     #[should_panic(
-        expected = "assertion failed: `(left > right)`\n    left: `3`,\n    right: `3`: foo"
+        expected = "assertion failed: `(left > right)`\n    left: `3`,\n    right: `3`,\n    difference: `0`: foo"
     )]
     fn equal_custom_message() {
         assert_gt!(3, 3, "foo");
@@ -108,7 +158,7 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "assertion failed: `(left > right)`\n    left: `1`,\n    right: `3`: foo"
+        expected = "assertion failed: `(left > right)`\n    left: `1`,\n    right: `3`,\n    difference: `2`: foo"
     )]
     fn less_than_custom_message() {
         assert_gt!(1, 3, "foo");
@@ -122,14 +172,18 @@ mod tests {
 
     #[test]
     #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
-    #[should_panic(expected = "assertion failed: `(left > right)`\n    left: `3`,\n    right: `3`")]
+    #[should_panic(
+        expected = "assertion failed: `(left > right)`\n    left: `3`,\n    right: `3`,\n    difference: `0`"
+    )]
     fn debug_equal() {
         debug_assert_gt!(3, 3);
     }
 
     #[test]
     #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
-    #[should_panic(expected = "assertion failed: `(left > right)`\n    left: `1`,\n    right: `3`")]
+    #[should_panic(
+        expected = "assertion failed: `(left > right)`\n    left: `1`,\n    right: `3`,\n    difference: `2`"
+    )]
     fn debug_less_than() {
         debug_assert_gt!(1, 3);
     }
@@ -137,7 +191,7 @@ mod tests {
     #[test]
     #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
     #[should_panic(
-        expected = "assertion failed: `(left > right)`\n    left: `3`,\n    right: `3`: foo"
+        expected = "assertion failed: `(left > right)`\n    left: `3`,\n    right: `3`,\n    difference: `0`: foo"
     )]
     fn debug_equal_custom_message() {
         debug_assert_gt!(3, 3, "foo");
@@ -146,7 +200,7 @@ mod tests {
     #[test]
     #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
     #[should_panic(
-        expected = "assertion failed: `(left > right)`\n    left: `1`,\n    right: `3`: foo"
+        expected = "assertion failed: `(left > right)`\n    left: `1`,\n    right: `3`,\n    difference: `2`: foo"
     )]
     fn debug_less_than_custom_message() {
         debug_assert_gt!(1, 3, "foo");
@@ -163,4 +217,237 @@ mod tests {
     fn debug_release_less_than() {
         debug_assert_gt!(1, 3);
     }
+
+    #[cfg(debug_assertions)]
+    fn debug_only_value() -> usize {
+        5
+    }
+
+    #[test]
+    fn debug_compiled_out_in_release() {
+        // `debug_only_value()` does not exist in release builds, so this only compiles if the
+        // assertion is removed entirely rather than skipped at runtime.
+        debug_assert_gt!(debug_only_value(), 3);
+    }
+
+    #[test]
+    fn value_returned() {
+        let index = 5;
+        let value = assert_gt!(index, 3);
+        assert_eq!(*value, 5);
+    }
+
+    #[test]
+    fn non_copy_not_moved() {
+        let left = String::from("b");
+        let right = String::from("a");
+        let value = assert_gt!(left, right);
+        assert_eq!(value, "b");
+        drop(left);
+        drop(right);
+    }
+
+    #[test]
+    fn non_copy_field_not_moved() {
+        struct Pair {
+            left: NonCopy,
+            right: NonCopy,
+        }
+
+        let pair = Pair {
+            left: NonCopy(5),
+            right: NonCopy(3),
+        };
+        assert_gt!(pair.left, pair.right);
+        assert_eq!(pair.left, NonCopy(5));
+    }
+
+    #[test]
+    fn debug_does_not_move() {
+        let left = NonCopy(5);
+        debug_assert_gt!(left, NonCopy(3));
+        assert_eq!(left, NonCopy(5));
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_gt!(5, 3);
+    }
+
+    #[test]
+    fn does_not_require_operands_to_impl_debug() {
+        #[derive(Eq, Ord, PartialEq, PartialOrd)]
+        struct Key(usize);
+
+        assert_gt!(Key(5), Key(3));
+    }
+
+    #[test]
+    fn does_not_require_operands_to_impl_debug_custom_message() {
+        #[derive(Eq, Ord, PartialEq, PartialOrd)]
+        struct Key(usize);
+
+        assert_gt!(Key(5), Key(3), "foo");
+    }
+
+    #[test]
+    fn debug_does_not_require_operands_to_impl_debug() {
+        #[derive(Eq, Ord, PartialEq, PartialOrd)]
+        struct Key(usize);
+
+        debug_assert_gt!(Key(5), Key(3));
+    }
+
+    #[test]
+    fn debug_does_not_require_operands_to_impl_debug_custom_message() {
+        #[derive(Eq, Ord, PartialEq, PartialOrd)]
+        struct Key(usize);
+
+        debug_assert_gt!(Key(5), Key(3), "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left > right)`\n    left: `<non-Debug>`,\n    right: `<non-Debug>`"
+    )]
+    fn non_debug_operands() {
+        #[derive(Eq, Ord, PartialEq, PartialOrd)]
+        struct Key(usize);
+
+        assert_gt!(Key(1), Key(3));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left > right)`\n    left: `<non-Debug>`,\n    right: `<non-Debug>`: foo"
+    )]
+    fn non_debug_operands_custom_message() {
+        #[derive(Eq, Ord, PartialEq, PartialOrd)]
+        struct Key(usize);
+
+        assert_gt!(Key(1), Key(3), "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left > right)`\n    left: `<non-Debug>`,\n    right: `3`"
+    )]
+    fn non_debug_left_operand() {
+        struct Key(usize);
+
+        impl PartialEq<usize> for Key {
+            fn eq(&self, other: &usize) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialOrd<usize> for Key {
+            fn partial_cmp(&self, other: &usize) -> Option<core::cmp::Ordering> {
+                self.0.partial_cmp(other)
+            }
+        }
+
+        assert_gt!(Key(1), 3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left > right)`\n    left: `-128`,\n    right: `127`,\n    difference: `255`"
+    )]
+    fn difference_does_not_overflow() {
+        assert_gt!(-128i8, 127);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left > right)`\n    left: `1.0`,\n    right: `2.5`,\n    difference: `1.5`"
+    )]
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    fn difference_float() {
+        assert_gt!(1.0, 2.5);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left > right)`\n    left: `Meters(3)`,\n    right: `Meters(5)`,\n    difference: `Meters(2)`"
+    )]
+    fn difference_sub() {
+        #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+        struct Meters(u32);
+
+        impl core::ops::Sub for &Meters {
+            type Output = Meters;
+
+            fn sub(self, other: &Meters) -> Meters {
+                Meters(self.0 - other.0)
+            }
+        }
+
+        assert_gt!(Meters(3), Meters(5));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left > right)`\n    left: `Key(3)`,\n    right: `Key(5)`: foo"
+    )]
+    fn no_difference_without_sub() {
+        #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+        struct Key(u32);
+
+        assert_gt!(Key(3), Key(5), "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: left and right are not comparable (partial_cmp returned None)\n    left: `NaN`,\n    right: `1.0`"
+    )]
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    fn not_comparable_left_nan() {
+        assert_gt!(core::f64::NAN, 1.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: left and right are not comparable (partial_cmp returned None)\n    left: `1.0`,\n    right: `NaN`"
+    )]
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    fn not_comparable_right_nan() {
+        assert_gt!(1.0, core::f64::NAN);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: left and right are not comparable (partial_cmp returned None)\n    left: `Flags(1)`,\n    right: `Flags(2)`: foo"
+    )]
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    fn not_comparable_custom_message() {
+        #[derive(Debug, PartialEq)]
+        struct Flags(u8);
+
+        impl PartialOrd for Flags {
+            fn partial_cmp(&self, other: &Flags) -> Option<core::cmp::Ordering> {
+                if self.0 == other.0 {
+                    Some(core::cmp::Ordering::Equal)
+                } else if self.0 & other.0 == self.0 {
+                    Some(core::cmp::Ordering::Less)
+                } else if self.0 & other.0 == other.0 {
+                    Some(core::cmp::Ordering::Greater)
+                } else {
+                    None
+                }
+            }
+        }
+
+        assert_gt!(Flags(1), Flags(2), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: left and right are not comparable (partial_cmp returned None)\n    left: `NaN`,\n    right: `1.0`"
+    )]
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    fn debug_not_comparable() {
+        debug_assert_gt!(core::f64::NAN, 1.0);
+    }
 }
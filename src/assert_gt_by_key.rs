@@ -0,0 +1,283 @@
+/// Asserts that the key of the first expression is greater than the key of the second, returning
+/// the first.
+///
+/// The key function, such as `|x| x.deadline`, is applied to a reference to each expression, and
+/// the resulting keys are compared with `>`. Both expressions must be of the same type, and the
+/// keys must implement [`Debug`].
+///
+/// On failure, the panic message shows both keys as well as both expressions. The expressions are
+/// not required to implement [`Debug`]; values which do not implement it are displayed as
+/// `<non-Debug>` in the panic message.
+///
+/// As with [`assert_gt!`], both expressions are only borrowed, and a reference to the first is
+/// returned from the macro call once the comparison has passed.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_gt_by_key!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// struct Retry {
+///     deadline: u64,
+/// }
+///
+/// let first = Retry { deadline: 3 };
+/// let second = Retry { deadline: 2 };
+/// assert_gt_by_key!(first, second, |retry| retry.deadline);
+///
+/// // With a custom message
+/// assert_gt_by_key!(first, second, |retry| retry.deadline, "Comparing retry deadlines");
+/// # }
+/// ```
+///
+/// The first value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let pair = (3, 'a');
+/// let value = assert_gt_by_key!(pair, (2, 'b'), |pair| pair.0);
+/// assert_eq!(*value, (3, 'a'));
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_gt_by_key!((2, 'a'), (2, 'b'), |pair| pair.0);  // Will panic
+/// # }
+/// ```
+///
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`assert_gt!`]: crate::assert_gt!
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_gt_by_key!`]: crate::debug_assert_gt_by_key!
+#[macro_export]
+macro_rules! assert_gt_by_key {
+    ($left:expr, $right:expr, $key:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let (left_key, right_key) = $crate::__private::by_key(left_val, right_val, $key);
+                if !::core::cmp::PartialOrd::gt(&left_key, &right_key) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed: `(key(left) > key(right))`
+    left key: `{:?}`,
+    right key: `{:?}`,
+    left: `{:?}`,
+    right: `{:?}`"#, left_key, right_key, (&$crate::__private::MaybeDebug(&*left_val)).maybe_debug(), (&$crate::__private::MaybeDebug(&*right_val)).maybe_debug())
+                }
+                left_val
+            }
+        }
+    };
+    ($left:expr, $right:expr, $key:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let (left_key, right_key) = $crate::__private::by_key(left_val, right_val, $key);
+                if !::core::cmp::PartialOrd::gt(&left_key, &right_key) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed: `(key(left) > key(right))`
+    left key: `{:?}`,
+    right key: `{:?}`,
+    left: `{:?}`,
+    right: `{:?}`: {}"#, left_key, right_key, (&$crate::__private::MaybeDebug(&*left_val)).maybe_debug(), (&$crate::__private::MaybeDebug(&*right_val)).maybe_debug(), ::core::format_args!($($arg)+))
+                }
+                left_val
+            }
+        }
+    };
+}
+
+/// Asserts that the key of the first expression is greater than the key of the second on debug
+/// builds.
+///
+/// This macro behaves nearly the same as [`assert_gt_by_key!`] on debug builds, although it does not
+/// return the first expression. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_gt_by_key {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_gt_by_key!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug, PartialEq)]
+    struct Retry {
+        attempt: u32,
+        deadline: u64,
+    }
+
+    struct Opaque {
+        deadline: u64,
+    }
+
+    #[test]
+    fn passes() {
+        assert_gt_by_key!(
+            Retry {
+                attempt: 1,
+                deadline: 3
+            },
+            Retry {
+                attempt: 0,
+                deadline: 2
+            },
+            |retry| retry.deadline
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(key(left) > key(right))`\n    left key: `2`,\n    right key: `2`,\n    left: `Retry { attempt: 1, deadline: 2 }`,\n    right: `Retry { attempt: 0, deadline: 2 }`"
+    )]
+    fn fails() {
+        assert_gt_by_key!(
+            Retry {
+                attempt: 1,
+                deadline: 2
+            },
+            Retry {
+                attempt: 0,
+                deadline: 2
+            },
+            |retry| retry.deadline
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(key(left) > key(right))`\n    left key: `2`,\n    right key: `2`,\n    left: `Retry { attempt: 1, deadline: 2 }`,\n    right: `Retry { attempt: 0, deadline: 2 }`: foo"
+    )]
+    fn fails_custom_message() {
+        assert_gt_by_key!(
+            Retry {
+                attempt: 1,
+                deadline: 2
+            },
+            Retry {
+                attempt: 0,
+                deadline: 2
+            },
+            |retry| retry.deadline,
+            "foo"
+        );
+    }
+
+    #[test]
+    fn value_returned() {
+        let first = Retry {
+            attempt: 1,
+            deadline: 3,
+        };
+        let second = Retry {
+            attempt: 0,
+            deadline: 2,
+        };
+        let retry = assert_gt_by_key!(first, second, |retry| retry.deadline);
+        assert_eq!(retry, &first);
+        assert_eq!(second.attempt, 0);
+    }
+
+    #[test]
+    fn by_reference() {
+        let first = Retry {
+            attempt: 1,
+            deadline: 3,
+        };
+        let second = Retry {
+            attempt: 0,
+            deadline: 2,
+        };
+        assert_gt_by_key!(&first, &second, |retry| retry.deadline);
+        assert_eq!(first.attempt, 1);
+    }
+
+    #[test]
+    fn does_not_require_operands_to_impl_debug() {
+        assert_gt_by_key!(Opaque { deadline: 3 }, Opaque { deadline: 2 }, |opaque| {
+            opaque.deadline
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(key(left) > key(right))`\n    left key: `2`,\n    right key: `2`,\n    left: `<non-Debug>`,\n    right: `<non-Debug>`"
+    )]
+    fn non_debug_operands() {
+        assert_gt_by_key!(Opaque { deadline: 2 }, Opaque { deadline: 2 }, |opaque| {
+            opaque.deadline
+        });
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_passes() {
+        debug_assert_gt_by_key!(
+            Retry {
+                attempt: 1,
+                deadline: 3
+            },
+            Retry {
+                attempt: 0,
+                deadline: 2
+            },
+            |retry| retry.deadline
+        );
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(key(left) > key(right))`\n    left key: `2`,\n    right key: `2`,\n    left: `<non-Debug>`,\n    right: `<non-Debug>`: foo"
+    )]
+    fn debug_non_debug_operands_custom_message() {
+        debug_assert_gt_by_key!(
+            Opaque { deadline: 2 },
+            Opaque { deadline: 2 },
+            |opaque| opaque.deadline,
+            "foo"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_fails() {
+        debug_assert_gt_by_key!(
+            Retry {
+                attempt: 1,
+                deadline: 2
+            },
+            Retry {
+                attempt: 0,
+                deadline: 2
+            },
+            |retry| retry.deadline
+        );
+    }
+
+    #[test]
+    fn debug_does_not_move() {
+        let first = Opaque { deadline: 3 };
+        let second = Opaque { deadline: 2 };
+        let () = debug_assert_gt_by_key!(first, second, |opaque| opaque.deadline);
+        assert_eq!(first.deadline, 3);
+    }
+}
@@ -0,0 +1,249 @@
+/// Asserts that the value is contained in the closed range `[low, high]`, including both bounds,
+/// returning a reference to the value.
+///
+/// This is equivalent to `assert_in_range!(value, low..=high)`, but the name states explicitly that
+/// both bounds are inclusive.
+///
+/// The value must be comparable with both bounds using [`PartialOrd`], and all three must
+/// implement [`Debug`]. On failure, the value and both bounds are printed, along with the bound
+/// that was violated. If both are, the lower bound is reported.
+///
+/// All three expressions are only borrowed, and a reference to the value is returned from the
+/// macro call once the assertion has passed.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_in_closed_range!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_in_closed_range!(42, 0, 100);
+/// assert_in_closed_range!(100, 0, 100);
+///
+/// // With a custom message
+/// assert_in_closed_range!(42, 0, 100, "Expecting {} to be a valid percentage", 42);
+/// # }
+/// ```
+///
+/// A reference to the value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let percentage = 42;
+/// let value = assert_in_closed_range!(percentage, 0, 100);
+/// assert_eq!(*value, 42);
+/// # }
+/// ```
+///
+/// A value outside of the range will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_in_closed_range!(101, 0, 100);  // Will panic
+/// # }
+/// ```
+///
+/// [`PartialOrd`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_in_closed_range!`]: crate::debug_assert_in_closed_range!
+#[macro_export]
+macro_rules! assert_in_closed_range {
+    ($value:expr, $low:expr, $high:expr $(,)?) => {
+        match (&$value, &$low, &$high) {
+            (value, low, high) => {
+                let violated = if *low <= *value {
+                    if *value <= *high {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some("value <= high")
+                    }
+                } else {
+                    ::core::option::Option::Some("low <= value")
+                };
+                if let ::core::option::Option::Some(violated) = violated {
+                    ::core::panic!(r#"assertion failed: `(low <= value && value <= high)`, violated `{}`
+    value: `{:?}`,
+    low: `{:?}`,
+    high: `{:?}`"#, violated, &*value, &*low, &*high);
+                }
+                value
+            }
+        }
+    };
+    ($value:expr, $low:expr, $high:expr, $($arg:tt)+) => {
+        match (&$value, &$low, &$high) {
+            (value, low, high) => {
+                let violated = if *low <= *value {
+                    if *value <= *high {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some("value <= high")
+                    }
+                } else {
+                    ::core::option::Option::Some("low <= value")
+                };
+                if let ::core::option::Option::Some(violated) = violated {
+                    ::core::panic!(r#"assertion failed: `(low <= value && value <= high)`, violated `{}`
+    value: `{:?}`,
+    low: `{:?}`,
+    high: `{:?}`: {}"#, violated, &*value, &*low, &*high, ::core::format_args!($($arg)+));
+                }
+                value
+            }
+        }
+    };
+}
+
+/// Asserts that the value is contained in the closed range `[low, high]` on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_in_closed_range!`] on debug builds, although it
+/// does not return the value. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_in_closed_range {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_in_closed_range!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::String;
+
+    #[test]
+    fn in_range() {
+        assert_in_closed_range!(5, 0, 10);
+    }
+
+    #[test]
+    fn lower_bound() {
+        assert_in_closed_range!(0, 0, 10);
+    }
+
+    #[test]
+    fn upper_bound() {
+        assert_in_closed_range!(10, 0, 10);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(low <= value && value <= high)`, violated `low <= value`\n    value: `-1`,\n    low: `0`,\n    high: `10`"
+    )]
+    fn below_lower_bound() {
+        assert_in_closed_range!(-1, 0, 10);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(low <= value && value <= high)`, violated `value <= high`\n    value: `11`,\n    low: `0`,\n    high: `10`"
+    )]
+    fn above_upper_bound() {
+        assert_in_closed_range!(11, 0, 10);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(low <= value && value <= high)`, violated `low <= value`\n    value: `5`,\n    low: `10`,\n    high: `0`"
+    )]
+    fn empty_range() {
+        assert_in_closed_range!(5, 10, 0);
+    }
+
+    #[test]
+    fn in_range_floats() {
+        assert_in_closed_range!(0.5, 0.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(low <= value && value <= high)`, violated `low <= value`\n    value: `NaN`,\n    low: `0.0`,\n    high: `1.0`"
+    )]
+    fn nan() {
+        assert_in_closed_range!(core::f64::NAN, 0.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(low <= value && value <= high)`, violated `value <= high`\n    value: `11`,\n    low: `0`,\n    high: `10`: foo"
+    )]
+    fn above_upper_bound_custom_message() {
+        assert_in_closed_range!(11, 0, 10, "foo");
+    }
+
+    #[test]
+    fn value_returned() {
+        let index = 5;
+        let value = assert_in_closed_range!(index, 0, 10);
+        assert_eq!(*value, 5);
+    }
+
+    #[test]
+    fn non_copy_not_moved() {
+        let value = String::from("b");
+        let low = String::from("a");
+        let high = String::from("c");
+        let returned = assert_in_closed_range!(value, low, high);
+        assert_eq!(returned, "b");
+        assert_eq!(value, "b");
+        assert_eq!(low, "a");
+        assert_eq!(high, "c");
+    }
+
+    #[test]
+    fn debug_does_not_move() {
+        let value = String::from("b");
+        debug_assert_in_closed_range!(value, String::from("a"), String::from("c"));
+        assert_eq!(value, "b");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_in_range() {
+        debug_assert_in_closed_range!(5, 0, 10);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(low <= value && value <= high)`, violated `low <= value`\n    value: `-1`,\n    low: `0`,\n    high: `10`"
+    )]
+    fn debug_below_lower_bound() {
+        debug_assert_in_closed_range!(-1, 0, 10);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(low <= value && value <= high)`, violated `value <= high`\n    value: `11`,\n    low: `0`,\n    high: `10`: foo"
+    )]
+    fn debug_above_upper_bound_custom_message() {
+        debug_assert_in_closed_range!(11, 0, 10, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_above_upper_bound() {
+        debug_assert_in_closed_range!(11, 0, 10);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_in_closed_range!(5, 0, 10);
+    }
+}
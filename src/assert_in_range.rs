@@ -0,0 +1,247 @@
+/// Asserts that the expression is contained in the given range, returning the value.
+///
+/// Works with any range implementing [`RangeBounds`], such as `a..b`, `a..=b`, `a..`, `..b`, and
+/// `..=b`. Requires that the value be comparable with the range's bounds. The range is only
+/// borrowed, so a range held in a variable can still be used after the assertion.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_in_range!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_in_range!(42, 0..100);
+/// assert_in_range!(10, 1..=10);
+/// assert_in_range!(42, 7..);
+///
+/// // With a custom message
+/// assert_in_range!(42, 0..100, "Expecting {} to be a valid percentage", 42);
+/// # }
+/// ```
+///
+/// The value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let value = assert_in_range!(42, 0..100);
+/// assert_eq!(value, 42);
+/// # }
+/// ```
+///
+/// A value outside of the range will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_in_range!(100, 0..100);  // Will panic
+/// # }
+/// ```
+///
+/// [`RangeBounds`]: https://doc.rust-lang.org/core/ops/trait.RangeBounds.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_in_range!`]: crate::debug_assert_in_range!
+#[macro_export]
+macro_rules! assert_in_range {
+    ($value:expr, $range:expr $(,)?) => {
+        match ($value, &$range) {
+            (value, range) => {
+                if !::core::ops::RangeBounds::contains(range, &value) {
+                    ::core::panic!(r#"assertion failed, expected value to be in range
+    value: `{:?}`,
+    range: `{}`"#, value, ::core::stringify!($range))
+                }
+                value
+            }
+        }
+    };
+    ($value:expr, $range:expr, $($arg:tt)+) => {
+        match ($value, &$range) {
+            (value, range) => {
+                if !::core::ops::RangeBounds::contains(range, &value) {
+                    ::core::panic!(r#"assertion failed, expected value to be in range
+    value: `{:?}`,
+    range: `{}`: {}"#, value, ::core::stringify!($range), ::core::format_args!($($arg)+))
+                }
+                value
+            }
+        }
+    };
+}
+
+/// Asserts that the expression is contained in the given range on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_in_range!`] on debug builds, although it does
+/// not return the value. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_in_range {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_in_range!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::String;
+
+    #[test]
+    fn in_range() {
+        assert_in_range!(5, 0..10);
+    }
+
+    #[test]
+    fn in_range_lower_bound() {
+        assert_in_range!(0, 0..10);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected value to be in range\n    value: `10`,\n    range: `0..10`"
+    )]
+    fn excluded_upper_bound() {
+        assert_in_range!(10, 0..10);
+    }
+
+    #[test]
+    fn included_upper_bound() {
+        assert_in_range!(10, 0..=10);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected value to be in range\n    value: `11`,\n    range: `0..=10`"
+    )]
+    fn above_included_upper_bound() {
+        assert_in_range!(11, 0..=10);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected value to be in range\n    value: `-1`,\n    range: `0..10`"
+    )]
+    fn below_lower_bound() {
+        assert_in_range!(-1, 0..10);
+    }
+
+    #[test]
+    fn in_range_from() {
+        assert_in_range!(100, 3..);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected value to be in range\n    value: `2`,\n    range: `3..`"
+    )]
+    fn not_in_range_from() {
+        assert_in_range!(2, 3..);
+    }
+
+    #[test]
+    fn in_range_to() {
+        assert_in_range!(2, ..3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected value to be in range\n    value: `3`,\n    range: `..3`"
+    )]
+    fn excluded_range_to_bound() {
+        assert_in_range!(3, ..3);
+    }
+
+    #[test]
+    fn included_range_to_bound() {
+        assert_in_range!(3, ..=3);
+    }
+
+    #[test]
+    fn in_range_full() {
+        assert_in_range!(42, ..);
+    }
+
+    #[test]
+    fn in_range_floats() {
+        assert_in_range!(0.5, 0.0..1.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected value to be in range\n    value: `10`,\n    range: `0..10`: foo"
+    )]
+    fn not_in_range_custom_message() {
+        assert_in_range!(10, 0..10, "foo");
+    }
+
+    #[test]
+    fn in_range_value_returned() {
+        let value = assert_in_range!(5, 0..10);
+        assert_eq!(value, 5);
+    }
+
+    #[test]
+    fn non_copy_range_not_moved() {
+        let range = String::from("b")..String::from("d");
+        assert_in_range!(String::from("c"), range);
+        assert_eq!(range.start, "b");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_in_range() {
+        debug_assert_in_range!(5, 0..10);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected value to be in range\n    value: `10`,\n    range: `0..10`"
+    )]
+    fn debug_excluded_upper_bound() {
+        debug_assert_in_range!(10, 0..10);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected value to be in range\n    value: `10`,\n    range: `0..10`: foo"
+    )]
+    fn debug_not_in_range_custom_message() {
+        debug_assert_in_range!(10, 0..10, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_in_range() {
+        debug_assert_in_range!(10, 0..10);
+    }
+
+    #[test]
+    fn debug_in_range_returns_unit() {
+        let () = debug_assert_in_range!(5, 1..10);
+    }
+
+    #[test]
+    fn debug_in_range_returns_unit_custom_message() {
+        let () = debug_assert_in_range!(5, 1..10, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_in_range_returns_unit() {
+        let () = debug_assert_in_range!(11, 1..10);
+    }
+}
@@ -0,0 +1,152 @@
+/// Asserts that the expression is equal to the default value of its type, returning the value.
+///
+/// This is an alias for [`assert_default_eq!`], for tests which read better as a check of a state
+/// than as a comparison, such as checking that a constructor or reset function produces a default
+/// value. The value's type must implement [`Default`], [`PartialEq`], and [`Debug`]. On failure,
+/// both the value and the default are printed.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled. See
+/// [`debug_assert_is_default!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # #[derive(Debug, Default, PartialEq)]
+/// # struct Buffer { data: Vec<u8> }
+/// # impl Buffer {
+/// #     fn clear(&mut self) { self.data.clear(); }
+/// # }
+/// # fn main() {
+/// let mut buffer = Buffer { data: vec![1, 2, 3] };
+/// buffer.clear();
+/// assert_is_default!(buffer);
+///
+/// // With a custom message
+/// assert_is_default!(String::new(), "Expecting an empty string");
+/// # }
+/// ```
+///
+/// The value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let value = assert_is_default!(0u32);
+/// assert_eq!(value, 0u32);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_is_default!(Some(1));  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_default_eq!`]: crate::assert_default_eq!
+/// [`Default`]: https://doc.rust-lang.org/core/default/trait.Default.html
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_is_default!`]: crate::debug_assert_is_default!
+#[macro_export]
+macro_rules! assert_is_default {
+    ($($arg:tt)+) => {
+        $crate::assert_default_eq!($($arg)+)
+    };
+}
+
+/// Asserts that the expression is equal to the default value of its type on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_is_default!`] on debug builds, although it does
+/// not return the value. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_is_default {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_is_default!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{string::String, vec, vec::Vec};
+
+    #[test]
+    fn default() {
+        assert_is_default!(Vec::<u32>::new());
+    }
+
+    #[test]
+    fn default_primitive() {
+        assert_is_default!(0u32);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(value == Default::default())`\n    value: `[1, 2]`,\n    default: `[]`"
+    )]
+    fn not_default() {
+        assert_is_default!(vec![1, 2]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(value == Default::default())`\n    value: `\"foo\"`,\n    default: `\"\"`: bar"
+    )]
+    fn not_default_custom_message() {
+        assert_is_default!(String::from("foo"), "bar");
+    }
+
+    #[test]
+    fn value_returned() {
+        let value = assert_is_default!(String::new());
+        assert_eq!(value, "");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_default() {
+        debug_assert_is_default!(0u32);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(value == Default::default())`\n    value: `Some(1)`,\n    default: `None`"
+    )]
+    fn debug_not_default() {
+        debug_assert_is_default!(Some(1));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(value == Default::default())`\n    value: `Some(1)`,\n    default: `None`: foo"
+    )]
+    fn debug_not_default_custom_message() {
+        debug_assert_is_default!(Some(1), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_default() {
+        debug_assert_is_default!(Some(1));
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_is_default!(0u32);
+    }
+}
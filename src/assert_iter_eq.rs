@@ -0,0 +1,243 @@
+use core::fmt;
+
+/// Asserts that two iterators produce equal elements in the same order.
+///
+/// Both expressions can be anything implementing [`IntoIterator`], and their elements are compared
+/// pairwise using [`PartialEq`]. Unlike
+/// `assert_eq!(left.collect::<Vec<_>>(), right.collect::<Vec<_>>())`, this does not require
+/// allocation, and the failure message points at where the iterators diverge: the index of the
+/// first element that differs is printed along with both elements at that position. If one
+/// iterator ends before the other, its side is printed as `<end>`.
+///
+/// The elements must implement [`Debug`].
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_iter_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let words = vec!["foo", "bar", "baz"];
+/// assert_iter_eq!(words.iter().map(|word| word.len()), vec![3, 3, 3]);
+///
+/// // With a custom message
+/// assert_iter_eq!(words.iter().rev(), &["baz", "bar", "foo"], "Expecting reversed words");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_iter_eq!(1..4, vec![1, 2, 4]);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_iter_eq!`]: crate::debug_assert_iter_eq!
+#[macro_export]
+macro_rules! assert_iter_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        if let ::core::option::Option::Some(divergence) = $crate::__private::first_divergence($left, $right) {
+            ::core::panic!(r#"assertion failed, expected iterators to be equal
+    index: `{}`,
+    left: `{:?}`,
+    right: `{:?}`"#, divergence.index, divergence.left, divergence.right)
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        if let ::core::option::Option::Some(divergence) = $crate::__private::first_divergence($left, $right) {
+            ::core::panic!(r#"assertion failed, expected iterators to be equal
+    index: `{}`,
+    left: `{:?}`,
+    right: `{:?}`: {}"#, divergence.index, divergence.left, divergence.right, ::core::format_args!($($arg)+))
+        }
+    };
+}
+
+/// Asserts that two iterators produce equal elements in the same order on debug builds.
+///
+/// This macro behaves the same as [`assert_iter_eq!`] on debug builds. On release builds it is a
+/// no-op.
+#[macro_export]
+macro_rules! debug_assert_iter_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_iter_eq!($($arg)*);
+        }
+    }};
+}
+
+/// An element of an iterator, or the end of the iterator.
+#[doc(hidden)]
+pub struct Element<T>(Option<T>);
+
+impl<T> fmt::Debug for Element<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match &self.0 {
+            Some(element) => element.fmt(formatter),
+            None => formatter.write_str("<end>"),
+        }
+    }
+}
+
+/// The first position at which two iterators differ.
+#[doc(hidden)]
+pub struct Divergence<A, B> {
+    pub index: usize,
+    pub left: Element<A>,
+    pub right: Element<B>,
+}
+
+/// Returns the first position at which the two iterators produce different elements, or at which
+/// one of them ends before the other.
+///
+/// This is an implementation detail of [`assert_iter_eq!`] and is not part of the public API.
+#[doc(hidden)]
+pub fn first_divergence<A, B>(left: A, right: B) -> Option<Divergence<A::Item, B::Item>>
+where
+    A: IntoIterator,
+    B: IntoIterator,
+    A::Item: PartialEq<B::Item>,
+{
+    let mut left = left.into_iter();
+    let mut right = right.into_iter();
+    let mut index = 0;
+    loop {
+        match (left.next(), right.next()) {
+            (None, None) => return None,
+            (Some(l), Some(r)) => {
+                if l != r {
+                    return Some(Divergence {
+                        index,
+                        left: Element(Some(l)),
+                        right: Element(Some(r)),
+                    });
+                }
+            }
+            (l, r) => {
+                return Some(Divergence {
+                    index,
+                    left: Element(l),
+                    right: Element(r),
+                })
+            }
+        }
+        index += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{iter, string::String, vec, vec::Vec};
+
+    #[test]
+    fn equal() {
+        assert_iter_eq!(1..4, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn equal_references() {
+        let values = vec![1, 2, 3];
+        assert_iter_eq!(&values, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn equal_empty() {
+        assert_iter_eq!(iter::empty::<u32>(), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn equal_different_types() {
+        assert_iter_eq!(
+            vec!["foo", "bar"],
+            vec![String::from("foo"), String::from("bar")]
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterators to be equal\n    index: `2`,\n    left: `3`,\n    right: `4`"
+    )]
+    fn not_equal() {
+        assert_iter_eq!(1..4, vec![1, 2, 4]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterators to be equal\n    index: `0`,\n    left: `1`,\n    right: `2`"
+    )]
+    fn not_equal_first_element() {
+        assert_iter_eq!(1..4, vec![2, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterators to be equal\n    index: `3`,\n    left: `<end>`,\n    right: `4`"
+    )]
+    fn left_shorter() {
+        assert_iter_eq!(1..4, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterators to be equal\n    index: `2`,\n    left: `3`,\n    right: `<end>`"
+    )]
+    fn right_shorter() {
+        assert_iter_eq!(1..4, vec![1, 2]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterators to be equal\n    index: `2`,\n    left: `3`,\n    right: `4`: foo"
+    )]
+    fn not_equal_custom_message() {
+        assert_iter_eq!(1..4, vec![1, 2, 4], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_equal() {
+        debug_assert_iter_eq!(1..4, vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected iterators to be equal\n    index: `2`,\n    left: `3`,\n    right: `4`"
+    )]
+    fn debug_not_equal() {
+        debug_assert_iter_eq!(1..4, vec![1, 2, 4]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected iterators to be equal\n    index: `2`,\n    left: `3`,\n    right: `4`: foo"
+    )]
+    fn debug_not_equal_custom_message() {
+        debug_assert_iter_eq!(1..4, vec![1, 2, 4], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_equal() {
+        debug_assert_iter_eq!(1..4, vec![1, 2, 4]);
+    }
+}
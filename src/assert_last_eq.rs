@@ -0,0 +1,269 @@
+/// Asserts that the last element of a collection or iterator is equal to the expected value,
+/// returning the element.
+///
+/// Works with anything that can be iterated over by reference, such as slices, `Vec`s, and
+/// `VecDeque`s, and with cloneable iterators. The collection is only borrowed, so the same
+/// collection can be checked again afterwards, and the elements of a collection are returned by
+/// reference. The last element is found using [`Iterator::last()`], which walks through every
+/// element for most iterators, so this is not suitable for infinite iterators, and any side effects
+/// of producing the earlier elements will occur. The element is
+/// compared to the expected value using [`PartialEq`], and when it is yielded by reference, the
+/// expected value can be given either by value or by reference. Both values must implement
+/// [`Debug`], and are printed on a mismatch. An empty iterator always panics, with a message
+/// distinct from a mismatch. See [`assert_first_eq!`] to check the first element instead.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_last_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// #[derive(Debug, PartialEq)]
+/// enum Event {
+///     Connect,
+///     Message(String),
+///     Disconnect,
+/// }
+///
+/// let events = vec![Event::Connect, Event::Message("hi".to_string()), Event::Disconnect];
+///
+/// assert_last_eq!(events, Event::Disconnect);
+///
+/// // With a custom message
+/// assert_last_eq!(events, Event::Disconnect, "Expecting a clean shutdown");
+/// # }
+/// ```
+///
+/// The last element will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// #[derive(Debug, PartialEq)]
+/// enum Event {
+///     Connect,
+///     Message(String),
+///     Disconnect,
+/// }
+///
+/// let events = vec![Event::Connect, Event::Message("hi".to_string()), Event::Disconnect];
+///
+/// let last = assert_last_eq!(events, Event::Disconnect);
+/// assert_eq!(last, &Event::Disconnect);
+/// # }
+/// ```
+///
+/// A different last element or an empty iterator will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// #[derive(Debug, PartialEq)]
+/// enum Event {
+///     Connect,
+///     Message(String),
+///     Disconnect,
+/// }
+///
+/// let events = vec![Event::Connect, Event::Message("hi".to_string()), Event::Disconnect];
+///
+/// assert_last_eq!(events, Event::Connect);  // Will panic
+/// # }
+/// ```
+///
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`Iterator::last()`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#method.last
+/// [`assert_first_eq!`]: crate::assert_first_eq!
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_last_eq!`]: crate::debug_assert_last_eq!
+#[macro_export]
+macro_rules! assert_last_eq {
+    ($collection:expr, $expected:expr $(,)?) => {
+        match (&$collection, &$expected) {
+            (collection_val, expected) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+                match (&&&&&$crate::__private::Collection(collection_val)).view().last() {
+                    ::core::result::Result::Ok(last) => {
+                        if !$crate::__private::BorrowEq::borrow_eq(&last, expected) {
+                            ::core::panic!(r#"assertion failed: `(last(iter) == expected)`
+    last: `{:?}`,
+    expected: `{:?}`"#, &last, &*expected);
+                        }
+                        last
+                    }
+                    ::core::result::Result::Err(_) => {
+                        ::core::panic!("assertion failed, expected last element of non-empty iterator, got empty iterator");
+                    }
+                }
+            }
+        }
+    };
+    ($collection:expr, $expected:expr, $($arg:tt)+) => {
+        match (&$collection, &$expected) {
+            (collection_val, expected) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+                match (&&&&&$crate::__private::Collection(collection_val)).view().last() {
+                    ::core::result::Result::Ok(last) => {
+                        if !$crate::__private::BorrowEq::borrow_eq(&last, expected) {
+                            ::core::panic!(r#"assertion failed: `(last(iter) == expected)`
+    last: `{:?}`,
+    expected: `{:?}`: {}"#, &last, &*expected, ::core::format_args!($($arg)+));
+                        }
+                        last
+                    }
+                    ::core::result::Result::Err(_) => {
+                        ::core::panic!("assertion failed, expected last element of non-empty iterator, got empty iterator: {}", ::core::format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that the last element produced by an iterator is equal to the expected value on debug
+/// builds.
+///
+/// This macro behaves nearly the same as [`assert_last_eq!`] on debug builds, although it does not
+/// return the last element. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_last_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_last_eq!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::VecDeque, iter, vec, vec::Vec};
+
+    #[test]
+    fn equal_slice() {
+        let values: &[u32] = &[1, 2, 3];
+        assert_last_eq!(values, 3);
+    }
+
+    #[test]
+    fn equal_vec() {
+        let values = vec![1, 2, 3];
+        assert_last_eq!(&values, 3);
+        assert_last_eq!(values, 3);
+    }
+
+    #[test]
+    fn equal_vec_deque() {
+        let values: VecDeque<u32> = (1..4).collect();
+        assert_last_eq!(&values, 3);
+    }
+
+    #[test]
+    fn equal_iterator() {
+        assert_last_eq!((1..4).map(|n| n * 2), 6);
+    }
+
+    #[test]
+    fn equal_by_reference() {
+        assert_last_eq!(&[1, 2, 3], &3);
+    }
+
+    #[test]
+    fn single() {
+        assert_last_eq!(iter::once(7), 7);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(last(iter) == expected)`\n    last: `3`,\n    expected: `4`"
+    )]
+    fn not_equal() {
+        assert_last_eq!(vec![1, 2, 3], 4);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(last(iter) == expected)`\n    last: `3`,\n    expected: `4`: foo"
+    )]
+    fn not_equal_custom_message() {
+        assert_last_eq!(vec![1, 2, 3], 4, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected last element of non-empty iterator, got empty iterator"
+    )]
+    fn empty() {
+        assert_last_eq!(Vec::<u32>::new(), 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected last element of non-empty iterator, got empty iterator: foo"
+    )]
+    fn empty_custom_message() {
+        assert_last_eq!(Vec::<u32>::new(), 0, "foo");
+    }
+
+    #[test]
+    fn iterator_not_advanced() {
+        let mut values = [1, 2, 3].iter();
+        assert_last_eq!(values, &3);
+        assert_eq!(values.next(), Some(&1));
+    }
+
+    #[test]
+    fn last_returned() {
+        let values = vec![1, 2, 3];
+        let last = assert_last_eq!(&values, 3);
+        assert_eq!(last, &3);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_equal() {
+        debug_assert_last_eq!(vec![1, 2, 3], 3);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(last(iter) == expected)`\n    last: `3`,\n    expected: `4`"
+    )]
+    fn debug_not_equal() {
+        debug_assert_last_eq!(vec![1, 2, 3], 4);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(last(iter) == expected)`\n    last: `3`,\n    expected: `4`: foo"
+    )]
+    fn debug_not_equal_custom_message() {
+        debug_assert_last_eq!(vec![1, 2, 3], 4, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_equal() {
+        debug_assert_last_eq!(vec![1, 2, 3], 4);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_last_eq!(vec![1, 2, 3], 3);
+    }
+}
@@ -1,7 +1,20 @@
-/// Asserts that the first expression is less or equal than the second.
+/// Asserts that the first expression is less or equal than the second, returning the first.
 ///
 /// Requires that both expressions be comparable with `<=`.
 ///
+/// Neither expression is required to implement [`Debug`]. Values which do not implement it are
+/// displayed as `<non-Debug>` in the panic message.
+///
+/// If both values are primitive numbers of the same type, or if they implement [`Sub`] with a
+/// [`Debug`] output, the panic message also includes the difference `left - right`, showing how far
+/// the first value exceeds the second.
+///
+/// If the values cannot be compared at all, because [`PartialOrd::partial_cmp`] returns `None` for
+/// them, such as when either is `NaN`, the panic message says so instead.
+///
+/// Both expressions are only borrowed, and a reference to the first is returned from the macro call
+/// once the comparison has passed.
+///
 /// ## Uses
 ///
 /// Assertions are always checked in both debug and release builds, and cannot be disabled.
@@ -24,6 +37,17 @@
 /// # }
 /// ```
 ///
+/// The first value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let index = 3;
+/// let value = assert_le!(index, 5);
+/// assert_eq!(*value, 3);
+/// # }
+/// ```
+///
 /// ```rust,should_panic
 /// # #[macro_use] extern crate claims;
 /// # fn main() {
@@ -34,6 +58,9 @@
 /// # }
 /// ```
 ///
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`Sub`]: https://doc.rust-lang.org/core/ops/trait.Sub.html
+/// [`PartialOrd::partial_cmp`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html#tymethod.partial_cmp
 /// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
 /// [`debug_assert_le!`]: crate::debug_assert_le!
 #[macro_export]
@@ -42,13 +69,18 @@ macro_rules! assert_le {
         match (&$left, &$right) {
             (left_val, right_val) => {
                 if !(*left_val <= *right_val) {
-                    // The reborrows below are intentional. Without them, the stack slot for the
-                    // borrow is initialized even before the values are compared, leading to a
-                    // noticeable slow down.
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsAbsDiff as _, IsDebug as _, IsNotDebug as _, IsNotSub as _, IsSub as _};
+                    if ::core::cmp::PartialOrd::partial_cmp(&*left_val, &*right_val).is_none() {
+                        ::core::panic!(r#"assertion failed: left and right are not comparable (partial_cmp returned None)
+    left: `{:?}`,
+    right: `{:?}`"#, (&$crate::__private::MaybeDebug(&*left_val)).maybe_debug(), (&$crate::__private::MaybeDebug(&*right_val)).maybe_debug())
+                    }
                     ::core::panic!(r#"assertion failed: `(left <= right)`
     left: `{:?}`,
-    right: `{:?}`"#, &*left_val, &*right_val)
+    right: `{:?}`{}"#, (&$crate::__private::MaybeDebug(&*left_val)).maybe_debug(), (&$crate::__private::MaybeDebug(&*right_val)).maybe_debug(), (&&$crate::__private::MaybeDifference(&*left_val, &*right_val)).difference())
                 }
+                left_val
             }
         }
     };
@@ -56,13 +88,18 @@ macro_rules! assert_le {
         match (&$left, &$right) {
             (left_val, right_val) => {
                 if !(*left_val <= *right_val) {
-                    // The reborrows below are intentional. Without them, the stack slot for the
-                    // borrow is initialized even before the values are compared, leading to a
-                    // noticeable slow down.
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsAbsDiff as _, IsDebug as _, IsNotDebug as _, IsNotSub as _, IsSub as _};
+                    if ::core::cmp::PartialOrd::partial_cmp(&*left_val, &*right_val).is_none() {
+                        ::core::panic!(r#"assertion failed: left and right are not comparable (partial_cmp returned None)
+    left: `{:?}`,
+    right: `{:?}`: {}"#, (&$crate::__private::MaybeDebug(&*left_val)).maybe_debug(), (&$crate::__private::MaybeDebug(&*right_val)).maybe_debug(), ::core::format_args!($($arg)+))
+                    }
                     ::core::panic!(r#"assertion failed: `(left <= right)`
     left: `{:?}`,
-    right: `{:?}`: {}"#, &*left_val, &*right_val, ::core::format_args!($($arg)+))
+    right: `{:?}`{}: {}"#, (&$crate::__private::MaybeDebug(&*left_val)).maybe_debug(), (&$crate::__private::MaybeDebug(&*right_val)).maybe_debug(), (&&$crate::__private::MaybeDifference(&*left_val, &*right_val)).difference(), ::core::format_args!($($arg)+))
                 }
+                left_val
             }
         }
     };
@@ -70,20 +107,29 @@ macro_rules! assert_le {
 
 /// Asserts that the first expression is less or equal than the second on debug builds.
 ///
-/// This macro behaves the same as [`assert_le!`] on debug builds. On release builds it is a no-op.
+/// This macro behaves nearly the same as [`assert_le!`] on debug builds, although it does not
+/// return the first expression. On release builds it is a no-op.
 #[macro_export]
 macro_rules! debug_assert_le {
-    ($($arg:tt)*) => {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
         #[cfg(debug_assertions)]
-        $crate::assert_le!($($arg)*);
-    }
+        {
+            $crate::assert_le!($($arg)*);
+        }
+    }};
 }
 
 #[cfg(test)]
 mod tests {
+    use std::string::String;
+
+    #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+    struct NonCopy(usize);
+
     #[test]
     #[should_panic(
-        expected = "assertion failed: `(left <= right)`\n    left: `5`,\n    right: `3`"
+        expected = "assertion failed: `(left <= right)`\n    left: `5`,\n    right: `3`,\n    difference: `2`"
     )]
     fn greater_than() {
         assert_le!(5, 3);
@@ -101,7 +147,7 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "assertion failed: `(left <= right)`\n    left: `5`,\n    right: `3`: foo"
+        expected = "assertion failed: `(left <= right)`\n    left: `5`,\n    right: `3`,\n    difference: `2`: foo"
     )]
     fn greater_than_custom_message() {
         assert_le!(5, 3, "foo");
@@ -110,7 +156,7 @@ mod tests {
     #[test]
     #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
     #[should_panic(
-        expected = "assertion failed: `(left <= right)`\n    left: `5`,\n    right: `3`"
+        expected = "assertion failed: `(left <= right)`\n    left: `5`,\n    right: `3`,\n    difference: `2`"
     )]
     fn debug_greater_than() {
         debug_assert_le!(5, 3);
@@ -131,7 +177,7 @@ mod tests {
     #[test]
     #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
     #[should_panic(
-        expected = "assertion failed: `(left <= right)`\n    left: `5`,\n    right: `3`: foo"
+        expected = "assertion failed: `(left <= right)`\n    left: `5`,\n    right: `3`,\n    difference: `2`: foo"
     )]
     fn debug_greater_than_custom_message() {
         debug_assert_le!(5, 3, "foo");
@@ -142,4 +188,225 @@ mod tests {
     fn debug_release_greater_than() {
         debug_assert_le!(5, 3);
     }
+
+    #[test]
+    fn value_returned() {
+        let index = 3;
+        let value = assert_le!(index, 5);
+        assert_eq!(*value, 3);
+    }
+
+    #[test]
+    fn non_copy_not_moved() {
+        let left = String::from("a");
+        let right = String::from("b");
+        let value = assert_le!(left, right);
+        assert_eq!(value, "a");
+        drop(left);
+        drop(right);
+    }
+
+    #[test]
+    fn non_copy_field_not_moved() {
+        struct Pair {
+            left: NonCopy,
+            right: NonCopy,
+        }
+
+        let pair = Pair {
+            left: NonCopy(3),
+            right: NonCopy(5),
+        };
+        assert_le!(pair.left, pair.right);
+        assert_eq!(pair.left, NonCopy(3));
+    }
+
+    #[test]
+    fn debug_does_not_move() {
+        let left = NonCopy(3);
+        debug_assert_le!(left, NonCopy(5));
+        assert_eq!(left, NonCopy(3));
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_le!(3, 5);
+    }
+
+    #[test]
+    fn does_not_require_operands_to_impl_debug() {
+        #[derive(Eq, Ord, PartialEq, PartialOrd)]
+        struct Key(usize);
+
+        assert_le!(Key(3), Key(5));
+    }
+
+    #[test]
+    fn does_not_require_operands_to_impl_debug_custom_message() {
+        #[derive(Eq, Ord, PartialEq, PartialOrd)]
+        struct Key(usize);
+
+        assert_le!(Key(3), Key(5), "foo");
+    }
+
+    #[test]
+    fn debug_does_not_require_operands_to_impl_debug() {
+        #[derive(Eq, Ord, PartialEq, PartialOrd)]
+        struct Key(usize);
+
+        debug_assert_le!(Key(3), Key(5));
+    }
+
+    #[test]
+    fn debug_does_not_require_operands_to_impl_debug_custom_message() {
+        #[derive(Eq, Ord, PartialEq, PartialOrd)]
+        struct Key(usize);
+
+        debug_assert_le!(Key(3), Key(5), "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left <= right)`\n    left: `<non-Debug>`,\n    right: `<non-Debug>`"
+    )]
+    fn non_debug_operands() {
+        #[derive(Eq, Ord, PartialEq, PartialOrd)]
+        struct Key(usize);
+
+        assert_le!(Key(3), Key(1));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left <= right)`\n    left: `<non-Debug>`,\n    right: `<non-Debug>`: foo"
+    )]
+    fn non_debug_operands_custom_message() {
+        #[derive(Eq, Ord, PartialEq, PartialOrd)]
+        struct Key(usize);
+
+        assert_le!(Key(3), Key(1), "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left <= right)`\n    left: `<non-Debug>`,\n    right: `1`"
+    )]
+    fn non_debug_left_operand() {
+        struct Key(usize);
+
+        impl PartialEq<usize> for Key {
+            fn eq(&self, other: &usize) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialOrd<usize> for Key {
+            fn partial_cmp(&self, other: &usize) -> Option<core::cmp::Ordering> {
+                self.0.partial_cmp(other)
+            }
+        }
+
+        assert_le!(Key(3), 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left <= right)`\n    left: `127`,\n    right: `-128`,\n    difference: `255`"
+    )]
+    fn difference_does_not_overflow() {
+        assert_le!(127i8, -128);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left <= right)`\n    left: `2.5`,\n    right: `1.0`,\n    difference: `1.5`"
+    )]
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    fn difference_float() {
+        assert_le!(2.5, 1.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left <= right)`\n    left: `Meters(5)`,\n    right: `Meters(3)`,\n    difference: `Meters(2)`"
+    )]
+    fn difference_sub() {
+        #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+        struct Meters(u32);
+
+        impl core::ops::Sub for &Meters {
+            type Output = Meters;
+
+            fn sub(self, other: &Meters) -> Meters {
+                Meters(self.0 - other.0)
+            }
+        }
+
+        assert_le!(Meters(5), Meters(3));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left <= right)`\n    left: `Key(5)`,\n    right: `Key(3)`: foo"
+    )]
+    fn no_difference_without_sub() {
+        #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+        struct Key(u32);
+
+        assert_le!(Key(5), Key(3), "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: left and right are not comparable (partial_cmp returned None)\n    left: `NaN`,\n    right: `1.0`"
+    )]
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    fn not_comparable_left_nan() {
+        assert_le!(core::f64::NAN, 1.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: left and right are not comparable (partial_cmp returned None)\n    left: `1.0`,\n    right: `NaN`"
+    )]
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    fn not_comparable_right_nan() {
+        assert_le!(1.0, core::f64::NAN);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: left and right are not comparable (partial_cmp returned None)\n    left: `Flags(1)`,\n    right: `Flags(2)`: foo"
+    )]
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    fn not_comparable_custom_message() {
+        #[derive(Debug, PartialEq)]
+        struct Flags(u8);
+
+        impl PartialOrd for Flags {
+            fn partial_cmp(&self, other: &Flags) -> Option<core::cmp::Ordering> {
+                if self.0 == other.0 {
+                    Some(core::cmp::Ordering::Equal)
+                } else if self.0 & other.0 == self.0 {
+                    Some(core::cmp::Ordering::Less)
+                } else if self.0 & other.0 == other.0 {
+                    Some(core::cmp::Ordering::Greater)
+                } else {
+                    None
+                }
+            }
+        }
+
+        assert_le!(Flags(1), Flags(2), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: left and right are not comparable (partial_cmp returned None)\n    left: `NaN`,\n    right: `1.0`"
+    )]
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    fn debug_not_comparable() {
+        debug_assert_le!(core::f64::NAN, 1.0);
+    }
 }
@@ -0,0 +1,249 @@
+/// Asserts that a collection, string, or iterator has the expected length.
+///
+/// Works with the same values as [`assert_empty!`]: anything that can be iterated over by
+/// reference, such as arrays, slices, `Vec`s, maps, and sets, cloneable iterators, including most
+/// [`ExactSizeIterator`]s, and strings. The expression is only borrowed.
+///
+/// The length of a collection or iterator is its number of elements. The length of a string is
+/// given by [`str::len`], and is therefore its length in bytes rather than in `char`s.
+///
+/// On failure, the expected length, the actual length, and the contents are printed. Only the
+/// first 16 elements of a collection or iterator are shown, followed by a count of the elements
+/// that were left out. Strings are printed in full.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_len_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let items = vec![1, 2, 3, 4, 5];
+/// assert_len_eq!(items, 5);
+///
+/// assert_len_eq!(items.iter().filter(|item| **item > 2), 3);
+///
+/// // Strings are measured in bytes.
+/// assert_len_eq!("ü", 2);
+///
+/// // With a custom message
+/// assert_len_eq!(items, 5, "Expecting {:?} to have five items", items);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_len_eq!(vec![1, 2, 3, 4], 5);  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_empty!`]: crate::assert_empty!
+/// [`ExactSizeIterator`]: https://doc.rust-lang.org/core/iter/trait.ExactSizeIterator.html
+/// [`str::len`]: https://doc.rust-lang.org/core/primitive.str.html#method.len
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_len_eq!`]: crate::debug_assert_len_eq!
+#[macro_export]
+macro_rules! assert_len_eq {
+    ($collection:expr, $expected:expr $(,)?) => {
+        match (&$collection, &$expected) {
+            (collection_val, expected_val) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+                let description = (&&&&&$crate::__private::Collection(collection_val)).view().describe();
+                if description.length != *expected_val {
+                    ::core::panic!(r#"assertion failed, expected {} to have length `{}`
+    length: `{}`,
+    {}: `{:?}`"#, description.kind, expected_val, description.length, description.kind, description.contents)
+                }
+            }
+        }
+    };
+    ($collection:expr, $expected:expr, $($arg:tt)+) => {
+        match (&$collection, &$expected) {
+            (collection_val, expected_val) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+                let description = (&&&&&$crate::__private::Collection(collection_val)).view().describe();
+                if description.length != *expected_val {
+                    ::core::panic!(r#"assertion failed, expected {} to have length `{}`
+    length: `{}`,
+    {}: `{:?}`: {}"#, description.kind, expected_val, description.length, description.kind, description.contents, ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that a collection, string, or iterator has the expected length on debug builds.
+///
+/// This macro behaves the same as [`assert_len_eq!`] on debug builds. On release builds it is a
+/// no-op.
+#[macro_export]
+macro_rules! debug_assert_len_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_len_eq!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{BTreeMap, HashSet},
+        string::ToString,
+        vec,
+        vec::Vec,
+    };
+
+    #[test]
+    fn array() {
+        assert_len_eq!([1, 2, 3], 3);
+    }
+
+    #[test]
+    fn slice() {
+        let values: &[u32] = &[1, 2, 3];
+        assert_len_eq!(values, 3);
+    }
+
+    #[test]
+    fn vec() {
+        let values = vec![1, 2, 3];
+        assert_len_eq!(values, 3);
+        assert_len_eq!(&values, 3);
+    }
+
+    #[test]
+    fn empty_vec() {
+        let values: Vec<u32> = Vec::new();
+        assert_len_eq!(values, 0);
+    }
+
+    #[test]
+    fn map() {
+        let map: BTreeMap<&str, u32> = [("foo", 1), ("bar", 2)].iter().copied().collect();
+        assert_len_eq!(map, 2);
+    }
+
+    #[test]
+    fn hash_set() {
+        let set: HashSet<u32> = [1, 2, 2, 3].iter().copied().collect();
+        assert_len_eq!(set, 3);
+    }
+
+    #[test]
+    fn str() {
+        assert_len_eq!("foo", 3);
+    }
+
+    #[test]
+    fn str_length_in_bytes() {
+        assert_len_eq!("ü", 2);
+    }
+
+    #[test]
+    fn string() {
+        assert_len_eq!("foo".to_string(), 3);
+    }
+
+    #[test]
+    fn iterator() {
+        let values = [1, 2, 3, 4];
+        assert_len_eq!(values.iter(), 4);
+        assert_len_eq!(values.iter().filter(|value| **value > 2), 2);
+        assert_len_eq!(0..10, 10);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to have length `5`\n    length: `4`,\n    collection: `[1, 2, 3, 4]`"
+    )]
+    fn vec_wrong_length() {
+        assert_len_eq!(vec![1, 2, 3, 4], 5);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to have length `1`\n    length: `2`,\n    collection: `[(\"bar\", 2), (\"foo\", 1)]`"
+    )]
+    fn map_wrong_length() {
+        let map: BTreeMap<&str, u32> = [("foo", 1), ("bar", 2)].iter().copied().collect();
+        assert_len_eq!(map, 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected string to have length `1`\n    length: `2`,\n    string: `\"ü\"`"
+    )]
+    fn str_wrong_length() {
+        assert_len_eq!("ü", 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to have length `3`\n    length: `2`,\n    iterator: `[3, 4]`"
+    )]
+    fn iterator_wrong_length() {
+        let values = [1, 2, 3, 4];
+        assert_len_eq!(values.iter().filter(|value| **value > 2), 3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to have length `5`\n    length: `100`,\n    collection: `[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, ... (84 more)]`"
+    )]
+    fn truncated() {
+        let values: Vec<u32> = (0..100).collect();
+        assert_len_eq!(values, 5);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection to have length `5`\n    length: `4`,\n    collection: `[1, 2, 3, 4]`: foo"
+    )]
+    fn wrong_length_custom_message() {
+        assert_len_eq!(vec![1, 2, 3, 4], 5, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_vec() {
+        debug_assert_len_eq!(vec![1, 2, 3], 3);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected collection to have length `5`\n    length: `4`,\n    collection: `[1, 2, 3, 4]`"
+    )]
+    fn debug_vec_wrong_length() {
+        debug_assert_len_eq!(vec![1, 2, 3, 4], 5);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected collection to have length `5`\n    length: `4`,\n    collection: `[1, 2, 3, 4]`: foo"
+    )]
+    fn debug_vec_wrong_length_custom_message() {
+        debug_assert_len_eq!(vec![1, 2, 3, 4], 5, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_vec_wrong_length() {
+        debug_assert_len_eq!(vec![1, 2, 3, 4], 5);
+    }
+}
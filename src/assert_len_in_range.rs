@@ -0,0 +1,237 @@
+/// Asserts that the length of a collection, string, or iterator is contained in the given range.
+///
+/// Works with the same values as [`assert_len_eq!`], and measures their length in the same way:
+/// the number of elements of a collection or iterator, and the length in bytes of a string. The
+/// expression is only borrowed. The range can be any range over `usize` implementing
+/// [`RangeBounds`], such as `a..b`, `a..=b`, `a..`, `..b`, and `..=b`.
+///
+/// On failure, the actual length, the range, and the contents are printed. Only the first 16
+/// elements of a collection or iterator are shown, followed by a count of the elements that were
+/// left out. Strings are printed in full.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_len_in_range!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let chunks = vec![[0u8; 4], [1u8; 4], [2u8; 4]];
+/// assert_len_in_range!(chunks, 1..=16);
+///
+/// assert_len_in_range!("foo", ..8);
+///
+/// // With a custom message
+/// assert_len_in_range!(chunks, 1..=16, "Expecting between 1 and 16 chunks");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let chunks: Vec<[u8; 4]> = Vec::new();
+/// assert_len_in_range!(chunks, 1..=16);  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_len_eq!`]: crate::assert_len_eq!
+/// [`RangeBounds`]: https://doc.rust-lang.org/core/ops/trait.RangeBounds.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_len_in_range!`]: crate::debug_assert_len_in_range!
+#[macro_export]
+macro_rules! assert_len_in_range {
+    ($collection:expr, $range:expr $(,)?) => {
+        match (&$collection, $range) {
+            (collection_val, range) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+                let description = (&&&&&$crate::__private::Collection(collection_val)).view().describe();
+                if !::core::ops::RangeBounds::<usize>::contains(&range, &description.length) {
+                    ::core::panic!(r#"assertion failed, expected {} length to be in range
+    length: `{}`,
+    range: `{}`,
+    {}: `{:?}`"#, description.kind, description.length, ::core::stringify!($range), description.kind, description.contents)
+                }
+            }
+        }
+    };
+    ($collection:expr, $range:expr, $($arg:tt)+) => {
+        match (&$collection, $range) {
+            (collection_val, range) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+                let description = (&&&&&$crate::__private::Collection(collection_val)).view().describe();
+                if !::core::ops::RangeBounds::<usize>::contains(&range, &description.length) {
+                    ::core::panic!(r#"assertion failed, expected {} length to be in range
+    length: `{}`,
+    range: `{}`,
+    {}: `{:?}`: {}"#, description.kind, description.length, ::core::stringify!($range), description.kind, description.contents, ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that the length of a collection, string, or iterator is contained in the given range on
+/// debug builds.
+///
+/// This macro behaves the same as [`assert_len_in_range!`] on debug builds. On release builds it
+/// is a no-op.
+#[macro_export]
+macro_rules! debug_assert_len_in_range {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_len_in_range!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, string::ToString, vec, vec::Vec};
+
+    #[test]
+    fn vec() {
+        let values = vec![1, 2, 3];
+        assert_len_in_range!(values, 1..=16);
+        assert_len_in_range!(&values, 1..=16);
+    }
+
+    #[test]
+    fn at_lower_bound() {
+        assert_len_in_range!([1], 1..=16);
+    }
+
+    #[test]
+    fn at_included_upper_bound() {
+        let values: Vec<u32> = (0..16).collect();
+        assert_len_in_range!(values, 1..=16);
+    }
+
+    #[test]
+    fn below_excluded_upper_bound() {
+        let values: Vec<u32> = (0..15).collect();
+        assert_len_in_range!(values, 1..16);
+    }
+
+    #[test]
+    fn range_from() {
+        assert_len_in_range!([1, 2, 3], 2..);
+    }
+
+    #[test]
+    fn range_to() {
+        let values: Vec<u32> = Vec::new();
+        assert_len_in_range!(values, ..1);
+    }
+
+    #[test]
+    fn map() {
+        let map: BTreeMap<&str, u32> = [("foo", 1), ("bar", 2)].iter().copied().collect();
+        assert_len_in_range!(map, 1..3);
+    }
+
+    #[test]
+    fn string() {
+        assert_len_in_range!("foo", 3..=3);
+        assert_len_in_range!("foo".to_string(), ..8);
+    }
+
+    #[test]
+    fn iterator() {
+        let values = [1, 2, 3, 4];
+        assert_len_in_range!(values.iter().filter(|value| **value > 2), 1..=2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection length to be in range\n    length: `0`,\n    range: `1..=16`,\n    collection: `[]`"
+    )]
+    fn below_lower_bound() {
+        let values: Vec<u32> = Vec::new();
+        assert_len_in_range!(values, 1..=16);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection length to be in range\n    length: `17`,\n    range: `1..=16`,\n    collection: `[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, ... (1 more)]`"
+    )]
+    fn above_included_upper_bound() {
+        let values: Vec<u32> = (0..17).collect();
+        assert_len_in_range!(values, 1..=16);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection length to be in range\n    length: `3`,\n    range: `1..3`,\n    collection: `[1, 2, 3]`"
+    )]
+    fn at_excluded_upper_bound() {
+        assert_len_in_range!([1, 2, 3], 1..3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected string length to be in range\n    length: `3`,\n    range: `..3`,\n    string: `\"foo\"`"
+    )]
+    fn string_not_in_range() {
+        assert_len_in_range!("foo", ..3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator length to be in range\n    length: `2`,\n    range: `3..`,\n    iterator: `[3, 4]`"
+    )]
+    fn iterator_not_in_range() {
+        let values = [1, 2, 3, 4];
+        assert_len_in_range!(values.iter().filter(|value| **value > 2), 3..);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected collection length to be in range\n    length: `3`,\n    range: `1..3`,\n    collection: `[1, 2, 3]`: foo"
+    )]
+    fn not_in_range_custom_message() {
+        assert_len_in_range!([1, 2, 3], 1..3, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_vec() {
+        debug_assert_len_in_range!(vec![1, 2, 3], 1..=16);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected collection length to be in range\n    length: `3`,\n    range: `1..3`,\n    collection: `[1, 2, 3]`"
+    )]
+    fn debug_at_excluded_upper_bound() {
+        debug_assert_len_in_range!([1, 2, 3], 1..3);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected collection length to be in range\n    length: `3`,\n    range: `1..3`,\n    collection: `[1, 2, 3]`: foo"
+    )]
+    fn debug_not_in_range_custom_message() {
+        debug_assert_len_in_range!([1, 2, 3], 1..3, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_in_range() {
+        debug_assert_len_in_range!([1, 2, 3], 1..3);
+    }
+}
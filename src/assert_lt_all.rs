@@ -0,0 +1,246 @@
+use core::borrow::Borrow;
+
+/// Asserts that the first expression is less than every element of the second, returning the
+/// first.
+///
+/// The second expression can be anything implementing [`IntoIterator`], such as a reference to an
+/// array or slice. Each element is compared against the first expression with `<`. Elements
+/// yielded by reference are borrowed as the type of the first expression, so the first expression
+/// is given by value whether the iterator yields values or references. The assertion panics at the
+/// first element for which the comparison does not hold, reporting the element and its index. An
+/// empty iterator passes.
+///
+/// As with [`assert_lt!`], the first expression is taken by value and returned from the macro
+/// call, while the elements are only borrowed.
+///
+/// Neither the value nor the elements are required to implement [`Debug`]. Values which do not
+/// implement it are displayed as `<non-Debug>` in the panic message.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_lt_all!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let candidates = [5, 2, 4];
+/// assert_lt_all!(1, &candidates);
+///
+/// // With a custom message
+/// assert_lt_all!(1, &candidates, "Expecting {} to be less than all of {:?}", 1, candidates);
+/// # }
+/// ```
+///
+/// The first value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let value = assert_lt_all!(1, vec![5, 2, 4]);
+/// assert_eq!(value, 1);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_lt_all!(3, &[5, 2, 4]);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`assert_lt!`]: crate::assert_lt!
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_lt_all!`]: crate::debug_assert_lt_all!
+#[macro_export]
+macro_rules! assert_lt_all {
+    ($left:expr, $right:expr $(,)?) => {
+        match $left {
+            left_val => {
+                for (index, element) in ::core::iter::Iterator::enumerate(::core::iter::IntoIterator::into_iter($right)) {
+                    let element = $crate::__private::borrow_element(&left_val, &element);
+                    if !::core::cmp::PartialOrd::lt(&left_val, element) {
+                        #[allow(unused_imports)]
+                        use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                        ::core::panic!(r#"assertion failed: `(left < right)` for all elements
+    left: `{:?}`,
+    right: `{:?}`,
+    index: `{}`"#, (&$crate::__private::MaybeDebug(&left_val)).maybe_debug(), (&$crate::__private::MaybeDebug(element)).maybe_debug(), index)
+                    }
+                }
+                left_val
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match $left {
+            left_val => {
+                for (index, element) in ::core::iter::Iterator::enumerate(::core::iter::IntoIterator::into_iter($right)) {
+                    let element = $crate::__private::borrow_element(&left_val, &element);
+                    if !::core::cmp::PartialOrd::lt(&left_val, element) {
+                        #[allow(unused_imports)]
+                        use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                        ::core::panic!(r#"assertion failed: `(left < right)` for all elements
+    left: `{:?}`,
+    right: `{:?}`,
+    index: `{}`: {}"#, (&$crate::__private::MaybeDebug(&left_val)).maybe_debug(), (&$crate::__private::MaybeDebug(element)).maybe_debug(), index, ::core::format_args!($($arg)+))
+                    }
+                }
+                left_val
+            }
+        }
+    };
+}
+
+/// Asserts that the first expression is less than every element of the second on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_lt_all!`] on debug builds, although it does not
+/// return the first value. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_lt_all {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_lt_all!($($arg)*);
+        }
+    }};
+}
+
+/// Borrows an element as the type of the value it is compared against.
+///
+/// This allows comparing items yielded by reference, such as those of [`slice::iter()`], against a
+/// value given by value, while items yielded by value are passed through unchanged.
+///
+/// This is an implementation detail of [`assert_lt_all!`], [`assert_le_all!`], [`assert_gt_all!`],
+/// and [`assert_ge_all!`] and is not part of the public API.
+///
+/// [`slice::iter()`]: https://doc.rust-lang.org/core/primitive.slice.html#method.iter
+#[doc(hidden)]
+pub fn borrow_element<'a, T, E>(_value: &T, element: &'a E) -> &'a T
+where
+    T: ?Sized,
+    E: Borrow<T> + ?Sized,
+{
+    element.borrow()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn lt_all() {
+        assert_lt_all!(1, &[5, 2, 4]);
+    }
+
+    #[test]
+    fn lt_all_by_value() {
+        assert_lt_all!(1, [5, 2, 4].iter().copied());
+    }
+
+    #[test]
+    fn empty() {
+        let candidates: [u32; 0] = [];
+        assert_lt_all!(1, &candidates);
+    }
+
+    #[test]
+    fn by_reference() {
+        let value = 1;
+        let returned = assert_lt_all!(&value, &[5, 2, 4]);
+        assert_eq!(returned, &1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left < right)` for all elements\n    left: `5`,\n    right: `2`,\n    index: `0`"
+    )]
+    fn violation_first() {
+        assert_lt_all!(5, &[2, 7, 7]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left < right)` for all elements\n    left: `1`,\n    right: `1`,\n    index: `2`"
+    )]
+    fn violation_middle() {
+        assert_lt_all!(1, &[5, 2, 1, 4]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left < right)` for all elements\n    left: `1`,\n    right: `1`,\n    index: `2`: foo"
+    )]
+    fn violation_middle_custom_message() {
+        assert_lt_all!(1, &[5, 2, 1, 4], "foo");
+    }
+
+    #[test]
+    fn value_returned() {
+        let value = assert_lt_all!(1, [5, 2, 4].iter().copied());
+        assert_eq!(value, 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left < right)` for all elements\n    left: `<non-Debug>`,\n    right: `<non-Debug>`,\n    index: `0`"
+    )]
+    fn does_not_require_values_to_impl_debug() {
+        #[derive(PartialEq, PartialOrd)]
+        struct Weight(u32);
+
+        assert_lt_all!(Weight(5), &[Weight(2)]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_lt_all() {
+        debug_assert_lt_all!(1, &[5, 2, 4]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(left < right)` for all elements\n    left: `5`,\n    right: `2`,\n    index: `0`"
+    )]
+    fn debug_violation_first() {
+        debug_assert_lt_all!(5, &[2, 7, 7]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(left < right)` for all elements\n    left: `1`,\n    right: `1`,\n    index: `2`"
+    )]
+    fn debug_violation_middle() {
+        debug_assert_lt_all!(1, &[5, 2, 1, 4]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(left < right)` for all elements\n    left: `1`,\n    right: `1`,\n    index: `2`: foo"
+    )]
+    fn debug_violation_middle_custom_message() {
+        debug_assert_lt_all!(1, &[5, 2, 1, 4], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_violation_first() {
+        debug_assert_lt_all!(5, &[2, 7, 7]);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_lt_all!(1, &[5, 2, 4]);
+    }
+}
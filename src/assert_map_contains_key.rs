@@ -0,0 +1,287 @@
+use crate::assert_contains::Truncated;
+use core::{
+    borrow::Borrow,
+    hash::{BuildHasher, Hash},
+};
+use std::collections::{btree_map, hash_map, BTreeMap, HashMap};
+
+/// Asserts that a map contains the given key, returning a reference to the associated value.
+///
+/// Works with both [`HashMap`] and [`BTreeMap`]. The map is only borrowed, and the key is passed by
+/// reference, just as it is to [`HashMap::get`]. This means a map with `String` keys can be
+/// queried with a `&str`, and a map with integer keys is queried with `&1`.
+///
+/// On failure, the missing key is printed along with the keys that the map does contain. Only the
+/// first 16 keys are shown, followed by a count of the keys that were left out.
+///
+/// This macro requires the `std` feature.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_map_contains_key!`] for assertions that are not enabled in release builds
+/// by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # use std::collections::HashMap;
+/// # fn main() {
+/// let mut headers = HashMap::new();
+/// headers.insert("content-type".to_string(), "text/html");
+///
+/// assert_map_contains_key!(headers, "content-type");
+///
+/// // With a custom message
+/// assert_map_contains_key!(headers, "content-type", "Expecting a content type");
+/// # }
+/// ```
+///
+/// The associated value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # use std::collections::BTreeMap;
+/// # fn main() {
+/// let mut scores = BTreeMap::new();
+/// scores.insert(1, "alice");
+///
+/// let name = assert_map_contains_key!(scores, &1);
+/// assert_eq!(name, &"alice");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # use std::collections::HashMap;
+/// # fn main() {
+/// let headers: HashMap<String, String> = HashMap::new();
+/// assert_map_contains_key!(headers, "content-type");  // Will panic
+/// # }
+/// ```
+///
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+/// [`HashMap::get`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html#method.get
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_map_contains_key!`]: crate::debug_assert_map_contains_key!
+#[macro_export]
+macro_rules! assert_map_contains_key {
+    ($map:expr, $key:expr $(,)?) => {
+        match (&$map, $key) {
+            (map_val, key_val) => match $crate::__private::Map::lookup(map_val, key_val) {
+                ::core::result::Result::Ok(value) => value,
+                ::core::result::Result::Err(keys) => {
+                    ::core::panic!(r#"assertion failed, expected map to contain key
+    key: `{:?}`,
+    keys: `{:?}`"#, key_val, keys)
+                }
+            },
+        }
+    };
+    ($map:expr, $key:expr, $($arg:tt)+) => {
+        match (&$map, $key) {
+            (map_val, key_val) => match $crate::__private::Map::lookup(map_val, key_val) {
+                ::core::result::Result::Ok(value) => value,
+                ::core::result::Result::Err(keys) => {
+                    ::core::panic!(r#"assertion failed, expected map to contain key
+    key: `{:?}`,
+    keys: `{:?}`: {}"#, key_val, keys, ::core::format_args!($($arg)+))
+                }
+            },
+        }
+    };
+}
+
+/// Asserts that a map contains the given key on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_map_contains_key!`] on debug builds, although it
+/// does not return the associated value. On release builds it is a no-op.
+///
+/// This macro requires the `std` feature.
+#[macro_export]
+macro_rules! debug_assert_map_contains_key {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_map_contains_key!($($arg)*);
+        }
+    }};
+}
+
+/// Maps whose values can be looked up by a borrowed key.
+///
+/// This is an implementation detail of [`assert_map_contains_key!`] and [`assert_map_get_eq!`] and
+/// is not part of the public API.
+#[doc(hidden)]
+pub trait Map<'a, Q: ?Sized> {
+    type Value: 'a;
+    type Keys: Iterator;
+
+    /// Returns the value associated with the key, or the map's keys if there is none.
+    fn lookup(&'a self, key: &Q) -> Result<&'a Self::Value, Truncated<Self::Keys>>;
+}
+
+impl<'a, K, V, S, Q> Map<'a, Q> for HashMap<K, V, S>
+where
+    K: Borrow<Q> + Hash + Eq + 'a,
+    V: 'a,
+    S: BuildHasher,
+    Q: Hash + Eq + ?Sized,
+{
+    type Value = V;
+    type Keys = hash_map::Keys<'a, K, V>;
+
+    fn lookup(&'a self, key: &Q) -> Result<&'a V, Truncated<Self::Keys>> {
+        self.get(key).ok_or_else(|| Truncated::new(self.keys()))
+    }
+}
+
+impl<'a, K, V, Q> Map<'a, Q> for BTreeMap<K, V>
+where
+    K: Borrow<Q> + Ord + 'a,
+    V: 'a,
+    Q: Ord + ?Sized,
+{
+    type Value = V;
+    type Keys = btree_map::Keys<'a, K, V>;
+
+    fn lookup(&'a self, key: &Q) -> Result<&'a V, Truncated<Self::Keys>> {
+        self.get(key).ok_or_else(|| Truncated::new(self.keys()))
+    }
+}
+
+impl<'a, 'b, M, Q> Map<'a, Q> for &'b M
+where
+    M: Map<'a, Q>,
+    Q: ?Sized,
+    'b: 'a,
+{
+    type Value = M::Value;
+    type Keys = M::Keys;
+
+    fn lookup(&'a self, key: &Q) -> Result<&'a M::Value, Truncated<M::Keys>> {
+        (**self).lookup(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{BTreeMap, HashMap},
+        string::{String, ToString},
+    };
+
+    fn hash_map() -> HashMap<String, u32> {
+        let mut map = HashMap::new();
+        map.insert("foo".to_string(), 1);
+        map
+    }
+
+    fn btree_map() -> BTreeMap<u32, &'static str> {
+        (0..20).map(|key| (key, "value")).collect()
+    }
+
+    #[test]
+    fn hash_map_contains_key() {
+        assert_map_contains_key!(hash_map(), "foo");
+    }
+
+    #[test]
+    fn btree_map_contains_key() {
+        assert_map_contains_key!(btree_map(), &5);
+    }
+
+    #[test]
+    fn map_reference_contains_key() {
+        let map = hash_map();
+        assert_map_contains_key!(&map, "foo");
+    }
+
+    #[test]
+    fn returns_value() {
+        let map = hash_map();
+        assert_eq!(assert_map_contains_key!(map, "foo"), &1);
+    }
+
+    #[test]
+    fn returns_value_custom_message() {
+        let map = btree_map();
+        assert_eq!(assert_map_contains_key!(map, &5, "foo"), &"value");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected map to contain key\n    key: `\"bar\"`,\n    keys: `[\"foo\"]`"
+    )]
+    fn hash_map_missing_key() {
+        assert_map_contains_key!(hash_map(), "bar");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected map to contain key\n    key: `20`,\n    keys: `[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, ... (4 more)]`"
+    )]
+    fn btree_map_missing_key_truncated() {
+        assert_map_contains_key!(btree_map(), &20);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected map to contain key\n    key: `1`,\n    keys: `[]`"
+    )]
+    fn empty_map() {
+        let map: BTreeMap<u32, u32> = BTreeMap::new();
+        assert_map_contains_key!(map, &1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected map to contain key\n    key: `\"bar\"`,\n    keys: `[\"foo\"]`: foo"
+    )]
+    fn missing_key_custom_message() {
+        assert_map_contains_key!(hash_map(), "bar", "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_contains_key() {
+        debug_assert_map_contains_key!(hash_map(), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected map to contain key\n    key: `\"bar\"`,\n    keys: `[\"foo\"]`"
+    )]
+    fn debug_missing_key() {
+        debug_assert_map_contains_key!(hash_map(), "bar");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected map to contain key\n    key: `\"bar\"`,\n    keys: `[\"foo\"]`: foo"
+    )]
+    fn debug_missing_key_custom_message() {
+        debug_assert_map_contains_key!(hash_map(), "bar", "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_missing_key() {
+        debug_assert_map_contains_key!(hash_map(), "bar");
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_map_contains_key!(hash_map(), "foo");
+    }
+}
@@ -0,0 +1,495 @@
+use core::{
+    fmt,
+    hash::{BuildHasher, Hash},
+};
+use std::{
+    collections::{btree_map, hash_map, BTreeMap, HashMap},
+    vec::Vec,
+};
+
+/// Asserts that two maps contain the same keys, mapped to equal values.
+///
+/// Works with both [`HashMap`] and [`BTreeMap`], and the two maps do not need to be of the same
+/// kind, although they must have the same key type. Values are compared using [`PartialEq`]. The
+/// maps are only borrowed.
+///
+/// Rather than printing both maps in full, the failure message lists the differences in three
+/// categories: keys that are missing from the actual map, unexpected keys in the actual map, and
+/// keys whose values differ, along with both values. Categories without any differences are left
+/// out. When the keys implement [`Ord`], each list is
+/// sorted by key so that the output is deterministic. Keys and values must implement [`Debug`].
+///
+/// This macro requires the `std` feature.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_map_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # use std::collections::{BTreeMap, HashMap};
+/// # fn main() {
+/// let mut actual = HashMap::new();
+/// actual.insert("alice", 3);
+/// actual.insert("bob", 5);
+///
+/// let mut expected = BTreeMap::new();
+/// expected.insert("alice", 3);
+/// expected.insert("bob", 5);
+///
+/// assert_map_eq!(actual, expected);
+///
+/// // With a custom message
+/// assert_map_eq!(actual, expected, "Expecting {} scores", 2);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # use std::collections::HashMap;
+/// # fn main() {
+/// let mut actual = HashMap::new();
+/// actual.insert("alice", 3);
+///
+/// let mut expected = HashMap::new();
+/// expected.insert("alice", 4);
+///
+/// assert_map_eq!(actual, expected);  // Will panic
+/// # }
+/// ```
+///
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`Ord`]: https://doc.rust-lang.org/core/cmp/trait.Ord.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_map_eq!`]: crate::debug_assert_map_eq!
+#[macro_export]
+macro_rules! assert_map_eq {
+    ($actual:expr, $expected:expr $(,)?) => {
+        match (&$actual, &$expected) {
+            (actual_val, expected_val) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{OrdKeys as _, UnorderedKeys as _};
+                let difference = (&$crate::__private::MapPair(actual_val, expected_val)).difference();
+                if !difference.is_empty() {
+                    ::core::panic!(r#"assertion failed, expected maps to be equal
+{}"#, difference)
+                }
+            }
+        }
+    };
+    ($actual:expr, $expected:expr, $($arg:tt)+) => {
+        match (&$actual, &$expected) {
+            (actual_val, expected_val) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{OrdKeys as _, UnorderedKeys as _};
+                let difference = (&$crate::__private::MapPair(actual_val, expected_val)).difference();
+                if !difference.is_empty() {
+                    ::core::panic!(r#"assertion failed, expected maps to be equal
+{}: {}"#, difference, ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two maps contain the same keys, mapped to equal values, on debug builds.
+///
+/// This macro behaves the same as [`assert_map_eq!`] on debug builds. On release builds it is a
+/// no-op.
+///
+/// This macro requires the `std` feature.
+#[macro_export]
+macro_rules! debug_assert_map_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_map_eq!($($arg)*);
+        }
+    }};
+}
+
+/// Maps whose entries can be iterated over and looked up by key.
+#[doc(hidden)]
+pub trait Entries<'a> {
+    type Key: 'a;
+    type Value: 'a;
+    type Iter: Iterator<Item = (&'a Self::Key, &'a Self::Value)>;
+
+    fn entries(&'a self) -> Self::Iter;
+
+    fn value(&'a self, key: &Self::Key) -> Option<&'a Self::Value>;
+}
+
+impl<'a, K, V, S> Entries<'a> for HashMap<K, V, S>
+where
+    K: Hash + Eq + 'a,
+    V: 'a,
+    S: BuildHasher,
+{
+    type Key = K;
+    type Value = V;
+    type Iter = hash_map::Iter<'a, K, V>;
+
+    fn entries(&'a self) -> Self::Iter {
+        self.iter()
+    }
+
+    fn value(&'a self, key: &K) -> Option<&'a V> {
+        self.get(key)
+    }
+}
+
+impl<'a, K, V> Entries<'a> for BTreeMap<K, V>
+where
+    K: Ord + 'a,
+    V: 'a,
+{
+    type Key = K;
+    type Value = V;
+    type Iter = btree_map::Iter<'a, K, V>;
+
+    fn entries(&'a self) -> Self::Iter {
+        self.iter()
+    }
+
+    fn value(&'a self, key: &K) -> Option<&'a V> {
+        self.get(key)
+    }
+}
+
+/// Formats a list of entries as a map.
+#[doc(hidden)]
+pub struct EntryList<K, V>(Vec<(K, V)>);
+
+impl<K, V> fmt::Debug for EntryList<K, V>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_map()
+            .entries(self.0.iter().map(|(key, value)| (key, value)))
+            .finish()
+    }
+}
+
+/// A pair of differing values.
+#[doc(hidden)]
+pub struct ValuePair<'a, V, W> {
+    actual: &'a V,
+    expected: &'a W,
+}
+
+impl<V, W> fmt::Debug for ValuePair<'_, V, W>
+where
+    V: fmt::Debug,
+    W: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "(actual: {:?}, expected: {:?})",
+            self.actual, self.expected
+        )
+    }
+}
+
+/// The differences between two maps.
+#[doc(hidden)]
+pub struct MapDifference<'a, K, V, W> {
+    pub missing: EntryList<&'a K, &'a W>,
+    pub unexpected: EntryList<&'a K, &'a V>,
+    pub differing: EntryList<&'a K, ValuePair<'a, V, W>>,
+}
+
+impl<K, V, W> MapDifference<'_, K, V, W> {
+    pub fn is_empty(&self) -> bool {
+        self.missing.0.is_empty() && self.unexpected.0.is_empty() && self.differing.0.is_empty()
+    }
+}
+
+impl<K, V, W> fmt::Display for MapDifference<'_, K, V, W>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+    W: fmt::Debug,
+{
+    /// Lists each kind of difference on its own line, leaving out the kinds that didn't occur.
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut separator = "";
+        if !self.missing.0.is_empty() {
+            write!(formatter, "    missing keys: `{:?}`", self.missing)?;
+            separator = ",\n";
+        }
+        if !self.unexpected.0.is_empty() {
+            write!(
+                formatter,
+                "{}    unexpected keys: `{:?}`",
+                separator, self.unexpected
+            )?;
+            separator = ",\n";
+        }
+        if !self.differing.0.is_empty() {
+            write!(
+                formatter,
+                "{}    differing values: `{:?}`",
+                separator, self.differing
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The actual and expected maps being compared.
+///
+/// Whether the differences are sorted is chosen through autoref-based specialization: calling
+/// `difference()` on a `&MapPair<A, E>` resolves to [`OrdKeys`] when the keys implement `Ord`, and
+/// falls back to [`UnorderedKeys`] otherwise. Both traits must be in scope at the call site.
+///
+/// This is an implementation detail of [`assert_map_eq!`] and is not part of the public API.
+#[doc(hidden)]
+pub struct MapPair<'a, A, E>(pub &'a A, pub &'a E);
+
+/// Collects the differences between two maps, in the order of the actual map's entries followed
+/// by the expected map's entries.
+fn difference<'a, K, V, W, A, E>(actual: &'a A, expected: &'a E) -> MapDifference<'a, K, V, W>
+where
+    A: Entries<'a, Key = K, Value = V>,
+    E: Entries<'a, Key = K, Value = W>,
+    V: PartialEq<W>,
+{
+    let mut unexpected = Vec::new();
+    let mut differing = Vec::new();
+    for (key, actual_value) in actual.entries() {
+        match expected.value(key) {
+            Some(expected_value) => {
+                if *actual_value != *expected_value {
+                    differing.push((
+                        key,
+                        ValuePair {
+                            actual: actual_value,
+                            expected: expected_value,
+                        },
+                    ));
+                }
+            }
+            None => unexpected.push((key, actual_value)),
+        }
+    }
+    let missing = expected
+        .entries()
+        .filter(|(key, _)| actual.value(key).is_none())
+        .collect();
+    MapDifference {
+        missing: EntryList(missing),
+        unexpected: EntryList(unexpected),
+        differing: EntryList(differing),
+    }
+}
+
+#[doc(hidden)]
+pub trait OrdKeys<'a, K, V, W> {
+    fn difference(&self) -> MapDifference<'a, K, V, W>;
+}
+
+impl<'a, K, V, W, A, E> OrdKeys<'a, K, V, W> for MapPair<'a, A, E>
+where
+    A: Entries<'a, Key = K, Value = V>,
+    E: Entries<'a, Key = K, Value = W>,
+    K: Ord + 'a,
+    V: PartialEq<W> + 'a,
+    W: 'a,
+{
+    /// Returns the differences, with each list sorted by key.
+    fn difference(&self) -> MapDifference<'a, K, V, W> {
+        let mut difference = difference(self.0, self.1);
+        difference.missing.0.sort_by(|a, b| a.0.cmp(b.0));
+        difference.unexpected.0.sort_by(|a, b| a.0.cmp(b.0));
+        difference.differing.0.sort_by(|a, b| a.0.cmp(b.0));
+        difference
+    }
+}
+
+#[doc(hidden)]
+pub trait UnorderedKeys<'a, K, V, W> {
+    fn difference(&self) -> MapDifference<'a, K, V, W>;
+}
+
+impl<'a, K, V, W, A, E> UnorderedKeys<'a, K, V, W> for &MapPair<'a, A, E>
+where
+    A: Entries<'a, Key = K, Value = V>,
+    E: Entries<'a, Key = K, Value = W>,
+    K: 'a,
+    V: PartialEq<W> + 'a,
+    W: 'a,
+{
+    /// Returns the differences in the order the maps' entries are iterated.
+    fn difference(&self) -> MapDifference<'a, K, V, W> {
+        difference(self.0, self.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    struct Key(&'static str);
+
+    fn hash_map(entries: &[(&'static str, u32)]) -> HashMap<&'static str, u32> {
+        entries.iter().copied().collect()
+    }
+
+    fn btree_map(entries: &[(&'static str, u32)]) -> BTreeMap<&'static str, u32> {
+        entries.iter().copied().collect()
+    }
+
+    #[test]
+    fn equal() {
+        assert_map_eq!(
+            hash_map(&[("foo", 1), ("bar", 2)]),
+            hash_map(&[("bar", 2), ("foo", 1)])
+        );
+    }
+
+    #[test]
+    fn equal_different_kinds() {
+        assert_map_eq!(
+            hash_map(&[("foo", 1), ("bar", 2)]),
+            btree_map(&[("bar", 2), ("foo", 1)])
+        );
+    }
+
+    #[test]
+    fn equal_empty() {
+        assert_map_eq!(hash_map(&[]), btree_map(&[]));
+    }
+
+    #[test]
+    fn equal_unordered_keys() {
+        let mut actual = HashMap::new();
+        actual.insert(Key("foo"), 1);
+        let mut expected = HashMap::new();
+        expected.insert(Key("foo"), 1);
+        assert_map_eq!(actual, expected);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected maps to be equal\n    missing keys: `{\"baz\": 3, \"qux\": 4}`"
+    )]
+    fn missing_keys() {
+        assert_map_eq!(
+            hash_map(&[("foo", 1)]),
+            hash_map(&[("qux", 4), ("foo", 1), ("baz", 3)])
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected maps to be equal\n    missing keys: `{\"bar\": 2}`: foo"
+    )]
+    fn missing_keys_custom_message() {
+        assert_map_eq!(
+            hash_map(&[("foo", 1)]),
+            hash_map(&[("foo", 1), ("bar", 2)]),
+            "foo"
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected maps to be equal\n    unexpected keys: `{\"bar\": 2, \"baz\": 3}`"
+    )]
+    fn unexpected_keys() {
+        assert_map_eq!(
+            hash_map(&[("foo", 1), ("baz", 3), ("bar", 2)]),
+            hash_map(&[("foo", 1)])
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected maps to be equal\n    differing values: `{\"bar\": (actual: 2, expected: 3), \"foo\": (actual: 1, expected: 4)}`"
+    )]
+    fn differing_values() {
+        assert_map_eq!(
+            hash_map(&[("foo", 1), ("bar", 2)]),
+            btree_map(&[("foo", 4), ("bar", 3)])
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected maps to be equal\n    missing keys: `{\"qux\": 4}`,\n    unexpected keys: `{\"baz\": 3}`,\n    differing values: `{\"bar\": (actual: 2, expected: 5)}`"
+    )]
+    fn all_differences() {
+        assert_map_eq!(
+            btree_map(&[("foo", 1), ("bar", 2), ("baz", 3)]),
+            hash_map(&[("foo", 1), ("bar", 5), ("qux", 4)])
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected maps to be equal\n    differing values: `{Key(\"foo\"): (actual: 1, expected: 2)}`"
+    )]
+    fn differing_values_unordered_keys() {
+        let mut actual = HashMap::new();
+        actual.insert(Key("foo"), 1);
+        let mut expected = HashMap::new();
+        expected.insert(Key("foo"), 2);
+        assert_map_eq!(actual, expected);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected maps to be equal\n    differing values: `{\"foo\": (actual: 1, expected: 2)}`: foo"
+    )]
+    fn differing_values_custom_message() {
+        assert_map_eq!(hash_map(&[("foo", 1)]), hash_map(&[("foo", 2)]), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_equal() {
+        debug_assert_map_eq!(hash_map(&[("foo", 1)]), btree_map(&[("foo", 1)]));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected maps to be equal\n    differing values: `{\"foo\": (actual: 1, expected: 2)}`"
+    )]
+    fn debug_differing_values() {
+        debug_assert_map_eq!(hash_map(&[("foo", 1)]), hash_map(&[("foo", 2)]));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected maps to be equal\n    differing values: `{\"foo\": (actual: 1, expected: 2)}`: foo"
+    )]
+    fn debug_differing_values_custom_message() {
+        debug_assert_map_eq!(hash_map(&[("foo", 1)]), hash_map(&[("foo", 2)]), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_differing_values() {
+        debug_assert_map_eq!(hash_map(&[("foo", 1)]), hash_map(&[("foo", 2)]));
+    }
+}
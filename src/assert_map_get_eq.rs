@@ -0,0 +1,230 @@
+/// Asserts that a map contains the given key and that its value equals the expected value,
+/// returning a reference to the value.
+///
+/// Works with both [`HashMap`] and [`BTreeMap`]. The map is only borrowed, and the key is passed by
+/// reference, just as it is to [`HashMap::get`]. Compared to
+/// `assert_some_eq!(map.get(key), &expected)`, the failure message includes the key, and when the
+/// key is missing, the keys that the map does contain. Only the first 16 keys are shown, followed
+/// by a count of the keys that were left out.
+///
+/// This macro requires the `std` feature.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_map_get_eq!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # use std::collections::HashMap;
+/// # fn main() {
+/// let mut headers = HashMap::new();
+/// headers.insert("content-type".to_string(), "text/html");
+///
+/// assert_map_get_eq!(headers, "content-type", "text/html");
+///
+/// // With a custom message
+/// assert_map_get_eq!(headers, "content-type", "text/html", "Expecting an HTML page");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # use std::collections::HashMap;
+/// # fn main() {
+/// let mut headers = HashMap::new();
+/// headers.insert("content-type".to_string(), "text/plain");
+///
+/// assert_map_get_eq!(headers, "content-type", "text/html");  // Will panic
+/// # }
+/// ```
+///
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+/// [`HashMap::get`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html#method.get
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_map_get_eq!`]: crate::debug_assert_map_get_eq!
+#[macro_export]
+macro_rules! assert_map_get_eq {
+    ($map:expr, $key:expr, $expected:expr $(,)?) => {
+        match (&$map, $key, &$expected) {
+            (map_val, key_val, expected_val) => match $crate::__private::Map::lookup(map_val, key_val) {
+                ::core::result::Result::Ok(value) => {
+                    if !(*value == *expected_val) {
+                        ::core::panic!(r#"assertion failed, expected map value to equal expected value
+    key: `{:?}`,
+    value: `{:?}`,
+    expected: `{:?}`"#, key_val, value, expected_val)
+                    }
+                    value
+                }
+                ::core::result::Result::Err(keys) => {
+                    ::core::panic!(r#"assertion failed, expected map to contain key
+    key: `{:?}`,
+    keys: `{:?}`"#, key_val, keys)
+                }
+            },
+        }
+    };
+    ($map:expr, $key:expr, $expected:expr, $($arg:tt)+) => {
+        match (&$map, $key, &$expected) {
+            (map_val, key_val, expected_val) => match $crate::__private::Map::lookup(map_val, key_val) {
+                ::core::result::Result::Ok(value) => {
+                    if !(*value == *expected_val) {
+                        ::core::panic!(r#"assertion failed, expected map value to equal expected value
+    key: `{:?}`,
+    value: `{:?}`,
+    expected: `{:?}`: {}"#, key_val, value, expected_val, ::core::format_args!($($arg)+))
+                    }
+                    value
+                }
+                ::core::result::Result::Err(keys) => {
+                    ::core::panic!(r#"assertion failed, expected map to contain key
+    key: `{:?}`,
+    keys: `{:?}`: {}"#, key_val, keys, ::core::format_args!($($arg)+))
+                }
+            },
+        }
+    };
+}
+
+/// Asserts that a map contains the given key and that its value equals the expected value on
+/// debug builds.
+///
+/// This macro behaves nearly the same as [`assert_map_get_eq!`] on debug builds, although it does
+/// not return the value. On release builds it is a no-op.
+///
+/// This macro requires the `std` feature.
+#[macro_export]
+macro_rules! debug_assert_map_get_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_map_get_eq!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{BTreeMap, HashMap},
+        string::{String, ToString},
+    };
+
+    fn hash_map() -> HashMap<String, u32> {
+        let mut map = HashMap::new();
+        map.insert("foo".to_string(), 1);
+        map
+    }
+
+    fn btree_map() -> BTreeMap<u32, &'static str> {
+        (0..20).map(|key| (key, "value")).collect()
+    }
+
+    #[test]
+    fn hash_map_equal() {
+        assert_map_get_eq!(hash_map(), "foo", 1);
+    }
+
+    #[test]
+    fn btree_map_equal() {
+        assert_map_get_eq!(btree_map(), &5, "value");
+    }
+
+    #[test]
+    fn returns_value() {
+        let map = hash_map();
+        assert_eq!(assert_map_get_eq!(map, "foo", 1), &1);
+    }
+
+    #[test]
+    fn returns_value_custom_message() {
+        let map = hash_map();
+        assert_eq!(assert_map_get_eq!(map, "foo", 1, "foo"), &1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected map value to equal expected value\n    key: `\"foo\"`,\n    value: `1`,\n    expected: `2`"
+    )]
+    fn not_equal() {
+        assert_map_get_eq!(hash_map(), "foo", 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected map to contain key\n    key: `\"bar\"`,\n    keys: `[\"foo\"]`"
+    )]
+    fn missing_key() {
+        assert_map_get_eq!(hash_map(), "bar", 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected map to contain key\n    key: `20`,\n    keys: `[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, ... (4 more)]`"
+    )]
+    fn missing_key_truncated() {
+        assert_map_get_eq!(btree_map(), &20, "value");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected map value to equal expected value\n    key: `\"foo\"`,\n    value: `1`,\n    expected: `2`: foo"
+    )]
+    fn not_equal_custom_message() {
+        assert_map_get_eq!(hash_map(), "foo", 2, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected map to contain key\n    key: `\"bar\"`,\n    keys: `[\"foo\"]`: foo"
+    )]
+    fn missing_key_custom_message() {
+        assert_map_get_eq!(hash_map(), "bar", 1, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_equal() {
+        debug_assert_map_get_eq!(hash_map(), "foo", 1);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected map value to equal expected value\n    key: `\"foo\"`,\n    value: `1`,\n    expected: `2`"
+    )]
+    fn debug_not_equal() {
+        debug_assert_map_get_eq!(hash_map(), "foo", 2);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected map value to equal expected value\n    key: `\"foo\"`,\n    value: `1`,\n    expected: `2`: foo"
+    )]
+    fn debug_not_equal_custom_message() {
+        debug_assert_map_get_eq!(hash_map(), "foo", 2, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_equal() {
+        debug_assert_map_get_eq!(hash_map(), "foo", 2);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_map_get_eq!(hash_map(), "foo", 1);
+    }
+}
@@ -69,10 +69,13 @@ macro_rules! assert_matches {
 /// no-op.
 #[macro_export]
 macro_rules! debug_assert_matches {
-    ($($arg:tt)*) => {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
         #[cfg(debug_assertions)]
-        $crate::assert_matches!($($arg)*);
-    }
+        {
+            $crate::assert_matches!($($arg)*);
+        }
+    }};
 }
 
 #[cfg(test)]
@@ -235,4 +238,15 @@ mod tests {
     fn debug_release_not_matches_nested_pattern() {
         debug_assert_matches!(None, Some(Foo::Bar(_) | Foo::Baz(1 | 2)));
     }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_matches!(Some(42), Some(_));
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_returns_unit() {
+        let () = debug_assert_matches!(None::<usize>, Some(_));
+    }
 }
@@ -0,0 +1,252 @@
+use core::borrow::Borrow;
+
+/// Asserts that the maximum of the values produced by an iterator is equal to the expected
+/// value, returning the maximum.
+///
+/// The first expression can be anything implementing [`IntoIterator`] whose items implement
+/// [`Ord`]. The maximum is computed with [`Iterator::max()`], without collecting or sorting the
+/// values. When the iterator yields references, the expected value can be given either by value
+/// or by reference. An empty iterator always panics, with a message distinct from a mismatch.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_max_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let samples = [3, 97, 42];
+/// assert_max_eq!(samples.iter(), 97);
+///
+/// // With a custom message
+/// assert_max_eq!(samples.iter(), 97, "Expecting the maximum of {:?} to be {}", samples, 97);
+/// # }
+/// ```
+///
+/// The maximum will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let samples = [3, 97, 42];
+/// let max = assert_max_eq!(samples.iter(), 97);
+/// assert_eq!(max, &97);
+/// # }
+/// ```
+///
+/// A different maximum or an empty iterator will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let samples = [3, 97, 42];
+/// assert_max_eq!(samples.iter(), 100);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`Ord`]: https://doc.rust-lang.org/core/cmp/trait.Ord.html
+/// [`Iterator::max()`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#method.max
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_max_eq!`]: crate::debug_assert_max_eq!
+#[macro_export]
+macro_rules! assert_max_eq {
+    ($iter:expr, $expected:expr $(,)?) => {
+        match (::core::iter::Iterator::max(::core::iter::IntoIterator::into_iter($iter)), &$expected) {
+            (::core::option::Option::Some(max), expected) => {
+                if !$crate::__private::BorrowEq::borrow_eq(&max, expected) {
+                    ::core::panic!(r#"assertion failed: `(max(iter) == expected)`
+    max: `{:?}`,
+    expected: `{:?}`"#, &max, &*expected);
+                }
+                max
+            }
+            (::core::option::Option::None, _) => {
+                ::core::panic!("assertion failed, expected maximum of non-empty iterator, got empty iterator");
+            }
+        }
+    };
+    ($iter:expr, $expected:expr, $($arg:tt)+) => {
+        match (::core::iter::Iterator::max(::core::iter::IntoIterator::into_iter($iter)), &$expected) {
+            (::core::option::Option::Some(max), expected) => {
+                if !$crate::__private::BorrowEq::borrow_eq(&max, expected) {
+                    ::core::panic!(r#"assertion failed: `(max(iter) == expected)`
+    max: `{:?}`,
+    expected: `{:?}`: {}"#, &max, &*expected, ::core::format_args!($($arg)+));
+                }
+                max
+            }
+            (::core::option::Option::None, _) => {
+                ::core::panic!("assertion failed, expected maximum of non-empty iterator, got empty iterator: {}", ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that the maximum of the values produced by an iterator is equal to the expected
+/// value on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_max_eq!`] on debug builds, although it does not
+/// return the maximum. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_max_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_max_eq!($($arg)*);
+        }
+    }};
+}
+
+/// Values which can be compared to an expected value through [`Borrow`].
+///
+/// This allows comparing items yielded by reference, such as those of [`slice::iter()`], against
+/// an expected value given by value.
+///
+/// This is an implementation detail of [`assert_max_eq!`], [`assert_min_eq!`],
+/// [`assert_first_eq!`], [`assert_last_eq!`], and [`assert_next_eq!`] and is not part of the
+/// public API.
+///
+/// [`Borrow`]: core::borrow::Borrow
+/// [`slice::iter()`]: https://doc.rust-lang.org/core/primitive.slice.html#method.iter
+#[doc(hidden)]
+pub trait BorrowEq<E>
+where
+    E: ?Sized,
+{
+    fn borrow_eq(&self, expected: &E) -> bool;
+}
+
+impl<T, E> BorrowEq<E> for T
+where
+    T: Borrow<E>,
+    E: PartialEq + ?Sized,
+{
+    fn borrow_eq(&self, expected: &E) -> bool {
+        self.borrow() == expected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn equal() {
+        assert_max_eq!([3, 97, 42].iter(), 97);
+    }
+
+    #[test]
+    fn equal_by_value() {
+        assert_max_eq!([3, 97, 42].iter().copied(), 97);
+    }
+
+    #[test]
+    fn equal_by_reference() {
+        assert_max_eq!([3, 97, 42].iter(), &97);
+    }
+
+    #[test]
+    fn single() {
+        assert_max_eq!(&[97], 97);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(max(iter) == expected)`\n    max: `97`,\n    expected: `100`"
+    )]
+    fn not_equal() {
+        assert_max_eq!([3, 97, 42].iter(), 100);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(max(iter) == expected)`\n    max: `97`,\n    expected: `100`: foo"
+    )]
+    fn not_equal_custom_message() {
+        assert_max_eq!([3, 97, 42].iter(), 100, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected maximum of non-empty iterator, got empty iterator"
+    )]
+    fn empty() {
+        let samples: [u32; 0] = [];
+        assert_max_eq!(samples.iter(), 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected maximum of non-empty iterator, got empty iterator: foo"
+    )]
+    fn empty_custom_message() {
+        let samples: [u32; 0] = [];
+        assert_max_eq!(samples.iter(), 0, "foo");
+    }
+
+    #[test]
+    fn max_returned() {
+        let max = assert_max_eq!([3, 97, 42].iter().copied(), 97);
+        assert_eq!(max, 97);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_equal() {
+        debug_assert_max_eq!([3, 97, 42].iter(), 97);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(max(iter) == expected)`\n    max: `97`,\n    expected: `100`"
+    )]
+    fn debug_not_equal() {
+        debug_assert_max_eq!([3, 97, 42].iter(), 100);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(max(iter) == expected)`\n    max: `97`,\n    expected: `100`: foo"
+    )]
+    fn debug_not_equal_custom_message() {
+        debug_assert_max_eq!([3, 97, 42].iter(), 100, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected maximum of non-empty iterator, got empty iterator"
+    )]
+    fn debug_empty() {
+        let samples: [u32; 0] = [];
+        debug_assert_max_eq!(samples.iter(), 0);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_equal() {
+        debug_assert_max_eq!([3, 97, 42].iter(), 100);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_empty() {
+        let samples: [u32; 0] = [];
+        debug_assert_max_eq!(samples.iter(), 0);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_max_eq!([3, 97, 42].iter(), 97);
+    }
+}
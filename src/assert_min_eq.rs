@@ -0,0 +1,221 @@
+/// Asserts that the minimum of the values produced by an iterator is equal to the expected
+/// value, returning the minimum.
+///
+/// The first expression can be anything implementing [`IntoIterator`] whose items implement
+/// [`Ord`]. The minimum is computed with [`Iterator::min()`], without collecting or sorting the
+/// values. When the iterator yields references, the expected value can be given either by value
+/// or by reference. An empty iterator always panics, with a message distinct from a mismatch.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_min_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let samples = [3, 97, 42];
+/// assert_min_eq!(samples.iter(), 3);
+///
+/// // With a custom message
+/// assert_min_eq!(samples.iter(), 3, "Expecting the minimum of {:?} to be {}", samples, 3);
+/// # }
+/// ```
+///
+/// The minimum will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let samples = [3, 97, 42];
+/// let min = assert_min_eq!(samples.iter(), 3);
+/// assert_eq!(min, &3);
+/// # }
+/// ```
+///
+/// A different minimum or an empty iterator will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let samples = [3, 97, 42];
+/// assert_min_eq!(samples.iter(), 1);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`Ord`]: https://doc.rust-lang.org/core/cmp/trait.Ord.html
+/// [`Iterator::min()`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#method.min
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_min_eq!`]: crate::debug_assert_min_eq!
+#[macro_export]
+macro_rules! assert_min_eq {
+    ($iter:expr, $expected:expr $(,)?) => {
+        match (::core::iter::Iterator::min(::core::iter::IntoIterator::into_iter($iter)), &$expected) {
+            (::core::option::Option::Some(min), expected) => {
+                if !$crate::__private::BorrowEq::borrow_eq(&min, expected) {
+                    ::core::panic!(r#"assertion failed: `(min(iter) == expected)`
+    min: `{:?}`,
+    expected: `{:?}`"#, &min, &*expected);
+                }
+                min
+            }
+            (::core::option::Option::None, _) => {
+                ::core::panic!("assertion failed, expected minimum of non-empty iterator, got empty iterator");
+            }
+        }
+    };
+    ($iter:expr, $expected:expr, $($arg:tt)+) => {
+        match (::core::iter::Iterator::min(::core::iter::IntoIterator::into_iter($iter)), &$expected) {
+            (::core::option::Option::Some(min), expected) => {
+                if !$crate::__private::BorrowEq::borrow_eq(&min, expected) {
+                    ::core::panic!(r#"assertion failed: `(min(iter) == expected)`
+    min: `{:?}`,
+    expected: `{:?}`: {}"#, &min, &*expected, ::core::format_args!($($arg)+));
+                }
+                min
+            }
+            (::core::option::Option::None, _) => {
+                ::core::panic!("assertion failed, expected minimum of non-empty iterator, got empty iterator: {}", ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that the minimum of the values produced by an iterator is equal to the expected
+/// value on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_min_eq!`] on debug builds, although it does not
+/// return the minimum. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_min_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_min_eq!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn equal() {
+        assert_min_eq!([3, 97, 42].iter(), 3);
+    }
+
+    #[test]
+    fn equal_by_value() {
+        assert_min_eq!([3, 97, 42].iter().copied(), 3);
+    }
+
+    #[test]
+    fn equal_by_reference() {
+        assert_min_eq!([3, 97, 42].iter(), &3);
+    }
+
+    #[test]
+    fn single() {
+        assert_min_eq!(&[3], 3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(min(iter) == expected)`\n    min: `3`,\n    expected: `1`"
+    )]
+    fn not_equal() {
+        assert_min_eq!([3, 97, 42].iter(), 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(min(iter) == expected)`\n    min: `3`,\n    expected: `1`: foo"
+    )]
+    fn not_equal_custom_message() {
+        assert_min_eq!([3, 97, 42].iter(), 1, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected minimum of non-empty iterator, got empty iterator"
+    )]
+    fn empty() {
+        let samples: [u32; 0] = [];
+        assert_min_eq!(samples.iter(), 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected minimum of non-empty iterator, got empty iterator: foo"
+    )]
+    fn empty_custom_message() {
+        let samples: [u32; 0] = [];
+        assert_min_eq!(samples.iter(), 0, "foo");
+    }
+
+    #[test]
+    fn min_returned() {
+        let min = assert_min_eq!([3, 97, 42].iter().copied(), 3);
+        assert_eq!(min, 3);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_equal() {
+        debug_assert_min_eq!([3, 97, 42].iter(), 3);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(min(iter) == expected)`\n    min: `3`,\n    expected: `1`"
+    )]
+    fn debug_not_equal() {
+        debug_assert_min_eq!([3, 97, 42].iter(), 1);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(min(iter) == expected)`\n    min: `3`,\n    expected: `1`: foo"
+    )]
+    fn debug_not_equal_custom_message() {
+        debug_assert_min_eq!([3, 97, 42].iter(), 1, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected minimum of non-empty iterator, got empty iterator"
+    )]
+    fn debug_empty() {
+        let samples: [u32; 0] = [];
+        debug_assert_min_eq!(samples.iter(), 0);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_equal() {
+        debug_assert_min_eq!([3, 97, 42].iter(), 1);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_empty() {
+        let samples: [u32; 0] = [];
+        debug_assert_min_eq!(samples.iter(), 0);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_min_eq!([3, 97, 42].iter(), 3);
+    }
+}
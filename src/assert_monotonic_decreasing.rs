@@ -0,0 +1,113 @@
+/// Asserts that the elements of a collection or iterator are non-increasing.
+///
+/// Works with anything that can be iterated over by reference, such as arrays, slices, and `Vec`s,
+/// as well as with cloneable iterators. The collection is only borrowed. Adjacent elements are
+/// compared using [`PartialOrd`], and each element must be greater than or equal to the next one.
+/// Collections with zero or one elements always pass. Elements that cannot be compared, such as
+/// `NaN`, are reported as a violation. This is [`assert_sorted_by!`] with `>=` as the comparison
+/// function. See [`assert_strictly_decreasing!`] to also reject equal adjacent elements.
+///
+/// On failure, the index of the first element that is less than its successor is printed, along
+/// with both elements, pointing at where the sequence stopped being non-increasing.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled. See
+/// [`debug_assert_monotonic_decreasing!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let remaining = vec![10, 7, 7, 3];
+/// assert_monotonic_decreasing!(remaining);
+///
+/// assert_monotonic_decreasing!(remaining.iter().map(|r| r * 2));
+///
+/// // With a custom message
+/// assert_monotonic_decreasing!(remaining, "Expecting {:?} to be non-increasing", remaining);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_monotonic_decreasing!([10, 7, 8]);  // Will panic
+/// # }
+/// ```
+///
+/// [`PartialOrd`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html
+/// [`assert_strictly_decreasing!`]: crate::assert_strictly_decreasing!
+/// [`assert_sorted_by!`]: crate::assert_sorted_by!
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_monotonic_decreasing!`]: crate::debug_assert_monotonic_decreasing!
+#[macro_export]
+macro_rules! assert_monotonic_decreasing {
+    ($collection:expr $(,)?) => {
+        $crate::assert_sorted_by!($collection, |left, right| left >= right)
+    };
+    ($collection:expr, $($arg:tt)+) => {
+        $crate::assert_sorted_by!($collection, |left, right| left >= right, $($arg)+)
+    };
+}
+
+/// Asserts that the elements of a collection or iterator are non-increasing on debug builds.
+///
+/// This macro behaves the same as [`assert_monotonic_decreasing!`] on debug builds. On release
+/// builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_monotonic_decreasing {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_monotonic_decreasing!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn monotonic_decreasing() {
+        assert_monotonic_decreasing!([3, 2, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `compare(collection[index], collection[index + 1])`\n    index: `2`,\n    collection[index]: `1`,\n    collection[index + 1]: `2`"
+    )]
+    fn violation() {
+        assert_monotonic_decreasing!([3, 2, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `compare(collection[index], collection[index + 1])`\n    index: `2`,\n    collection[index]: `1`,\n    collection[index + 1]: `2`: foo"
+    )]
+    fn violation_custom_message() {
+        assert_monotonic_decreasing!([3, 2, 1, 2], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `compare(collection[index], collection[index + 1])`\n    index: `2`,\n    collection[index]: `1`,\n    collection[index + 1]: `2`"
+    )]
+    fn debug_violation() {
+        debug_assert_monotonic_decreasing!([3, 2, 1, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_violation() {
+        debug_assert_monotonic_decreasing!([3, 2, 1, 2]);
+    }
+}
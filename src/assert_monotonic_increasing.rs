@@ -0,0 +1,111 @@
+/// Asserts that the elements of a collection or iterator are non-decreasing.
+///
+/// Works with anything that can be iterated over by reference, such as arrays, slices, and `Vec`s,
+/// as well as with cloneable iterators. The collection is only borrowed. Adjacent elements are
+/// compared using [`PartialOrd`], and each element must be less than or equal to the next one.
+/// Collections with zero or one elements always pass. Elements that cannot be compared, such as
+/// `NaN`, are reported as a violation. This is an alias of [`assert_sorted!`], named for sequences
+/// such as timestamps, sequence numbers, and cumulative counters. See
+/// [`assert_strictly_increasing!`] to also reject equal adjacent elements.
+///
+/// On failure, the index of the first element that is greater than its successor is printed, along
+/// with both elements, pointing at where the sequence stopped being non-decreasing.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled. See
+/// [`debug_assert_monotonic_increasing!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let timestamps = vec![100, 105, 105, 120];
+/// assert_monotonic_increasing!(timestamps);
+///
+/// assert_monotonic_increasing!(timestamps.iter().map(|t| t - 100));
+///
+/// // With a custom message
+/// assert_monotonic_increasing!(timestamps, "Expecting {:?} to be non-decreasing", timestamps);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_monotonic_increasing!([100, 120, 110]);  // Will panic
+/// # }
+/// ```
+///
+/// [`PartialOrd`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html
+/// [`assert_sorted!`]: crate::assert_sorted!
+/// [`assert_strictly_increasing!`]: crate::assert_strictly_increasing!
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_monotonic_increasing!`]: crate::debug_assert_monotonic_increasing!
+#[macro_export]
+macro_rules! assert_monotonic_increasing {
+    ($($arg:tt)*) => {
+        $crate::assert_sorted!($($arg)*)
+    };
+}
+
+/// Asserts that the elements of a collection or iterator are non-decreasing on debug builds.
+///
+/// This macro behaves the same as [`assert_monotonic_increasing!`] on debug builds. On release
+/// builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_monotonic_increasing {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_monotonic_increasing!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn monotonic_increasing() {
+        assert_monotonic_increasing!([1, 2, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] <= collection[index + 1])`\n    index: `2`,\n    collection[index]: `3`,\n    collection[index + 1]: `2`"
+    )]
+    fn violation() {
+        assert_monotonic_increasing!([1, 2, 3, 2]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] <= collection[index + 1])`\n    index: `2`,\n    collection[index]: `3`,\n    collection[index + 1]: `2`: foo"
+    )]
+    fn violation_custom_message() {
+        assert_monotonic_increasing!([1, 2, 3, 2], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] <= collection[index + 1])`\n    index: `2`,\n    collection[index]: `3`,\n    collection[index + 1]: `2`"
+    )]
+    fn debug_violation() {
+        debug_assert_monotonic_increasing!([1, 2, 3, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_violation() {
+        debug_assert_monotonic_increasing!([1, 2, 3, 2]);
+    }
+}
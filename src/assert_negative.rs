@@ -0,0 +1,182 @@
+/// Asserts that the expression is negative, returning the value.
+///
+/// A value is negative if it is strictly less than zero, so zero itself (including `-0.0`) will
+/// cause a panic.
+///
+/// Works with all primitive integer and floating point types, as well as [`Duration`].
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_negative!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_negative!(-42i64);
+/// assert_negative!(-0.5f32);
+///
+/// // With a custom message
+/// assert_negative!(-42, "Expecting {} to be negative", -42);
+/// # }
+/// ```
+///
+/// The value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let value = assert_negative!(-42);
+/// assert_eq!(value, -42);
+/// # }
+/// ```
+///
+/// Zero and positive values will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_negative!(0);  // Will panic
+/// # }
+/// ```
+///
+/// [`Duration`]: https://doc.rust-lang.org/core/time/struct.Duration.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_negative!`]: crate::debug_assert_negative!
+#[macro_export]
+macro_rules! assert_negative {
+    ($value:expr $(,)?) => {
+        match $value {
+            value => {
+                if !::core::cmp::PartialOrd::lt(&value, &$crate::__private::Zero::zero(&value)) {
+                    ::core::panic!("assertion failed, expected negative value, got {:?}", value);
+                }
+                value
+            }
+        }
+    };
+    ($value:expr, $($arg:tt)+) => {
+        match $value {
+            value => {
+                if !::core::cmp::PartialOrd::lt(&value, &$crate::__private::Zero::zero(&value)) {
+                    ::core::panic!("assertion failed, expected negative value, got {:?}: {}", value, ::core::format_args!($($arg)+));
+                }
+                value
+            }
+        }
+    };
+}
+
+/// Asserts that the expression is negative on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_negative!`] on debug builds, although it does
+/// not return the value. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_negative {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_negative!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn negative() {
+        assert_negative!(-42);
+    }
+
+    #[test]
+    fn negative_float() {
+        assert_negative!(-core::f64::MIN_POSITIVE);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected negative value, got 0")]
+    fn zero() {
+        assert_negative!(0i32);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected negative value, got -0.0")]
+    fn negative_zero_float() {
+        assert_negative!(-0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected negative value, got 1")]
+    fn positive() {
+        assert_negative!(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected negative value, got NaN")]
+    fn nan() {
+        assert_negative!(core::f32::NAN);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected negative value, got 1: foo")]
+    fn positive_custom_message() {
+        assert_negative!(1, "foo");
+    }
+
+    #[test]
+    fn negative_value_returned() {
+        let value = assert_negative!(-42);
+        assert_eq!(value, -42);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_negative() {
+        debug_assert_negative!(-42);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, expected negative value, got 0")]
+    fn debug_zero() {
+        debug_assert_negative!(0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, expected negative value, got 1: foo")]
+    fn debug_positive_custom_message() {
+        debug_assert_negative!(1, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_zero() {
+        debug_assert_negative!(0);
+    }
+
+    #[test]
+    fn debug_negative_returns_unit() {
+        let () = debug_assert_negative!(-1);
+    }
+
+    #[test]
+    fn debug_negative_returns_unit_custom_message() {
+        let () = debug_assert_negative!(-1, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_negative_returns_unit() {
+        let () = debug_assert_negative!(1);
+    }
+}
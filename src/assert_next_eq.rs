@@ -0,0 +1,238 @@
+/// Asserts that the next element produced by an iterator is equal to the expected value,
+/// returning the element.
+///
+/// This is intended for driving an iterator by hand, asserting on each element in turn. The
+/// iterator expression is advanced by calling [`Iterator::next()`] on a mutable reference to it,
+/// so the same iterator can be passed to repeated calls, either by name or as `&mut iter`. The
+/// element is compared to the expected value using [`PartialEq`], and when it is yielded by
+/// reference, the expected value can be given either by value or by reference.
+///
+/// On failure, the panic message includes the iterator expression as written in the source, which
+/// helps tell apart the steps of a longer test. A mismatched element is printed along with the
+/// expected value, both of which must implement [`Debug`]. An exhausted iterator panics with a
+/// distinct message.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_next_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let mut words = "the quick brown fox".split(' ');
+///
+/// assert_next_eq!(words, "the");
+/// assert_next_eq!(&mut words, "quick");
+///
+/// // With a custom message
+/// assert_next_eq!(words, "brown", "Expecting the third word to be {:?}", "brown");
+/// # }
+/// ```
+///
+/// The element will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let mut numbers = vec![1, 2, 3].into_iter();
+///
+/// let first = assert_next_eq!(numbers, 1);
+/// assert_eq!(first, 1);
+/// # }
+/// ```
+///
+/// A different element or an exhausted iterator will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let mut numbers = vec![1, 2, 3].into_iter();
+///
+/// assert_next_eq!(numbers, 1);
+/// assert_next_eq!(numbers, 3);  // Will panic
+/// # }
+/// ```
+///
+/// [`Iterator::next()`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#tymethod.next
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_next_eq!`]: crate::debug_assert_next_eq!
+#[macro_export]
+macro_rules! assert_next_eq {
+    ($iter:expr, $expected:expr $(,)?) => {
+        match (::core::iter::Iterator::next(&mut $iter), &$expected) {
+            (::core::option::Option::Some(next), expected) => {
+                if !$crate::__private::BorrowEq::borrow_eq(&next, expected) {
+                    ::core::panic!(r#"assertion failed: `(next(iter) == expected)`
+    iterator: `{}`,
+    next: `{:?}`,
+    expected: `{:?}`"#, ::core::stringify!($iter), &next, &*expected);
+                }
+                next
+            }
+            (::core::option::Option::None, _) => {
+                ::core::panic!(r#"assertion failed, expected another element, but iterator was exhausted
+    iterator: `{}`"#, ::core::stringify!($iter));
+            }
+        }
+    };
+    ($iter:expr, $expected:expr, $($arg:tt)+) => {
+        match (::core::iter::Iterator::next(&mut $iter), &$expected) {
+            (::core::option::Option::Some(next), expected) => {
+                if !$crate::__private::BorrowEq::borrow_eq(&next, expected) {
+                    ::core::panic!(r#"assertion failed: `(next(iter) == expected)`
+    iterator: `{}`,
+    next: `{:?}`,
+    expected: `{:?}`: {}"#, ::core::stringify!($iter), &next, &*expected, ::core::format_args!($($arg)+));
+                }
+                next
+            }
+            (::core::option::Option::None, _) => {
+                ::core::panic!(r#"assertion failed, expected another element, but iterator was exhausted
+    iterator: `{}`: {}"#, ::core::stringify!($iter), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that the next element produced by an iterator is equal to the expected value on debug
+/// builds.
+///
+/// This macro behaves nearly the same as [`assert_next_eq!`] on debug builds, although it does not
+/// return the element. On release builds it is a no-op, and the iterator is not advanced.
+#[macro_export]
+macro_rules! debug_assert_next_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_next_eq!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{iter, vec};
+
+    #[test]
+    fn equal() {
+        let mut iter = vec![1, 2, 3].into_iter();
+        assert_next_eq!(iter, 1);
+        assert_next_eq!(iter, 2);
+        assert_next_eq!(iter, 3);
+    }
+
+    #[test]
+    fn equal_mutable_reference() {
+        let mut iter = vec![1, 2].into_iter();
+        assert_next_eq!(&mut iter, 1);
+        assert_next_eq!(&mut iter, 2);
+    }
+
+    #[test]
+    fn equal_by_reference() {
+        let values = [1, 2, 3];
+        let mut iter = values.iter();
+        assert_next_eq!(iter, 1);
+        assert_next_eq!(iter, &2);
+    }
+
+    #[test]
+    fn next_returned() {
+        let mut iter = vec![1, 2, 3].into_iter();
+        let next = assert_next_eq!(iter, 1);
+        assert_eq!(next, 1);
+        assert_eq!(iter.next(), Some(2));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(next(iter) == expected)`\n    iterator: `iter`,\n    next: `2`,\n    expected: `3`"
+    )]
+    fn not_equal() {
+        let mut iter = vec![1, 2, 3].into_iter();
+        assert_next_eq!(iter, 1);
+        assert_next_eq!(iter, 3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(next(iter) == expected)`\n    iterator: `iter`,\n    next: `2`,\n    expected: `3`: foo"
+    )]
+    fn not_equal_custom_message() {
+        let mut iter = vec![1, 2, 3].into_iter();
+        assert_next_eq!(iter, 1);
+        assert_next_eq!(iter, 3, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected another element, but iterator was exhausted\n    iterator: `&mut iter`"
+    )]
+    fn exhausted() {
+        let mut iter = iter::once(1);
+        assert_next_eq!(&mut iter, 1);
+        assert_next_eq!(&mut iter, 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected another element, but iterator was exhausted\n    iterator: `iter`: foo"
+    )]
+    fn exhausted_custom_message() {
+        let mut iter = iter::empty::<u32>();
+        assert_next_eq!(iter, 1, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_equal() {
+        let mut iter = vec![1, 2, 3].into_iter();
+        debug_assert_next_eq!(iter, 1);
+        assert_eq!(iter.next(), Some(2));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(next(iter) == expected)`\n    iterator: `iter`,\n    next: `1`,\n    expected: `3`"
+    )]
+    fn debug_not_equal() {
+        let mut iter = vec![1, 2, 3].into_iter();
+        debug_assert_next_eq!(iter, 3);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(next(iter) == expected)`\n    iterator: `iter`,\n    next: `1`,\n    expected: `3`: foo"
+    )]
+    fn debug_not_equal_custom_message() {
+        let mut iter = vec![1, 2, 3].into_iter();
+        debug_assert_next_eq!(iter, 3, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_equal() {
+        let mut iter = vec![1, 2, 3].into_iter();
+        debug_assert_next_eq!(iter, 3);
+        assert_eq!(iter.next(), Some(1));
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let mut iter = vec![1, 2, 3].into_iter();
+        let () = debug_assert_next_eq!(iter, 1);
+    }
+}
@@ -0,0 +1,174 @@
+/// Asserts that an iterator is exhausted.
+///
+/// This is intended for driving an iterator by hand, alongside [`assert_next_eq!`] and
+/// [`assert_next_some!`]. The iterator expression is advanced by calling [`Iterator::next()`] once
+/// on a mutable reference to it, so it can be passed either by name or as `&mut iter`.
+///
+/// On failure, the panic message includes the iterator expression as written in the source, along
+/// with the unexpected element. The element is printed with its [`Debug`] implementation if it
+/// has one, and as `<non-Debug>` otherwise.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_next_none!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let mut chars = "a".chars();
+///
+/// assert_next_eq!(&mut chars, 'a');
+/// assert_next_none!(&mut chars);
+///
+/// // With a custom message
+/// assert_next_none!(&mut chars, "Expecting a single character");
+/// # }
+/// ```
+///
+/// An iterator producing another element will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let mut chars = "ab".chars();
+///
+/// assert_next_eq!(&mut chars, 'a');
+/// assert_next_none!(&mut chars);  // Will panic
+/// # }
+/// ```
+///
+/// [`Iterator::next()`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#tymethod.next
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_next_eq!`]: crate::assert_next_eq!
+/// [`assert_next_some!`]: crate::assert_next_some!
+/// [`debug_assert_next_none!`]: crate::debug_assert_next_none!
+#[macro_export]
+macro_rules! assert_next_none {
+    ($iter:expr $(,)?) => {
+        match ::core::iter::Iterator::next(&mut $iter) {
+            ::core::option::Option::None => {}
+            ::core::option::Option::Some(next) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!(r#"assertion failed, expected iterator to be exhausted, got another element
+    iterator: `{}`,
+    next: `{:?}`"#, ::core::stringify!($iter), (&$crate::__private::MaybeDebug(&next)).maybe_debug());
+            }
+        }
+    };
+    ($iter:expr, $($arg:tt)+) => {
+        match ::core::iter::Iterator::next(&mut $iter) {
+            ::core::option::Option::None => {}
+            ::core::option::Option::Some(next) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!(r#"assertion failed, expected iterator to be exhausted, got another element
+    iterator: `{}`,
+    next: `{:?}`: {}"#, ::core::stringify!($iter), (&$crate::__private::MaybeDebug(&next)).maybe_debug(), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that an iterator is exhausted on debug builds.
+///
+/// This macro behaves the same as [`assert_next_none!`] on debug builds. On release builds it is a
+/// no-op, and the iterator is not advanced.
+#[macro_export]
+macro_rules! debug_assert_next_none {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_next_none!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{iter, vec};
+
+    #[test]
+    fn none() {
+        let mut iter = vec![1].into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_next_none!(&mut iter);
+        assert_next_none!(iter);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to be exhausted, got another element\n    iterator: `&mut iter`,\n    next: `2`"
+    )]
+    fn some() {
+        let mut iter = vec![1, 2].into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_next_none!(&mut iter);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to be exhausted, got another element\n    iterator: `&mut iter`,\n    next: `<non-Debug>`"
+    )]
+    fn some_non_debug() {
+        struct NonDebug;
+
+        let mut iter = iter::once(NonDebug);
+        assert_next_none!(&mut iter);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to be exhausted, got another element\n    iterator: `&mut iter`,\n    next: `1`: foo"
+    )]
+    fn some_custom_message() {
+        let mut iter = iter::once(1);
+        assert_next_none!(&mut iter, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_none() {
+        let mut iter = iter::empty::<u32>();
+        debug_assert_next_none!(&mut iter);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to be exhausted, got another element\n    iterator: `&mut iter`,\n    next: `1`"
+    )]
+    fn debug_some() {
+        let mut iter = iter::once(1);
+        debug_assert_next_none!(&mut iter);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected iterator to be exhausted, got another element\n    iterator: `&mut iter`,\n    next: `1`: foo"
+    )]
+    fn debug_some_custom_message() {
+        let mut iter = iter::once(1);
+        debug_assert_next_none!(&mut iter, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_some() {
+        let mut iter = iter::once(1);
+        debug_assert_next_none!(&mut iter);
+        assert_eq!(iter.next(), Some(1));
+    }
+}
@@ -0,0 +1,179 @@
+/// Asserts that an iterator produces another element, returning the element.
+///
+/// This is intended for driving an iterator by hand, alongside [`assert_next_eq!`] and
+/// [`assert_next_none!`]. The iterator expression is advanced by calling [`Iterator::next()`] on a
+/// mutable reference to it, so the same iterator can be passed to repeated calls, either by name
+/// or as `&mut iter`. Unlike [`assert_next_eq!`], the element is not required to implement
+/// [`PartialEq`] or [`Debug`].
+///
+/// On failure, the panic message includes the iterator expression as written in the source.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_next_some!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let mut chars = "ab".chars();
+///
+/// let first = assert_next_some!(&mut chars);
+/// assert_eq!(first, 'a');
+///
+/// // With a custom message
+/// assert_next_some!(&mut chars, "Expecting a second character");
+/// # }
+/// ```
+///
+/// An exhausted iterator will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let mut chars = "a".chars();
+///
+/// assert_next_some!(&mut chars);
+/// assert_next_some!(&mut chars);  // Will panic
+/// # }
+/// ```
+///
+/// [`Iterator::next()`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#tymethod.next
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_next_eq!`]: crate::assert_next_eq!
+/// [`assert_next_none!`]: crate::assert_next_none!
+/// [`debug_assert_next_some!`]: crate::debug_assert_next_some!
+#[macro_export]
+macro_rules! assert_next_some {
+    ($iter:expr $(,)?) => {
+        match ::core::iter::Iterator::next(&mut $iter) {
+            ::core::option::Option::Some(next) => next,
+            ::core::option::Option::None => {
+                ::core::panic!(r#"assertion failed, expected another element, but iterator was exhausted
+    iterator: `{}`"#, ::core::stringify!($iter));
+            }
+        }
+    };
+    ($iter:expr, $($arg:tt)+) => {
+        match ::core::iter::Iterator::next(&mut $iter) {
+            ::core::option::Option::Some(next) => next,
+            ::core::option::Option::None => {
+                ::core::panic!(r#"assertion failed, expected another element, but iterator was exhausted
+    iterator: `{}`: {}"#, ::core::stringify!($iter), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that an iterator produces another element on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_next_some!`] on debug builds, although it does
+/// not return the element. On release builds it is a no-op, and the iterator is not advanced.
+#[macro_export]
+macro_rules! debug_assert_next_some {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_next_some!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{iter, vec};
+
+    struct NonDebug(u32);
+
+    #[test]
+    fn some() {
+        let mut iter = vec![1, 2].into_iter();
+        assert_next_some!(&mut iter);
+        assert_next_some!(iter);
+    }
+
+    #[test]
+    fn some_non_debug() {
+        let mut iter = iter::once(NonDebug(1));
+        assert_next_some!(&mut iter);
+    }
+
+    #[test]
+    fn next_returned() {
+        let mut iter = iter::once(NonDebug(42));
+        let next = assert_next_some!(&mut iter);
+        assert_eq!(next.0, 42);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected another element, but iterator was exhausted\n    iterator: `&mut iter`"
+    )]
+    fn exhausted() {
+        let mut iter = iter::once(1);
+        assert_next_some!(&mut iter);
+        assert_next_some!(&mut iter);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected another element, but iterator was exhausted\n    iterator: `&mut iter`: foo"
+    )]
+    fn exhausted_custom_message() {
+        let mut iter = iter::empty::<u32>();
+        assert_next_some!(&mut iter, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_some() {
+        let mut iter = vec![1, 2].into_iter();
+        debug_assert_next_some!(&mut iter);
+        assert_eq!(iter.next(), Some(2));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected another element, but iterator was exhausted\n    iterator: `&mut iter`"
+    )]
+    fn debug_exhausted() {
+        let mut iter = iter::empty::<u32>();
+        debug_assert_next_some!(&mut iter);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected another element, but iterator was exhausted\n    iterator: `&mut iter`: foo"
+    )]
+    fn debug_exhausted_custom_message() {
+        let mut iter = iter::empty::<u32>();
+        debug_assert_next_some!(&mut iter, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_exhausted() {
+        let mut iter = iter::empty::<u32>();
+        debug_assert_next_some!(&mut iter);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let mut iter = vec![1, 2].into_iter();
+        let () = debug_assert_next_some!(&mut iter);
+    }
+}
@@ -0,0 +1,189 @@
+/// Asserts that the expression is non-negative, returning the value.
+///
+/// A value is non-negative if it is greater than or equal to zero. Unlike [`assert_positive!`],
+/// zero (including `-0.0`) will pass.
+///
+/// Works with all primitive integer and floating point types, as well as [`Duration`].
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_non_negative!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_non_negative!(42);
+/// assert_non_negative!(0);
+/// assert_non_negative!(0.0f32);
+///
+/// // With a custom message
+/// assert_non_negative!(0, "Expecting {} to be non-negative", 0);
+/// # }
+/// ```
+///
+/// The value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let value = assert_non_negative!(42);
+/// assert_eq!(value, 42);
+/// # }
+/// ```
+///
+/// Negative values will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_non_negative!(-1);  // Will panic
+/// # }
+/// ```
+///
+/// [`Duration`]: https://doc.rust-lang.org/core/time/struct.Duration.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_positive!`]: crate::assert_positive!
+/// [`debug_assert_non_negative!`]: crate::debug_assert_non_negative!
+#[macro_export]
+macro_rules! assert_non_negative {
+    ($value:expr $(,)?) => {
+        match $value {
+            value => {
+                if !::core::cmp::PartialOrd::ge(&value, &$crate::__private::Zero::zero(&value)) {
+                    ::core::panic!("assertion failed, expected non-negative value, got {:?}", value);
+                }
+                value
+            }
+        }
+    };
+    ($value:expr, $($arg:tt)+) => {
+        match $value {
+            value => {
+                if !::core::cmp::PartialOrd::ge(&value, &$crate::__private::Zero::zero(&value)) {
+                    ::core::panic!("assertion failed, expected non-negative value, got {:?}: {}", value, ::core::format_args!($($arg)+));
+                }
+                value
+            }
+        }
+    };
+}
+
+/// Asserts that the expression is non-negative on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_non_negative!`] on debug builds, although it
+/// does not return the value. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_non_negative {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_non_negative!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    #[test]
+    fn positive() {
+        assert_non_negative!(42);
+    }
+
+    #[test]
+    fn zero() {
+        assert_non_negative!(0u8);
+    }
+
+    #[test]
+    fn zero_float() {
+        assert_non_negative!(0.0);
+    }
+
+    #[test]
+    fn negative_zero_float() {
+        assert_non_negative!(-0.0);
+    }
+
+    #[test]
+    fn zero_duration() {
+        assert_non_negative!(Duration::from_secs(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected non-negative value, got -1")]
+    fn negative() {
+        assert_non_negative!(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected non-negative value, got NaN")]
+    fn nan() {
+        assert_non_negative!(core::f64::NAN);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected non-negative value, got -1: foo")]
+    fn negative_custom_message() {
+        assert_non_negative!(-1, "foo");
+    }
+
+    #[test]
+    fn non_negative_value_returned() {
+        let value = assert_non_negative!(0);
+        assert_eq!(value, 0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_zero() {
+        debug_assert_non_negative!(0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, expected non-negative value, got -1")]
+    fn debug_negative() {
+        debug_assert_non_negative!(-1);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, expected non-negative value, got -1: foo")]
+    fn debug_negative_custom_message() {
+        debug_assert_non_negative!(-1, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_negative() {
+        debug_assert_non_negative!(-1);
+    }
+
+    #[test]
+    fn debug_non_negative_returns_unit() {
+        let () = debug_assert_non_negative!(0);
+    }
+
+    #[test]
+    fn debug_non_negative_returns_unit_custom_message() {
+        let () = debug_assert_non_negative!(0, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_non_negative_returns_unit() {
+        let () = debug_assert_non_negative!(-1);
+    }
+}
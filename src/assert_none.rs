@@ -65,10 +65,13 @@ macro_rules! assert_none {
 /// no-op.
 #[macro_export]
 macro_rules! debug_assert_none {
-    ($($arg:tt)*) => {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
         #[cfg(debug_assertions)]
-        $crate::assert_none!($($arg)*);
-    }
+        {
+            $crate::assert_none!($($arg)*);
+        }
+    }};
 }
 
 #[cfg(test)]
@@ -115,4 +118,15 @@ mod tests {
     fn debug_release_not_none() {
         debug_assert_none!(Some(()));
     }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_none!(None::<()>);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_returns_unit() {
+        let () = debug_assert_none!(Some(()));
+    }
 }
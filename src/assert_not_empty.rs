@@ -0,0 +1,280 @@
+/// Asserts that a collection, string, or iterator is not empty, returning its first element.
+///
+/// Works with the same values as [`assert_empty!`]: anything that can be iterated over by
+/// reference, such as arrays, slices, `Vec`s, maps, and sets, cloneable iterators, and strings.
+/// The expression is only borrowed.
+///
+/// The first element is returned so that the test can continue with it. For collections this is a
+/// reference to the first element, for iterators it is the first item, and for strings it is the
+/// first `char`. Since there are no contents to print on failure, the panic message includes the
+/// stringified expression instead.
+///
+/// Because the collection is only borrowed, the returned reference points into it. If the
+/// expression creates a temporary, such as `assert_not_empty!(make_vec())`, the temporary is
+/// dropped at the end of the statement, so the returned reference cannot be kept. Bind the
+/// collection to a variable first when the first element is needed afterwards.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_not_empty!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let values = vec![1, 2, 3];
+/// assert_not_empty!(values);
+///
+/// assert_not_empty!("foo");
+///
+/// // With a custom message
+/// assert_not_empty!(values, "Expecting {:?} to not be empty", values);
+/// # }
+/// ```
+///
+/// The first element will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let values = vec![1, 2, 3];
+/// let first = assert_not_empty!(values.iter().filter(|value| **value > 1));
+/// assert_eq!(first, &2);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let values: Vec<u32> = Vec::new();
+/// assert_not_empty!(values);  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_empty!`]: crate::assert_empty!
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_not_empty!`]: crate::debug_assert_not_empty!
+#[macro_export]
+macro_rules! assert_not_empty {
+    ($collection:expr $(,)?) => {
+        match &$collection {
+            collection_val => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+                match (&&&&&$crate::__private::Collection(collection_val)).view().first() {
+                    ::core::result::Result::Ok(first) => first,
+                    ::core::result::Result::Err(kind) => {
+                        ::core::panic!(r#"assertion failed, expected non-empty {}
+    expression: `{}`"#, kind, ::core::stringify!($collection));
+                    }
+                }
+            }
+        }
+    };
+    ($collection:expr, $($arg:tt)+) => {
+        match &$collection {
+            collection_val => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+                match (&&&&&$crate::__private::Collection(collection_val)).view().first() {
+                    ::core::result::Result::Ok(first) => first,
+                    ::core::result::Result::Err(kind) => {
+                        ::core::panic!(r#"assertion failed, expected non-empty {}
+    expression: `{}`: {}"#, kind, ::core::stringify!($collection), ::core::format_args!($($arg)+));
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that a collection, string, or iterator is not empty on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_not_empty!`] on debug builds, although it does
+/// not return the first element. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_not_empty {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_not_empty!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{BTreeMap, HashSet},
+        iter,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+
+    #[test]
+    fn array() {
+        assert_not_empty!([1, 2, 3]);
+    }
+
+    #[test]
+    fn slice() {
+        let values: &[u32] = &[1, 2, 3];
+        assert_not_empty!(values);
+    }
+
+    #[test]
+    fn vec() {
+        let values = vec![1, 2, 3];
+        assert_not_empty!(values);
+        assert_not_empty!(&values);
+    }
+
+    #[test]
+    fn map() {
+        let map: BTreeMap<&str, u32> = [("foo", 1)].iter().copied().collect();
+        assert_not_empty!(map);
+    }
+
+    #[test]
+    fn hash_set() {
+        let set: HashSet<u32> = [1].iter().copied().collect();
+        assert_not_empty!(set);
+    }
+
+    #[test]
+    fn string() {
+        assert_not_empty!("foo");
+        assert_not_empty!("foo".to_string());
+    }
+
+    #[test]
+    fn iterator() {
+        assert_not_empty!(0..10);
+    }
+
+    #[test]
+    fn returns_first_element() {
+        let values = vec![1, 2, 3];
+        assert_eq!(assert_not_empty!(values), &1);
+    }
+
+    #[test]
+    fn returns_first_element_custom_message() {
+        let values = vec![1, 2, 3];
+        assert_eq!(assert_not_empty!(values, "foo"), &1);
+    }
+
+    #[test]
+    fn returns_first_map_entry() {
+        let map: BTreeMap<&str, u32> = [("foo", 1), ("bar", 2)].iter().copied().collect();
+        assert_eq!(assert_not_empty!(map), (&"bar", &2));
+    }
+
+    #[test]
+    fn returns_first_item() {
+        let values = [1, 2, 3];
+        assert_eq!(
+            assert_not_empty!(values.iter().filter(|value| **value > 1)),
+            &2
+        );
+        assert_eq!(assert_not_empty!(5..10), 5);
+    }
+
+    #[test]
+    fn returns_first_char() {
+        assert_eq!(assert_not_empty!("foo"), 'f');
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected non-empty collection\n    expression: `values`"
+    )]
+    fn vec_empty() {
+        let values: Vec<u32> = Vec::new();
+        assert_not_empty!(values);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected non-empty collection\n    expression: `values`"
+    )]
+    fn slice_empty() {
+        let values: &[u32] = &[];
+        assert_not_empty!(values);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected non-empty string\n    expression: `string`"
+    )]
+    fn string_empty() {
+        let string = String::new();
+        assert_not_empty!(string);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected non-empty iterator\n    expression: `empty`"
+    )]
+    fn iterator_empty() {
+        let empty = iter::empty::<u32>();
+        assert_not_empty!(empty);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected non-empty collection\n    expression: `values`: foo"
+    )]
+    fn empty_custom_message() {
+        let values: Vec<u32> = Vec::new();
+        assert_not_empty!(values, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_vec() {
+        debug_assert_not_empty!(vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected non-empty collection\n    expression: `values`"
+    )]
+    fn debug_vec_empty() {
+        let values: Vec<u32> = Vec::new();
+        debug_assert_not_empty!(values);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected non-empty collection\n    expression: `values`: foo"
+    )]
+    fn debug_vec_empty_custom_message() {
+        let values: Vec<u32> = Vec::new();
+        debug_assert_not_empty!(values, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_vec_empty() {
+        let values: Vec<u32> = Vec::new();
+        debug_assert_not_empty!(values);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_not_empty!(vec![1, 2, 3]);
+    }
+}
@@ -1,5 +1,9 @@
 /// Asserts that the expression matches an [`Ok(_)`] variant, returning the contained value.
 ///
+/// On failure, the panic message includes the asserted expression as written in the source, which
+/// tells apart multiple `assert_ok!` calls in the same test. The error is also printed with its
+/// [`Debug`] implementation if it has one, and as `<non-Debug>` otherwise.
+///
 /// ## Uses
 ///
 /// Assertions are always checked in both debug and release builds, and cannot be disabled.
@@ -48,6 +52,7 @@
 /// ```
 ///
 /// [`Ok(_)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Ok
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
 /// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
 /// [`debug_assert_ok!`]: crate::debug_assert_ok!
 #[macro_export]
@@ -56,7 +61,11 @@ macro_rules! assert_ok {
         match $cond {
             ::core::result::Result::Ok(t) => t,
             ::core::result::Result::Err(e) => {
-                ::core::panic!("assertion failed, expected Ok(_), got Err({:?})", e);
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!(r#"assertion failed, expected Ok(_), got Err(_)
+    expression: `{}`,
+    error: `{:?}`"#, ::core::stringify!($cond), (&$crate::__private::MaybeDebug(&e)).maybe_debug());
             }
         }
     };
@@ -64,7 +73,11 @@ macro_rules! assert_ok {
         match $cond {
             ::core::result::Result::Ok(t) => t,
             ::core::result::Result::Err(e) => {
-                ::core::panic!("assertion failed, expected Ok(_), got Err({:?}): {}", e, ::core::format_args!($($arg)+));
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!(r#"assertion failed, expected Ok(_), got Err(_)
+    expression: `{}`,
+    error: `{:?}`: {}"#, ::core::stringify!($cond), (&$crate::__private::MaybeDebug(&e)).maybe_debug(), ::core::format_args!($($arg)+));
             }
         }
     };
@@ -78,10 +91,13 @@ macro_rules! assert_ok {
 /// [`Ok(_)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Ok
 #[macro_export]
 macro_rules! debug_assert_ok {
-    ($($arg:tt)*) => {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
         #[cfg(debug_assertions)]
-        $crate::assert_ok!($($arg)*);
-    }
+        {
+            $crate::assert_ok!($($arg)*);
+        }
+    }};
 }
 
 #[cfg(test)]
@@ -92,15 +108,21 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "assertion failed, expected Ok(_), got Err(())")]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_), got Err(_)\n    expression: `res`,\n    error: `()`"
+    )]
     fn not_ok() {
-        assert_ok!(Err::<(), _>(()));
+        let res = Err::<(), _>(());
+        assert_ok!(res);
     }
 
     #[test]
-    #[should_panic(expected = "assertion failed, expected Ok(_), got Err(()): foo")]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_), got Err(_)\n    expression: `res`,\n    error: `()`: foo"
+    )]
     fn not_ok_custom_message() {
-        assert_ok!(Err::<(), _>(()), "foo");
+        let res = Err::<(), _>(());
+        assert_ok!(res, "foo");
     }
 
     #[test]
@@ -116,16 +138,22 @@ mod tests {
 
     #[test]
     #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
-    #[should_panic(expected = "assertion failed, expected Ok(_), got Err(())")]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_), got Err(_)\n    expression: `res`,\n    error: `()`"
+    )]
     fn debug_not_ok() {
-        debug_assert_ok!(Err::<(), _>(()));
+        let res = Err::<(), _>(());
+        debug_assert_ok!(res);
     }
 
     #[test]
     #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
-    #[should_panic(expected = "assertion failed, expected Ok(_), got Err(()): foo")]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_), got Err(_)\n    expression: `res`,\n    error: `()`: foo"
+    )]
     fn debug_not_ok_custom_message() {
-        debug_assert_ok!(Err::<(), _>(()), "foo");
+        let res = Err::<(), _>(());
+        debug_assert_ok!(res, "foo");
     }
 
     #[test]
@@ -134,6 +162,22 @@ mod tests {
         debug_assert_ok!(Err::<(), _>(()));
     }
 
+    #[test]
+    fn debug_ok_returns_unit() {
+        let () = debug_assert_ok!(Ok::<_, ()>(42));
+    }
+
+    #[test]
+    fn debug_ok_returns_unit_custom_message() {
+        let () = debug_assert_ok!(Ok::<_, ()>(42), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_ok_returns_unit() {
+        let () = debug_assert_ok!(Err::<(), _>(()));
+    }
+
     #[test]
     fn does_not_require_ok_to_impl_debug() {
         enum Foo {
@@ -171,4 +215,72 @@ mod tests {
 
         debug_assert_ok!(Ok::<_, ()>(Foo::Bar), "foo");
     }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_), got Err(_)\n    expression: `res`,\n    error: `<non-Debug>`"
+    )]
+    fn does_not_require_err_to_impl_debug() {
+        enum Foo {
+            Bar,
+        }
+
+        let res = Err::<i32, _>(Foo::Bar);
+        assert_ok!(res);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_), got Err(_)\n    expression: `res`,\n    error: `<non-Debug>`"
+    )]
+    fn debug_does_not_require_err_to_impl_debug() {
+        #[allow(dead_code)]
+        enum Foo {
+            Bar,
+        }
+
+        let res = Err::<i32, _>(Foo::Bar);
+        debug_assert_ok!(res);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_), got Err(_)\n    expression: `res`,\n    error: `<non-Debug>`: foo"
+    )]
+    fn does_not_require_err_to_impl_debug_custom_message() {
+        enum Foo {
+            Bar,
+        }
+
+        let res = Err::<i32, _>(Foo::Bar);
+        assert_ok!(res, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_), got Err(_)\n    expression: `res`,\n    error: `<non-Debug>`: foo"
+    )]
+    fn debug_does_not_require_err_to_impl_debug_custom_message() {
+        #[allow(dead_code)]
+        enum Foo {
+            Bar,
+        }
+
+        let res = Err::<i32, _>(Foo::Bar);
+        debug_assert_ok!(res, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_), got Err(_)\n    expression: `parse()`,\n    error: `()`"
+    )]
+    fn expression_in_message() {
+        fn parse() -> Result<u32, ()> {
+            Err(())
+        }
+
+        assert_ok!(parse());
+    }
 }
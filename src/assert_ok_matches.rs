@@ -0,0 +1,224 @@
+/// Asserts that the expression matches an [`Ok(_)`] variant whose contained value matches the
+/// provided pattern.
+///
+/// This combines [`assert_ok!`] and [`assert_matches!`] into a single call. As with
+/// [`assert_matches!`], the pattern can contain multiple alternatives separated by `|` and can be
+/// followed by an `if` guard, and the contained value must implement [`Debug`] so that it can be
+/// printed when it does not match.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ok_matches!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// #[derive(Debug)]
+/// enum Response {
+///     Created { id: u32 },
+///     Updated { id: u32 },
+///     Deleted,
+/// }
+///
+/// let res: Result<Response, ()> = Ok(Response::Created { id: 42 });
+///
+/// assert_ok_matches!(&res, Response::Created { .. });
+/// assert_ok_matches!(&res, Response::Created { id } | Response::Updated { id } if *id > 0);
+///
+/// // With a custom message
+/// assert_ok_matches!(res, Response::Created { .. }, "Expecting a new entry");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let res: Result<Option<i32>, ()> = Ok(None);
+///
+/// assert_ok_matches!(res, Some(_));  // Will panic
+/// # }
+/// ```
+///
+/// [`Ok(_)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Ok
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_ok!`]: crate::assert_ok!
+/// [`assert_matches!`]: crate::assert_matches!
+/// [`debug_assert_ok_matches!`]: crate::debug_assert_ok_matches!
+#[macro_export]
+macro_rules! assert_ok_matches {
+    ($cond:expr, $($pattern:pat)|+ $(if $guard:expr)? $(,)?) => {
+        match $cond {
+            ::core::result::Result::Ok(t) => {
+                #[allow(unreachable_patterns)]
+                match t {
+                    $($pattern)|+ $(if $guard)? => {},
+                    other => {
+                        ::core::panic!(r#"assertion failed, expected Ok(_) to match the given pattern
+    value: `{:?}`,
+    pattern: `{}`"#, other, ::core::stringify!($($pattern)|+ $(if $guard)?));
+                    }
+                }
+            }
+            ::core::result::Result::Err(_) => {
+                ::core::panic!(r#"assertion failed, expected Ok(_), got Err(_)
+    expression: `{}`"#, ::core::stringify!($cond));
+            }
+        }
+    };
+    ($cond:expr, $($pattern:pat)|+ $(if $guard:expr)?, $($arg:tt)+) => {
+        match $cond {
+            ::core::result::Result::Ok(t) => {
+                #[allow(unreachable_patterns)]
+                match t {
+                    $($pattern)|+ $(if $guard)? => {},
+                    other => {
+                        ::core::panic!(r#"assertion failed, expected Ok(_) to match the given pattern
+    value: `{:?}`,
+    pattern: `{}`: {}"#, other, ::core::stringify!($($pattern)|+ $(if $guard)?), ::core::format_args!($($arg)+));
+                    }
+                }
+            }
+            ::core::result::Result::Err(_) => {
+                ::core::panic!(r#"assertion failed, expected Ok(_), got Err(_)
+    expression: `{}`: {}"#, ::core::stringify!($cond), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that the expression matches an [`Ok(_)`] variant whose contained value matches the
+/// provided pattern on debug builds.
+///
+/// This macro behaves the same as [`assert_ok_matches!`] on debug builds. On release builds it is
+/// a no-op.
+///
+/// [`Ok(_)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Ok
+#[macro_export]
+macro_rules! debug_assert_ok_matches {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_ok_matches!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    enum Foo {
+        Bar(usize),
+        Baz(usize),
+    }
+
+    #[test]
+    fn matches() {
+        assert_ok_matches!(Ok::<_, ()>(Foo::Bar(42)), Foo::Bar(_));
+    }
+
+    #[test]
+    fn matches_multiple_variants() {
+        assert_ok_matches!(Ok::<_, ()>(Foo::Baz(42)), Foo::Bar(_) | Foo::Baz(_));
+    }
+
+    #[test]
+    fn matches_with_guard() {
+        assert_ok_matches!(Ok::<_, ()>(Foo::Bar(42)), Foo::Bar(x) if x > 40);
+    }
+
+    #[test]
+    fn matches_multiple_variants_with_guard() {
+        assert_ok_matches!(Ok::<_, ()>(Foo::Baz(42)), Foo::Bar(x) | Foo::Baz(x) if x > 40);
+    }
+
+    #[test]
+    fn matches_reference() {
+        let res: Result<Foo, ()> = Ok(Foo::Bar(42));
+        assert_ok_matches!(&res, Foo::Bar(_));
+        assert_ok_matches!(res, Foo::Bar(42));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_) to match the given pattern\n    value: `Baz(42)`,\n    pattern: `Foo::Bar(_)`"
+    )]
+    fn not_matches() {
+        assert_ok_matches!(Ok::<_, ()>(Foo::Baz(42)), Foo::Bar(_));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_) to match the given pattern\n    value: `Bar(42)`,\n    pattern: `Foo::Bar(x) if x > 100`"
+    )]
+    fn not_matches_guard() {
+        assert_ok_matches!(Ok::<_, ()>(Foo::Bar(42)), Foo::Bar(x) if x > 100);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_) to match the given pattern\n    value: `Baz(42)`,\n    pattern: `Foo::Bar(_)`: foo"
+    )]
+    fn not_matches_custom_message() {
+        assert_ok_matches!(Ok::<_, ()>(Foo::Baz(42)), Foo::Bar(_), "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_), got Err(_)\n    expression: `res`"
+    )]
+    fn not_ok() {
+        let res: Result<Foo, ()> = Err(());
+        assert_ok_matches!(res, Foo::Bar(_));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_), got Err(_)\n    expression: `res`: foo"
+    )]
+    fn not_ok_custom_message() {
+        let res: Result<Foo, ()> = Err(());
+        assert_ok_matches!(res, Foo::Bar(_), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_matches() {
+        debug_assert_ok_matches!(Ok::<_, ()>(Foo::Bar(42)), Foo::Bar(_));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_) to match the given pattern\n    value: `Baz(42)`,\n    pattern: `Foo::Bar(_)`"
+    )]
+    fn debug_not_matches() {
+        debug_assert_ok_matches!(Ok::<_, ()>(Foo::Baz(42)), Foo::Bar(_));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_) to match the given pattern\n    value: `Baz(42)`,\n    pattern: `Foo::Bar(_)`: foo"
+    )]
+    fn debug_not_matches_custom_message() {
+        debug_assert_ok_matches!(Ok::<_, ()>(Foo::Baz(42)), Foo::Bar(_), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_matches() {
+        debug_assert_ok_matches!(Ok::<_, ()>(Foo::Baz(42)), Foo::Bar(_));
+    }
+}
@@ -0,0 +1,216 @@
+/// Asserts that the expression matches an [`Ok(None)`] variant.
+///
+/// This is useful for `Result<Option<T>, E>` values, such as those produced by fallible lookups,
+/// to test that the operation succeeded but found nothing. An `Err(_)` and an `Ok(Some(_))` panic
+/// with distinct messages. On failure, the panic message includes the asserted expression as
+/// written in the source, along with the error or the unexpected value. These are printed with
+/// their [`Debug`] implementations if they have them, and as `<non-Debug>` otherwise.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ok_none!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let lookup: Result<Option<i32>, &str> = Ok(None);
+///
+/// assert_ok_none!(lookup);
+///
+/// // With a custom message
+/// assert_ok_none!(lookup, "Expecting the key to be absent");
+/// # }
+/// ```
+///
+/// An `Ok(Some(_))` variant will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let lookup: Result<Option<i32>, &str> = Ok(Some(1));
+///
+/// assert_ok_none!(lookup);  // Will panic
+/// # }
+/// ```
+///
+/// [`Ok(None)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Ok
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_ok_none!`]: crate::debug_assert_ok_none!
+#[macro_export]
+macro_rules! assert_ok_none {
+    ($cond:expr $(,)?) => {
+        match $cond {
+            ::core::result::Result::Ok(::core::option::Option::None) => {}
+            ::core::result::Result::Ok(::core::option::Option::Some(t)) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!(r#"assertion failed, expected Ok(None), got Ok(Some(_))
+    expression: `{}`,
+    value: `{:?}`"#, ::core::stringify!($cond), (&$crate::__private::MaybeDebug(&t)).maybe_debug());
+            }
+            ::core::result::Result::Err(e) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!(r#"assertion failed, expected Ok(None), got Err(_)
+    expression: `{}`,
+    error: `{:?}`"#, ::core::stringify!($cond), (&$crate::__private::MaybeDebug(&e)).maybe_debug());
+            }
+        }
+    };
+    ($cond:expr, $($arg:tt)+) => {
+        match $cond {
+            ::core::result::Result::Ok(::core::option::Option::None) => {}
+            ::core::result::Result::Ok(::core::option::Option::Some(t)) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!(r#"assertion failed, expected Ok(None), got Ok(Some(_))
+    expression: `{}`,
+    value: `{:?}`: {}"#, ::core::stringify!($cond), (&$crate::__private::MaybeDebug(&t)).maybe_debug(), ::core::format_args!($($arg)+));
+            }
+            ::core::result::Result::Err(e) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!(r#"assertion failed, expected Ok(None), got Err(_)
+    expression: `{}`,
+    error: `{:?}`: {}"#, ::core::stringify!($cond), (&$crate::__private::MaybeDebug(&e)).maybe_debug(), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that the expression matches an [`Ok(None)`] variant on debug builds.
+///
+/// This macro behaves the same as [`assert_ok_none!`] on debug builds. On release builds it is a
+/// no-op.
+///
+/// [`Ok(None)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Ok
+#[macro_export]
+macro_rules! debug_assert_ok_none {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_ok_none!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    type Lookup = Result<Option<u32>, &'static str>;
+
+    struct NonDebug;
+
+    #[test]
+    fn ok_none() {
+        let res: Lookup = Ok(None);
+        assert_ok_none!(res);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(None), got Ok(Some(_))\n    expression: `res`,\n    value: `1`"
+    )]
+    fn ok_some() {
+        let res: Lookup = Ok(Some(1));
+        assert_ok_none!(res);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(None), got Ok(Some(_))\n    expression: `res`,\n    value: `<non-Debug>`"
+    )]
+    fn ok_some_non_debug() {
+        let res: Result<Option<NonDebug>, ()> = Ok(Some(NonDebug));
+        assert_ok_none!(res);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(None), got Err(_)\n    expression: `res`,\n    error: `\"failed\"`"
+    )]
+    fn err() {
+        let res: Lookup = Err("failed");
+        assert_ok_none!(res);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(None), got Err(_)\n    expression: `res`,\n    error: `<non-Debug>`"
+    )]
+    fn err_non_debug() {
+        let res: Result<Option<()>, NonDebug> = Err(NonDebug);
+        assert_ok_none!(res);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(None), got Ok(Some(_))\n    expression: `res`,\n    value: `1`: foo"
+    )]
+    fn ok_some_custom_message() {
+        let res: Lookup = Ok(Some(1));
+        assert_ok_none!(res, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(None), got Err(_)\n    expression: `res`,\n    error: `\"failed\"`: foo"
+    )]
+    fn err_custom_message() {
+        let res: Lookup = Err("failed");
+        assert_ok_none!(res, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_ok_none() {
+        let res: Lookup = Ok(None);
+        debug_assert_ok_none!(res);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(None), got Ok(Some(_))\n    expression: `res`,\n    value: `1`"
+    )]
+    fn debug_ok_some() {
+        let res: Lookup = Ok(Some(1));
+        debug_assert_ok_none!(res);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(None), got Err(_)\n    expression: `res`,\n    error: `\"failed\"`"
+    )]
+    fn debug_err() {
+        let res: Lookup = Err("failed");
+        debug_assert_ok_none!(res);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(None), got Err(_)\n    expression: `res`,\n    error: `\"failed\"`: foo"
+    )]
+    fn debug_err_custom_message() {
+        let res: Lookup = Err("failed");
+        debug_assert_ok_none!(res, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_err() {
+        let res: Lookup = Err("failed");
+        debug_assert_ok_none!(res);
+    }
+}
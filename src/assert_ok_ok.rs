@@ -0,0 +1,233 @@
+/// Asserts that the expression matches an [`Ok(Ok(_))`] variant, returning the innermost value.
+///
+/// This is useful for nested `Result<Result<T, E>, E2>` values, such as those produced by
+/// multi-stage processing pipelines where each stage can fail. An outer `Err(_)` and an
+/// `Ok(Err(_))` panic with distinct messages, so that it is clear which stage failed. On failure,
+/// the panic message includes the asserted expression as written in the source, along with the
+/// error. The error is printed with its [`Debug`] implementation if it has one, and as
+/// `<non-Debug>` otherwise.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ok_ok!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let res: Result<Result<i32, &str>, &str> = Ok(Ok(1));
+///
+/// assert_ok_ok!(res);
+///
+/// // With a custom message
+/// assert_ok_ok!(res, "Both stages should succeed");
+/// # }
+/// ```
+///
+/// The innermost value will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let res: Result<Result<i32, &str>, &str> = Ok(Ok(1));
+///
+/// let value = assert_ok_ok!(res);
+/// assert_eq!(value, 1);
+/// # }
+/// ```
+///
+/// An `Ok(Err(_))` variant will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let res: Result<Result<i32, &str>, &str> = Ok(Err("parse error"));
+///
+/// assert_ok_ok!(res);  // Will panic
+/// # }
+/// ```
+///
+/// [`Ok(Ok(_))`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Ok
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_ok_ok!`]: crate::debug_assert_ok_ok!
+#[macro_export]
+macro_rules! assert_ok_ok {
+    ($cond:expr $(,)?) => {
+        match $cond {
+            ::core::result::Result::Ok(::core::result::Result::Ok(t)) => t,
+            ::core::result::Result::Ok(::core::result::Result::Err(e)) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!(r#"assertion failed, expected Ok(Ok(_)), got Ok(Err(_))
+    expression: `{}`,
+    error: `{:?}`"#, ::core::stringify!($cond), (&$crate::__private::MaybeDebug(&e)).maybe_debug());
+            }
+            ::core::result::Result::Err(e) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!(r#"assertion failed, expected Ok(Ok(_)), got Err(_)
+    expression: `{}`,
+    error: `{:?}`"#, ::core::stringify!($cond), (&$crate::__private::MaybeDebug(&e)).maybe_debug());
+            }
+        }
+    };
+    ($cond:expr, $($arg:tt)+) => {
+        match $cond {
+            ::core::result::Result::Ok(::core::result::Result::Ok(t)) => t,
+            ::core::result::Result::Ok(::core::result::Result::Err(e)) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!(r#"assertion failed, expected Ok(Ok(_)), got Ok(Err(_))
+    expression: `{}`,
+    error: `{:?}`: {}"#, ::core::stringify!($cond), (&$crate::__private::MaybeDebug(&e)).maybe_debug(), ::core::format_args!($($arg)+));
+            }
+            ::core::result::Result::Err(e) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!(r#"assertion failed, expected Ok(Ok(_)), got Err(_)
+    expression: `{}`,
+    error: `{:?}`: {}"#, ::core::stringify!($cond), (&$crate::__private::MaybeDebug(&e)).maybe_debug(), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that the expression matches an [`Ok(Ok(_))`] variant on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_ok_ok!`] on debug builds, although it does not
+/// return the innermost value. On release builds it is a no-op.
+///
+/// [`Ok(Ok(_))`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Ok
+#[macro_export]
+macro_rules! debug_assert_ok_ok {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_ok_ok!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    type Nested = Result<Result<u32, &'static str>, &'static str>;
+
+    #[test]
+    fn ok_ok() {
+        let res: Nested = Ok(Ok(1));
+        assert_ok_ok!(res);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(Ok(_)), got Ok(Err(_))\n    expression: `res`,\n    error: `\"inner\"`"
+    )]
+    fn ok_err() {
+        let res: Nested = Ok(Err("inner"));
+        assert_ok_ok!(res);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(Ok(_)), got Err(_)\n    expression: `res`,\n    error: `\"outer\"`"
+    )]
+    fn err() {
+        let res: Nested = Err("outer");
+        assert_ok_ok!(res);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(Ok(_)), got Ok(Err(_))\n    expression: `res`,\n    error: `<non-Debug>`"
+    )]
+    fn ok_err_non_debug() {
+        struct NonDebug;
+
+        let res: Result<Result<(), NonDebug>, ()> = Ok(Err(NonDebug));
+        assert_ok_ok!(res);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(Ok(_)), got Ok(Err(_))\n    expression: `res`,\n    error: `\"inner\"`: foo"
+    )]
+    fn ok_err_custom_message() {
+        let res: Nested = Ok(Err("inner"));
+        assert_ok_ok!(res, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(Ok(_)), got Err(_)\n    expression: `res`,\n    error: `\"outer\"`: foo"
+    )]
+    fn err_custom_message() {
+        let res: Nested = Err("outer");
+        assert_ok_ok!(res, "foo");
+    }
+
+    #[test]
+    fn value_returned() {
+        let res: Nested = Ok(Ok(42));
+        let value = assert_ok_ok!(res);
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_ok_ok() {
+        let res: Nested = Ok(Ok(1));
+        debug_assert_ok_ok!(res);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(Ok(_)), got Ok(Err(_))\n    expression: `res`,\n    error: `\"inner\"`"
+    )]
+    fn debug_ok_err() {
+        let res: Nested = Ok(Err("inner"));
+        debug_assert_ok_ok!(res);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(Ok(_)), got Err(_)\n    expression: `res`,\n    error: `\"outer\"`"
+    )]
+    fn debug_err() {
+        let res: Nested = Err("outer");
+        debug_assert_ok_ok!(res);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(Ok(_)), got Ok(Err(_))\n    expression: `res`,\n    error: `\"inner\"`: foo"
+    )]
+    fn debug_ok_err_custom_message() {
+        let res: Nested = Ok(Err("inner"));
+        debug_assert_ok_ok!(res, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_ok_err() {
+        let res: Nested = Ok(Err("inner"));
+        debug_assert_ok_ok!(res);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let res: Nested = Ok(Ok(1));
+        let () = debug_assert_ok_ok!(res);
+    }
+}
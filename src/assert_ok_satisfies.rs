@@ -0,0 +1,286 @@
+/// Asserts that the expression matches an [`Ok(_)`] variant whose contained value satisfies the
+/// predicate, returning the contained value.
+///
+/// The predicate is called with a reference to the contained value, and can either be a closure,
+/// such as `|v| v.len() > 3`, or a named binding followed by an expression, such as
+/// `v => v.len() > 3`. This keeps the context of the `Result` in the failure message, which is lost
+/// when writing `let v = assert_ok!(result); assert!(predicate(&v));`.
+///
+/// If the value does not satisfy the predicate, the stringified predicate and the value are
+/// printed. The value is printed with its [`Debug`] implementation if it has one, and as
+/// `<non-Debug>` otherwise.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ok_satisfies!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let res: Result<&str, ()> = Ok("foobar");
+///
+/// assert_ok_satisfies!(res, |v| v.starts_with("foo"));
+/// assert_ok_satisfies!(res, v => v.len() == 6);
+///
+/// // With a custom message
+/// assert_ok_satisfies!(res, |v| v.starts_with("foo"), "Expecting a foo");
+/// # }
+/// ```
+///
+/// The contained value will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let res: Result<i32, ()> = Ok(42);
+///
+/// let value = assert_ok_satisfies!(res, |v| *v > 0);
+/// assert_eq!(value, 42);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let res: Result<i32, ()> = Ok(-1);
+///
+/// assert_ok_satisfies!(res, |v| *v > 0);  // Will panic
+/// # }
+/// ```
+///
+/// [`Ok(_)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Ok
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_ok_satisfies!`]: crate::debug_assert_ok_satisfies!
+#[macro_export]
+macro_rules! assert_ok_satisfies {
+    ($cond:expr, $binding:ident => $predicate:expr $(,)?) => {
+        match $cond {
+            ::core::result::Result::Ok(t) => {
+                if !$crate::__private::satisfies(&t, |$binding| $predicate) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected Ok(_) to satisfy predicate
+    predicate: `{} => {}`,
+    value: `{:?}`"#, ::core::stringify!($binding), ::core::stringify!($predicate), (&$crate::__private::MaybeDebug(&t)).maybe_debug());
+                }
+                t
+            }
+            ::core::result::Result::Err(_) => {
+                ::core::panic!(r#"assertion failed, expected Ok(_), got Err(_)
+    expression: `{}`"#, ::core::stringify!($cond));
+            }
+        }
+    };
+    ($cond:expr, $binding:ident => $predicate:expr, $($arg:tt)+) => {
+        match $cond {
+            ::core::result::Result::Ok(t) => {
+                if !$crate::__private::satisfies(&t, |$binding| $predicate) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected Ok(_) to satisfy predicate
+    predicate: `{} => {}`,
+    value: `{:?}`: {}"#, ::core::stringify!($binding), ::core::stringify!($predicate), (&$crate::__private::MaybeDebug(&t)).maybe_debug(), ::core::format_args!($($arg)+));
+                }
+                t
+            }
+            ::core::result::Result::Err(_) => {
+                ::core::panic!(r#"assertion failed, expected Ok(_), got Err(_)
+    expression: `{}`: {}"#, ::core::stringify!($cond), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+    ($cond:expr, $predicate:expr $(,)?) => {
+        match $cond {
+            ::core::result::Result::Ok(t) => {
+                if !$crate::__private::satisfies(&t, $predicate) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected Ok(_) to satisfy predicate
+    predicate: `{}`,
+    value: `{:?}`"#, ::core::stringify!($predicate), (&$crate::__private::MaybeDebug(&t)).maybe_debug());
+                }
+                t
+            }
+            ::core::result::Result::Err(_) => {
+                ::core::panic!(r#"assertion failed, expected Ok(_), got Err(_)
+    expression: `{}`"#, ::core::stringify!($cond));
+            }
+        }
+    };
+    ($cond:expr, $predicate:expr, $($arg:tt)+) => {
+        match $cond {
+            ::core::result::Result::Ok(t) => {
+                if !$crate::__private::satisfies(&t, $predicate) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected Ok(_) to satisfy predicate
+    predicate: `{}`,
+    value: `{:?}`: {}"#, ::core::stringify!($predicate), (&$crate::__private::MaybeDebug(&t)).maybe_debug(), ::core::format_args!($($arg)+));
+                }
+                t
+            }
+            ::core::result::Result::Err(_) => {
+                ::core::panic!(r#"assertion failed, expected Ok(_), got Err(_)
+    expression: `{}`: {}"#, ::core::stringify!($cond), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that the expression matches an [`Ok(_)`] variant whose contained value satisfies the
+/// predicate on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_ok_satisfies!`] on debug builds, although it does
+/// not return the value contained in the `Ok` variant. On release builds it is a no-op.
+///
+/// [`Ok(_)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Ok
+#[macro_export]
+macro_rules! debug_assert_ok_satisfies {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_ok_satisfies!($($arg)*);
+        }
+    }};
+}
+
+/// Returns whether the value satisfies the predicate.
+///
+/// Passing the predicate through this function, rather than calling it in the macro, gives a
+/// closure predicate its argument type without any annotations.
+///
+/// This is an implementation detail of the `*_satisfies!` macros, such as [`assert_ok_satisfies!`],
+/// and is not part of the public API.
+#[doc(hidden)]
+pub fn satisfies<T, F>(value: &T, predicate: F) -> bool
+where
+    F: FnOnce(&T) -> bool,
+{
+    predicate(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{string::String, vec, vec::Vec};
+
+    #[test]
+    fn ok_satisfies() {
+        assert_ok_satisfies!(Ok::<_, ()>(42), |v| *v > 0);
+    }
+
+    #[test]
+    fn ok_satisfies_binding() {
+        assert_ok_satisfies!(Ok::<_, ()>(42), v => *v > 0);
+    }
+
+    #[test]
+    fn ok_satisfies_method_call() {
+        let res: Result<Vec<u32>, ()> = Ok(vec![1, 2, 3]);
+        assert_ok_satisfies!(res, |v| v.len() == 3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_) to satisfy predicate\n    predicate: `|v| *v > 0`,\n    value: `-1`"
+    )]
+    fn ok_not_satisfies() {
+        assert_ok_satisfies!(Ok::<_, ()>(-1), |v| *v > 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_) to satisfy predicate\n    predicate: `v => *v > 0`,\n    value: `-1`"
+    )]
+    fn ok_not_satisfies_binding() {
+        assert_ok_satisfies!(Ok::<_, ()>(-1), v => *v > 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_) to satisfy predicate\n    predicate: `|_| false`,\n    value: `<non-Debug>`"
+    )]
+    fn ok_not_satisfies_non_debug() {
+        struct NonDebug;
+
+        assert_ok_satisfies!(Ok::<_, ()>(NonDebug), |_| false);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_) to satisfy predicate\n    predicate: `|v| *v > 0`,\n    value: `-1`: foo"
+    )]
+    fn ok_not_satisfies_custom_message() {
+        assert_ok_satisfies!(Ok::<_, ()>(-1), |v| *v > 0, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_), got Err(_)\n    expression: `res`"
+    )]
+    fn not_ok() {
+        let res = Err::<i32, _>(());
+        assert_ok_satisfies!(res, |v| *v > 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_), got Err(_)\n    expression: `res`: foo"
+    )]
+    fn not_ok_custom_message() {
+        let res = Err::<i32, _>(());
+        assert_ok_satisfies!(res, v => *v > 0, "foo");
+    }
+
+    #[test]
+    fn value_returned() {
+        let res: Result<String, ()> = Ok(String::from("foo"));
+        let value = assert_ok_satisfies!(res, |v| v.starts_with('f'));
+        assert_eq!(value, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_ok_satisfies() {
+        debug_assert_ok_satisfies!(Ok::<_, ()>(42), |v| *v > 0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_) to satisfy predicate\n    predicate: `|v| *v > 0`,\n    value: `-1`"
+    )]
+    fn debug_ok_not_satisfies() {
+        debug_assert_ok_satisfies!(Ok::<_, ()>(-1), |v| *v > 0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(_) to satisfy predicate\n    predicate: `|v| *v > 0`,\n    value: `-1`: foo"
+    )]
+    fn debug_ok_not_satisfies_custom_message() {
+        debug_assert_ok_satisfies!(Ok::<_, ()>(-1), |v| *v > 0, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_ok_not_satisfies() {
+        debug_assert_ok_satisfies!(Ok::<_, ()>(-1), |v| *v > 0);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_ok_satisfies!(Ok::<_, ()>(42), |v| *v > 0);
+    }
+}
@@ -0,0 +1,227 @@
+/// Asserts that the expression matches an [`Ok(Some(_))`] variant, returning the innermost value.
+///
+/// This is useful for `Result<Option<T>, E>` values, such as those produced by fallible lookups
+/// where an absent value is distinct from an error. An `Err(_)` and an `Ok(None)` panic with
+/// distinct messages, so that it is clear whether the lookup failed or found nothing. On failure,
+/// the panic message includes the asserted expression as written in the source. For `Err(_)`, the
+/// error is also printed with its [`Debug`] implementation if it has one, and as `<non-Debug>`
+/// otherwise.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ok_some!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let lookup: Result<Option<i32>, &str> = Ok(Some(1));
+///
+/// assert_ok_some!(lookup);
+///
+/// // With a custom message
+/// assert_ok_some!(lookup, "Expecting the key to be found");
+/// # }
+/// ```
+///
+/// The innermost value will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let lookup: Result<Option<i32>, &str> = Ok(Some(1));
+///
+/// let value = assert_ok_some!(lookup);
+/// assert_eq!(value, 1);
+/// # }
+/// ```
+///
+/// An `Ok(None)` variant will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let lookup: Result<Option<i32>, &str> = Ok(None);
+///
+/// assert_ok_some!(lookup);  // Will panic
+/// # }
+/// ```
+///
+/// [`Ok(Some(_))`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Ok
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_ok_some!`]: crate::debug_assert_ok_some!
+#[macro_export]
+macro_rules! assert_ok_some {
+    ($cond:expr $(,)?) => {
+        match $cond {
+            ::core::result::Result::Ok(::core::option::Option::Some(t)) => t,
+            ::core::result::Result::Ok(::core::option::Option::None) => {
+                ::core::panic!(r#"assertion failed, expected Ok(Some(_)), got Ok(None)
+    expression: `{}`"#, ::core::stringify!($cond));
+            }
+            ::core::result::Result::Err(e) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!(r#"assertion failed, expected Ok(Some(_)), got Err(_)
+    expression: `{}`,
+    error: `{:?}`"#, ::core::stringify!($cond), (&$crate::__private::MaybeDebug(&e)).maybe_debug());
+            }
+        }
+    };
+    ($cond:expr, $($arg:tt)+) => {
+        match $cond {
+            ::core::result::Result::Ok(::core::option::Option::Some(t)) => t,
+            ::core::result::Result::Ok(::core::option::Option::None) => {
+                ::core::panic!(r#"assertion failed, expected Ok(Some(_)), got Ok(None)
+    expression: `{}`: {}"#, ::core::stringify!($cond), ::core::format_args!($($arg)+));
+            }
+            ::core::result::Result::Err(e) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!(r#"assertion failed, expected Ok(Some(_)), got Err(_)
+    expression: `{}`,
+    error: `{:?}`: {}"#, ::core::stringify!($cond), (&$crate::__private::MaybeDebug(&e)).maybe_debug(), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that the expression matches an [`Ok(Some(_))`] variant on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_ok_some!`] on debug builds, although it does not
+/// return the innermost value. On release builds it is a no-op.
+///
+/// [`Ok(Some(_))`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Ok
+#[macro_export]
+macro_rules! debug_assert_ok_some {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_ok_some!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    type Lookup = Result<Option<u32>, &'static str>;
+
+    #[test]
+    fn ok_some() {
+        let res: Lookup = Ok(Some(1));
+        assert_ok_some!(res);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(Some(_)), got Ok(None)\n    expression: `res`"
+    )]
+    fn ok_none() {
+        let res: Lookup = Ok(None);
+        assert_ok_some!(res);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(Some(_)), got Err(_)\n    expression: `res`,\n    error: `\"failed\"`"
+    )]
+    fn err() {
+        let res: Lookup = Err("failed");
+        assert_ok_some!(res);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(Some(_)), got Err(_)\n    expression: `res`,\n    error: `<non-Debug>`"
+    )]
+    fn err_non_debug() {
+        struct NonDebug;
+
+        let res: Result<Option<()>, NonDebug> = Err(NonDebug);
+        assert_ok_some!(res);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(Some(_)), got Ok(None)\n    expression: `res`: foo"
+    )]
+    fn ok_none_custom_message() {
+        let res: Lookup = Ok(None);
+        assert_ok_some!(res, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(Some(_)), got Err(_)\n    expression: `res`,\n    error: `\"failed\"`: foo"
+    )]
+    fn err_custom_message() {
+        let res: Lookup = Err("failed");
+        assert_ok_some!(res, "foo");
+    }
+
+    #[test]
+    fn value_returned() {
+        let res: Lookup = Ok(Some(42));
+        let value = assert_ok_some!(res);
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_ok_some() {
+        let res: Lookup = Ok(Some(1));
+        debug_assert_ok_some!(res);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(Some(_)), got Ok(None)\n    expression: `res`"
+    )]
+    fn debug_ok_none() {
+        let res: Lookup = Ok(None);
+        debug_assert_ok_some!(res);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(Some(_)), got Err(_)\n    expression: `res`,\n    error: `\"failed\"`"
+    )]
+    fn debug_err() {
+        let res: Lookup = Err("failed");
+        debug_assert_ok_some!(res);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(Some(_)), got Ok(None)\n    expression: `res`: foo"
+    )]
+    fn debug_ok_none_custom_message() {
+        let res: Lookup = Ok(None);
+        debug_assert_ok_some!(res, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_ok_none() {
+        let res: Lookup = Ok(None);
+        debug_assert_ok_some!(res);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let res: Lookup = Ok(Some(1));
+        let () = debug_assert_ok_some!(res);
+    }
+}
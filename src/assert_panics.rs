@@ -0,0 +1,192 @@
+use std::{any::Any, boxed::Box, panic};
+
+/// Asserts that calling the closure panics, returning the panic payload.
+///
+/// The closure is called through [`catch_unwind`], so the panic is contained and the test can
+/// continue afterwards. Unlike `#[should_panic]`, this allows checking that only a specific part
+/// of a test panics. The closure is not required to be [`UnwindSafe`].
+///
+/// The panic message is still printed by the panic hook, as with any other panic.
+///
+/// This macro requires the `std` feature.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_panics!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let values: Vec<u32> = Vec::new();
+/// assert_panics!(|| values[0]);
+///
+/// // With a custom message
+/// assert_panics!(|| values[0], "Expecting indexing into an empty vector to panic");
+/// # }
+/// ```
+///
+/// The panic payload will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let payload = assert_panics!(|| panic!("out of fuel"));
+/// assert_eq!(payload.downcast_ref::<&str>(), Some(&"out of fuel"));
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_panics!(|| 1 + 1);  // Will panic
+/// # }
+/// ```
+///
+/// [`catch_unwind`]: https://doc.rust-lang.org/std/panic/fn.catch_unwind.html
+/// [`UnwindSafe`]: https://doc.rust-lang.org/std/panic/trait.UnwindSafe.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_panics!`]: crate::debug_assert_panics!
+#[macro_export]
+macro_rules! assert_panics {
+    ($closure:expr $(,)?) => {
+        match $crate::__private::catch_unwind($closure) {
+            ::core::result::Result::Ok(_) => {
+                ::core::panic!("assertion failed, expected closure to panic");
+            }
+            ::core::result::Result::Err(payload) => payload,
+        }
+    };
+    ($closure:expr, $($arg:tt)+) => {
+        match $crate::__private::catch_unwind($closure) {
+            ::core::result::Result::Ok(_) => {
+                ::core::panic!("assertion failed, expected closure to panic: {}", ::core::format_args!($($arg)+));
+            }
+            ::core::result::Result::Err(payload) => payload,
+        }
+    };
+}
+
+/// Asserts that calling the closure panics on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_panics!`] on debug builds, although it does not
+/// return the panic payload. On release builds it is a no-op, and the closure is not called.
+///
+/// This macro requires the `std` feature.
+#[macro_export]
+macro_rules! debug_assert_panics {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_panics!($($arg)*);
+        }
+    }};
+}
+
+/// Calls the closure, catching any panic.
+///
+/// This is an implementation detail of [`assert_panics!`] and is not part of the public API.
+#[doc(hidden)]
+pub fn catch_unwind<F, R>(closure: F) -> Result<R, Box<dyn Any + Send>>
+where
+    F: FnOnce() -> R,
+{
+    panic::catch_unwind(panic::AssertUnwindSafe(closure))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{boxed::Box, cell::Cell, panic, vec::Vec};
+
+    #[test]
+    fn panics() {
+        assert_panics!(|| panic!("foo"));
+    }
+
+    #[test]
+    fn panics_index_out_of_bounds() {
+        let values: Vec<u32> = Vec::new();
+        assert_panics!(|| values[0]);
+    }
+
+    #[test]
+    fn not_unwind_safe() {
+        let counter = Cell::new(0);
+        assert_panics!(|| {
+            counter.set(counter.get() + 1);
+            panic!("foo");
+        });
+        assert_eq!(counter.get(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected closure to panic")]
+    fn does_not_panic() {
+        assert_panics!(|| 1 + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected closure to panic: foo")]
+    fn does_not_panic_custom_message() {
+        assert_panics!(|| 1 + 1, "foo");
+    }
+
+    #[test]
+    fn payload_returned() {
+        let payload = assert_panics!(|| panic!("foo"));
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"foo"));
+    }
+
+    #[test]
+    fn payload_returned_custom_message() {
+        let payload = assert_panics!(|| panic::resume_unwind(Box::new(42u32)), "foo");
+        assert_eq!(payload.downcast_ref::<u32>(), Some(&42));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_panics() {
+        debug_assert_panics!(|| panic!("foo"));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, expected closure to panic")]
+    fn debug_does_not_panic() {
+        debug_assert_panics!(|| 1 + 1);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, expected closure to panic: foo")]
+    fn debug_does_not_panic_custom_message() {
+        debug_assert_panics!(|| 1 + 1, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_does_not_panic() {
+        debug_assert_panics!(|| 1 + 1);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_closure_not_called() {
+        let counter = Cell::new(0);
+        debug_assert_panics!(|| counter.set(counter.get() + 1));
+        assert_eq!(counter.get(), 0);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_panics!(|| panic!("foo"));
+    }
+}
@@ -76,19 +76,19 @@ macro_rules! assert_pending {
 
 /// Asserts that the expression matches a [`Poll::Pending`] variant on debug builds.
 ///
-/// This macro behaves the same as [`assert_pending!`] on debug builds. On release builds it is a
-/// no-op.
+/// This macro behaves nearly the same as [`assert_pending!`] on debug builds, although it does not
+/// return the `Poll::Pending` value. On release builds it is a no-op.
 ///
 /// [`Poll::Pending`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Pending
 #[macro_export]
 macro_rules! debug_assert_pending {
-    ($($arg:tt)*) => {
+    ($($arg:tt)*) => {{
         #[allow(unused_must_use)]
         #[cfg(debug_assertions)]
         {
             $crate::assert_pending!($($arg)*);
         }
-    }
+    }};
 }
 
 #[cfg(test)]
@@ -137,4 +137,9 @@ mod tests {
     fn debug_release_not_pending() {
         debug_assert_pending!(Ready(()));
     }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_pending!(Pending::<()>);
+    }
 }
@@ -0,0 +1,198 @@
+/// Asserts that the expression is positive, returning the value.
+///
+/// A value is positive if it is strictly greater than zero, so zero itself will cause a panic. See
+/// [`assert_non_negative!`] for an assertion which also accepts zero.
+///
+/// Works with all primitive integer and floating point types, as well as [`Duration`].
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_positive!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # use std::time::Duration;
+/// # fn main() {
+/// assert_positive!(42u64);
+/// assert_positive!(0.5f32);
+/// assert_positive!(Duration::from_millis(100));
+///
+/// // With a custom message
+/// assert_positive!(42, "Expecting {} to be positive", 42);
+/// # }
+/// ```
+///
+/// The value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let value = assert_positive!(42);
+/// assert_eq!(value, 42);
+/// # }
+/// ```
+///
+/// Zero and negative values will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_positive!(0);  // Will panic
+/// # }
+/// ```
+///
+/// [`Duration`]: https://doc.rust-lang.org/core/time/struct.Duration.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_non_negative!`]: crate::assert_non_negative!
+/// [`debug_assert_positive!`]: crate::debug_assert_positive!
+#[macro_export]
+macro_rules! assert_positive {
+    ($value:expr $(,)?) => {
+        match $value {
+            value => {
+                if !::core::cmp::PartialOrd::gt(&value, &$crate::__private::Zero::zero(&value)) {
+                    ::core::panic!("assertion failed, expected positive value, got {:?}", value);
+                }
+                value
+            }
+        }
+    };
+    ($value:expr, $($arg:tt)+) => {
+        match $value {
+            value => {
+                if !::core::cmp::PartialOrd::gt(&value, &$crate::__private::Zero::zero(&value)) {
+                    ::core::panic!("assertion failed, expected positive value, got {:?}: {}", value, ::core::format_args!($($arg)+));
+                }
+                value
+            }
+        }
+    };
+}
+
+/// Asserts that the expression is positive on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_positive!`] on debug builds, although it does
+/// not return the value. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_positive {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_positive!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    #[test]
+    fn positive() {
+        assert_positive!(42);
+    }
+
+    #[test]
+    fn positive_float() {
+        assert_positive!(core::f64::MIN_POSITIVE);
+    }
+
+    #[test]
+    fn positive_duration() {
+        assert_positive!(Duration::from_nanos(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected positive value, got 0")]
+    fn zero() {
+        assert_positive!(0u32);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected positive value, got 0.0")]
+    fn zero_float() {
+        assert_positive!(0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected positive value, got 0ns")]
+    fn zero_duration() {
+        assert_positive!(Duration::from_secs(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected positive value, got -1")]
+    fn negative() {
+        assert_positive!(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected positive value, got NaN")]
+    fn nan() {
+        assert_positive!(core::f32::NAN);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected positive value, got -1: foo")]
+    fn negative_custom_message() {
+        assert_positive!(-1, "foo");
+    }
+
+    #[test]
+    fn positive_value_returned() {
+        let value = assert_positive!(42);
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_positive() {
+        debug_assert_positive!(42);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, expected positive value, got 0")]
+    fn debug_zero() {
+        debug_assert_positive!(0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, expected positive value, got -1: foo")]
+    fn debug_negative_custom_message() {
+        debug_assert_positive!(-1, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_zero() {
+        debug_assert_positive!(0);
+    }
+
+    #[test]
+    fn debug_positive_returns_unit() {
+        let () = debug_assert_positive!(1);
+    }
+
+    #[test]
+    fn debug_positive_returns_unit_custom_message() {
+        let () = debug_assert_positive!(1, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_positive_returns_unit() {
+        let () = debug_assert_positive!(-1);
+    }
+}
@@ -0,0 +1,207 @@
+/// Asserts that the expression is a power of two, returning the value.
+///
+/// Works with all primitive unsigned integer types. Zero is not a power of two. On failure, the
+/// value is printed in both decimal and binary, making any stray bits visible.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_power_of_two!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let capacity: usize = 4096;
+/// assert_power_of_two!(capacity);
+///
+/// // With a custom message
+/// assert_power_of_two!(capacity, "Expecting a capacity of {} to be a power of two", capacity);
+/// # }
+/// ```
+///
+/// The value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let value = assert_power_of_two!(64u32);
+/// assert_eq!(value, 64);
+/// # }
+/// ```
+///
+/// Any other value will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_power_of_two!(4097u32);  // Will panic
+/// # }
+/// ```
+///
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_power_of_two!`]: crate::debug_assert_power_of_two!
+#[macro_export]
+macro_rules! assert_power_of_two {
+    ($value:expr $(,)?) => {
+        match $value {
+            value => {
+                if !$crate::__private::PowerOfTwo::is_power_of_two(&value) {
+                    if value == 0 {
+                        ::core::panic!("assertion failed, expected power of two, got zero");
+                    } else {
+                        ::core::panic!("assertion failed, expected power of two, got {} ({:#b})", value, value);
+                    }
+                }
+                value
+            }
+        }
+    };
+    ($value:expr, $($arg:tt)+) => {
+        match $value {
+            value => {
+                if !$crate::__private::PowerOfTwo::is_power_of_two(&value) {
+                    if value == 0 {
+                        ::core::panic!("assertion failed, expected power of two, got zero: {}", ::core::format_args!($($arg)+));
+                    } else {
+                        ::core::panic!("assertion failed, expected power of two, got {} ({:#b}): {}", value, value, ::core::format_args!($($arg)+));
+                    }
+                }
+                value
+            }
+        }
+    };
+}
+
+/// Asserts that the expression is a power of two on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_power_of_two!`] on debug builds, although it
+/// does not return the value. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_power_of_two {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_power_of_two!($($arg)*);
+        }
+    }};
+}
+
+/// Unsigned integer types which can be checked for being a power of two.
+///
+/// This is an implementation detail of [`assert_power_of_two!`] and is not part of the public API.
+#[doc(hidden)]
+pub trait PowerOfTwo {
+    fn is_power_of_two(&self) -> bool;
+}
+
+macro_rules! impl_power_of_two {
+    ($($ty:ident)*) => {
+        $(
+            impl PowerOfTwo for $ty {
+                fn is_power_of_two(&self) -> bool {
+                    $ty::is_power_of_two(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_power_of_two! {u8 u16 u32 u64 u128 usize}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn one() {
+        assert_power_of_two!(1u8);
+    }
+
+    #[test]
+    fn power_of_two() {
+        assert_power_of_two!(4096u32);
+    }
+
+    #[test]
+    fn largest_power_of_two() {
+        assert_power_of_two!(1u128 << 127);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected power of two, got zero")]
+    fn zero() {
+        assert_power_of_two!(0usize);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected power of two, got 4097 (0b1000000000001)"
+    )]
+    fn not_power_of_two() {
+        assert_power_of_two!(4097u64);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected power of two, got zero: foo")]
+    fn zero_custom_message() {
+        assert_power_of_two!(0u16, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected power of two, got 4097 (0b1000000000001): foo"
+    )]
+    fn not_power_of_two_custom_message() {
+        assert_power_of_two!(4097u64, "foo");
+    }
+
+    #[test]
+    fn power_of_two_value_returned() {
+        let value = assert_power_of_two!(4096u32);
+        assert_eq!(value, 4096);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_power_of_two() {
+        debug_assert_power_of_two!(4096u32);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, expected power of two, got zero")]
+    fn debug_zero() {
+        debug_assert_power_of_two!(0u32);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected power of two, got 4097 (0b1000000000001)"
+    )]
+    fn debug_not_power_of_two() {
+        debug_assert_power_of_two!(4097u32);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected power of two, got 4097 (0b1000000000001): foo"
+    )]
+    fn debug_not_power_of_two_custom_message() {
+        debug_assert_power_of_two!(4097u32, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_power_of_two() {
+        debug_assert_power_of_two!(4097u32);
+    }
+}
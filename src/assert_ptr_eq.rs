@@ -0,0 +1,176 @@
+/// Asserts that two pointers point to the same address.
+///
+/// Both expressions must coerce to a `*const T` pointer of the same type, which includes
+/// references, raw pointers, and mutable references. Unlike [`assert_eq!`], which compares the
+/// values being pointed to, this macro compares the pointers themselves using [`ptr::eq`]. This is
+/// useful, for example, to check that a cache returns the same instance or that two structures
+/// share the same memory. The pointers are never dereferenced.
+///
+/// Pointers to unsized types, such as slices and trait objects, are compared including their
+/// metadata, just as [`ptr::eq`] does.
+///
+/// On failure, both addresses are printed in hexadecimal.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ptr_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # use std::rc::Rc;
+/// # fn main() {
+/// let shared = Rc::new(42);
+/// let clone = Rc::clone(&shared);
+/// assert_ptr_eq!(&*shared, &*clone);
+///
+/// // With a custom message
+/// assert_ptr_eq!(&*shared, &*clone, "Expecting the clone to share its allocation");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let a = 42;
+/// let b = 42;
+/// assert_ptr_eq!(&a, &b);  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_eq!`]: https://doc.rust-lang.org/core/macro.assert_eq.html
+/// [`ptr::eq`]: https://doc.rust-lang.org/core/ptr/fn.eq.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_ptr_eq!`]: crate::debug_assert_ptr_eq!
+#[macro_export]
+macro_rules! assert_ptr_eq {
+    ($left:expr, $right:expr $(,)?) => {{
+        let left_ptr: *const _ = $left;
+        let right_ptr: *const _ = $right;
+        if !::core::ptr::eq(left_ptr, right_ptr) {
+            ::core::panic!(r#"assertion failed, expected pointers to be equal
+    left: `{:p}`,
+    right: `{:p}`"#, left_ptr, right_ptr);
+        }
+    }};
+    ($left:expr, $right:expr, $($arg:tt)+) => {{
+        let left_ptr: *const _ = $left;
+        let right_ptr: *const _ = $right;
+        if !::core::ptr::eq(left_ptr, right_ptr) {
+            ::core::panic!(r#"assertion failed, expected pointers to be equal
+    left: `{:p}`,
+    right: `{:p}`: {}"#, left_ptr, right_ptr, ::core::format_args!($($arg)+));
+        }
+    }};
+}
+
+/// Asserts that two pointers point to the same address on debug builds.
+///
+/// This macro behaves the same as [`assert_ptr_eq!`] on debug builds. On release builds it is a
+/// no-op.
+#[macro_export]
+macro_rules! debug_assert_ptr_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_ptr_eq!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn same_reference() {
+        let value = 42;
+        assert_ptr_eq!(&value, &value);
+    }
+
+    #[test]
+    fn mutable_reference() {
+        let mut value = 42;
+        let ptr: *const u32 = &value;
+        assert_ptr_eq!(&mut value, ptr);
+    }
+
+    #[test]
+    fn raw_pointers() {
+        assert_ptr_eq!(0x10 as *const u8, 0x10 as *const u8);
+    }
+
+    #[test]
+    fn slices() {
+        let values = [1, 2, 3];
+        assert_ptr_eq!(&values[..], &values[..]);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected pointers to be equal\n    left: `0x")]
+    fn different_references() {
+        let a = 42;
+        let b = 42;
+        assert_ptr_eq!(&a, &b);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected pointers to be equal\n    left: `0x10`,\n    right: `0x20`"
+    )]
+    fn different_raw_pointers() {
+        assert_ptr_eq!(0x10 as *const u8, 0x20 as *const u8);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected pointers to be equal\n    left: `")]
+    fn slices_different_length() {
+        let values = [1, 2, 3];
+        assert_ptr_eq!(&values[..], &values[..2]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected pointers to be equal\n    left: `0x10`,\n    right: `0x20`: foo"
+    )]
+    fn different_raw_pointers_custom_message() {
+        assert_ptr_eq!(0x10 as *const u8, 0x20 as *const u8, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_same_reference() {
+        let value = 42;
+        debug_assert_ptr_eq!(&value, &value);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected pointers to be equal\n    left: `0x10`,\n    right: `0x20`"
+    )]
+    fn debug_different_raw_pointers() {
+        debug_assert_ptr_eq!(0x10 as *const u8, 0x20 as *const u8);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected pointers to be equal\n    left: `0x10`,\n    right: `0x20`: foo"
+    )]
+    fn debug_different_raw_pointers_custom_message() {
+        debug_assert_ptr_eq!(0x10 as *const u8, 0x20 as *const u8, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_different_raw_pointers() {
+        debug_assert_ptr_eq!(0x10 as *const u8, 0x20 as *const u8);
+    }
+}
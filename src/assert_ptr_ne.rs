@@ -0,0 +1,167 @@
+/// Asserts that two pointers point to different addresses.
+///
+/// Both expressions must coerce to a `*const T` pointer of the same type, which includes
+/// references, raw pointers, and mutable references. Unlike [`assert_ne!`], which compares the
+/// values being pointed to, this macro compares the pointers themselves using [`ptr::eq`]. This is
+/// useful, for example, to check that `clone()` produces a distinct allocation. The pointers are
+/// never dereferenced.
+///
+/// Pointers to unsized types, such as slices and trait objects, are compared including their
+/// metadata, just as [`ptr::eq`] does.
+///
+/// On failure, both addresses are printed in hexadecimal.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ptr_ne!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let original = Box::new(42);
+/// let clone = original.clone();
+/// assert_ptr_ne!(&*original, &*clone);
+///
+/// // With a custom message
+/// assert_ptr_ne!(&*original, &*clone, "Expecting the clone to have its own allocation");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let value = 42;
+/// assert_ptr_ne!(&value, &value);  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_ne!`]: https://doc.rust-lang.org/core/macro.assert_ne.html
+/// [`ptr::eq`]: https://doc.rust-lang.org/core/ptr/fn.eq.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_ptr_ne!`]: crate::debug_assert_ptr_ne!
+#[macro_export]
+macro_rules! assert_ptr_ne {
+    ($left:expr, $right:expr $(,)?) => {{
+        let left_ptr: *const _ = $left;
+        let right_ptr: *const _ = $right;
+        if ::core::ptr::eq(left_ptr, right_ptr) {
+            ::core::panic!(r#"assertion failed, expected pointers to not be equal
+    left: `{:p}`,
+    right: `{:p}`"#, left_ptr, right_ptr);
+        }
+    }};
+    ($left:expr, $right:expr, $($arg:tt)+) => {{
+        let left_ptr: *const _ = $left;
+        let right_ptr: *const _ = $right;
+        if ::core::ptr::eq(left_ptr, right_ptr) {
+            ::core::panic!(r#"assertion failed, expected pointers to not be equal
+    left: `{:p}`,
+    right: `{:p}`: {}"#, left_ptr, right_ptr, ::core::format_args!($($arg)+));
+        }
+    }};
+}
+
+/// Asserts that two pointers point to different addresses on debug builds.
+///
+/// This macro behaves the same as [`assert_ptr_ne!`] on debug builds. On release builds it is a
+/// no-op.
+#[macro_export]
+macro_rules! debug_assert_ptr_ne {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_ptr_ne!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn different_references() {
+        let a = 42;
+        let b = 42;
+        assert_ptr_ne!(&a, &b);
+    }
+
+    #[test]
+    fn raw_pointers() {
+        assert_ptr_ne!(0x10 as *const u8, 0x20 as *const u8);
+    }
+
+    #[test]
+    fn slices_different_length() {
+        let values = [1, 2, 3];
+        assert_ptr_ne!(&values[..], &values[..2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected pointers to not be equal\n    left: `0x")]
+    fn same_reference() {
+        let value = 42;
+        assert_ptr_ne!(&value, &value);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected pointers to not be equal\n    left: `0x")]
+    fn mutable_reference() {
+        let mut value = 42;
+        let ptr: *const u32 = &value;
+        assert_ptr_ne!(&mut value, ptr);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected pointers to not be equal\n    left: `0x10`,\n    right: `0x10`"
+    )]
+    fn same_raw_pointers() {
+        assert_ptr_ne!(0x10 as *const u8, 0x10 as *const u8);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected pointers to not be equal\n    left: `0x10`,\n    right: `0x10`: foo"
+    )]
+    fn same_raw_pointers_custom_message() {
+        assert_ptr_ne!(0x10 as *const u8, 0x10 as *const u8, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_different_raw_pointers() {
+        debug_assert_ptr_ne!(0x10 as *const u8, 0x20 as *const u8);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected pointers to not be equal\n    left: `0x10`,\n    right: `0x10`"
+    )]
+    fn debug_same_raw_pointers() {
+        debug_assert_ptr_ne!(0x10 as *const u8, 0x10 as *const u8);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected pointers to not be equal\n    left: `0x10`,\n    right: `0x10`: foo"
+    )]
+    fn debug_same_raw_pointers_custom_message() {
+        debug_assert_ptr_ne!(0x10 as *const u8, 0x10 as *const u8, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_same_raw_pointers() {
+        debug_assert_ptr_ne!(0x10 as *const u8, 0x10 as *const u8);
+    }
+}
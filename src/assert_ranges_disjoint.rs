@@ -0,0 +1,198 @@
+/// Asserts that two ranges are disjoint.
+///
+/// Works with any pair of ranges implementing [`RangeBounds`] over the same type, such as `a..b`,
+/// `a..=b`, `a..`, `..b`, and `..=b`, including unbounded and excluded endpoints. The bounds must
+/// implement [`PartialOrd`] and [`Debug`].
+///
+/// Two ranges are disjoint if no value is contained in both of them. Ranges which only touch at an
+/// endpoint are disjoint unless both of them include that endpoint, so `1..3` and `3..5` are
+/// disjoint, but `1..=3` and `3..5` are not. An empty range, such as `3..3`, is disjoint from every
+/// other range.
+///
+/// On failure, both ranges are printed using range syntax. An excluded start bound, which has no
+/// range syntax, is printed as `Excluded(start)..`.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ranges_disjoint!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_ranges_disjoint!(1..3, 3..5);
+/// assert_ranges_disjoint!(..0, 0..);
+/// assert_ranges_disjoint!(3..3, 0..10);
+///
+/// // With a custom message
+/// assert_ranges_disjoint!(1..3, 3..5, "Expecting back-to-back bookings not to conflict");
+/// # }
+/// ```
+///
+/// Ranges that touch at an endpoint included by both will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_ranges_disjoint!(1..=3, 3..5);  // Will panic
+/// # }
+/// ```
+///
+/// [`RangeBounds`]: https://doc.rust-lang.org/core/ops/trait.RangeBounds.html
+/// [`PartialOrd`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_ranges_disjoint!`]: crate::debug_assert_ranges_disjoint!
+#[macro_export]
+macro_rules! assert_ranges_disjoint {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if $crate::__private::ranges_overlap(left_val, right_val) {
+                    let (left_syntax, right_syntax) = $crate::__private::range_syntax(left_val, right_val);
+                    ::core::panic!(r#"assertion failed, expected ranges to be disjoint
+    left: `{:?}`,
+    right: `{:?}`"#, left_syntax, right_syntax)
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if $crate::__private::ranges_overlap(left_val, right_val) {
+                    let (left_syntax, right_syntax) = $crate::__private::range_syntax(left_val, right_val);
+                    ::core::panic!(r#"assertion failed, expected ranges to be disjoint
+    left: `{:?}`,
+    right: `{:?}`: {}"#, left_syntax, right_syntax, ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two ranges are disjoint on debug builds.
+///
+/// This macro behaves the same as [`assert_ranges_disjoint!`] on debug builds. On release builds it
+/// is a no-op.
+#[macro_export]
+macro_rules! debug_assert_ranges_disjoint {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_ranges_disjoint!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::Bound;
+
+    #[test]
+    fn exclusive_touching() {
+        assert_ranges_disjoint!(1..3, 3..5);
+    }
+
+    #[test]
+    fn exclusive_touching_reversed() {
+        assert_ranges_disjoint!(3..5, 1..3);
+    }
+
+    #[test]
+    fn excluded_start_touching() {
+        assert_ranges_disjoint!(3..=5, (Bound::Excluded(5), Bound::Included(7)));
+    }
+
+    #[test]
+    fn unbounded() {
+        assert_ranges_disjoint!(..0, 0..);
+    }
+
+    #[test]
+    fn empty() {
+        assert_ranges_disjoint!(3..3, 0..10);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn empty_inclusive() {
+        assert_ranges_disjoint!(.., 5..=4);
+    }
+
+    #[test]
+    fn floats() {
+        assert_ranges_disjoint!(0.0..1.0, 1.0..=2.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected ranges to be disjoint\n    left: `1..=3`,\n    right: `3..5`"
+    )]
+    fn inclusive_touching() {
+        assert_ranges_disjoint!(1..=3, 3..5);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected ranges to be disjoint\n    left: `1..5`,\n    right: `4..8`"
+    )]
+    fn overlapping() {
+        assert_ranges_disjoint!(1..5, 4..8);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected ranges to be disjoint\n    left: `..10`,\n    right: `7..`"
+    )]
+    fn unbounded_overlapping() {
+        assert_ranges_disjoint!(..10, 7..);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected ranges to be disjoint\n    left: `1..=3`,\n    right: `3..5`: foo"
+    )]
+    fn inclusive_touching_custom_message() {
+        assert_ranges_disjoint!(1..=3, 3..5, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_exclusive_touching() {
+        debug_assert_ranges_disjoint!(1..3, 3..5);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected ranges to be disjoint\n    left: `1..=3`,\n    right: `3..5`"
+    )]
+    fn debug_inclusive_touching() {
+        debug_assert_ranges_disjoint!(1..=3, 3..5);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected ranges to be disjoint\n    left: `1..=3`,\n    right: `3..5`: foo"
+    )]
+    fn debug_inclusive_touching_custom_message() {
+        debug_assert_ranges_disjoint!(1..=3, 3..5, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_inclusive_touching() {
+        debug_assert_ranges_disjoint!(1..=3, 3..5);
+    }
+}
@@ -0,0 +1,298 @@
+use core::{
+    fmt,
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+};
+
+/// Asserts that two ranges overlap.
+///
+/// Works with any pair of ranges implementing [`RangeBounds`] over the same type, such as `a..b`,
+/// `a..=b`, `a..`, `..b`, and `..=b`, including unbounded and excluded endpoints. The bounds must
+/// implement [`PartialOrd`] and [`Debug`].
+///
+/// Two ranges overlap if there is a value contained in both of them. Ranges which only touch at an
+/// endpoint overlap if both of them include that endpoint, so `1..=3` overlaps `3..5`, but `1..3`
+/// does not. An empty range, such as `3..3`, never overlaps any other range.
+///
+/// On failure, both ranges are printed using range syntax. An excluded start bound, which has no
+/// range syntax, is printed as `Excluded(start)..`.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ranges_overlap!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_ranges_overlap!(1..5, 4..8);
+/// assert_ranges_overlap!(1..=3, 3..5);
+/// assert_ranges_overlap!(..10, 7..);
+///
+/// // With a custom message
+/// assert_ranges_overlap!(1..5, 4..8, "Expecting the bookings to conflict");
+/// # }
+/// ```
+///
+/// Ranges that only touch at an excluded endpoint will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_ranges_overlap!(1..3, 3..5);  // Will panic
+/// # }
+/// ```
+///
+/// [`RangeBounds`]: https://doc.rust-lang.org/core/ops/trait.RangeBounds.html
+/// [`PartialOrd`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_ranges_overlap!`]: crate::debug_assert_ranges_overlap!
+#[macro_export]
+macro_rules! assert_ranges_overlap {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !$crate::__private::ranges_overlap(left_val, right_val) {
+                    let (left_syntax, right_syntax) = $crate::__private::range_syntax(left_val, right_val);
+                    ::core::panic!(r#"assertion failed, expected ranges to overlap
+    left: `{:?}`,
+    right: `{:?}`"#, left_syntax, right_syntax)
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !$crate::__private::ranges_overlap(left_val, right_val) {
+                    let (left_syntax, right_syntax) = $crate::__private::range_syntax(left_val, right_val);
+                    ::core::panic!(r#"assertion failed, expected ranges to overlap
+    left: `{:?}`,
+    right: `{:?}`: {}"#, left_syntax, right_syntax, ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two ranges overlap on debug builds.
+///
+/// This macro behaves the same as [`assert_ranges_overlap!`] on debug builds. On release builds it
+/// is a no-op.
+#[macro_export]
+macro_rules! debug_assert_ranges_overlap {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_ranges_overlap!($($arg)*);
+        }
+    }};
+}
+
+/// Returns whether a range starting at `start` contains anything before `end`.
+fn starts_before_end<T>(start: Bound<&T>, end: Bound<&T>) -> bool
+where
+    T: PartialOrd + ?Sized,
+{
+    match (start, end) {
+        (Bound::Unbounded, _) | (_, Bound::Unbounded) => true,
+        (Bound::Included(start), Bound::Included(end)) => start <= end,
+        (Bound::Included(start), Bound::Excluded(end))
+        | (Bound::Excluded(start), Bound::Included(end))
+        | (Bound::Excluded(start), Bound::Excluded(end)) => start < end,
+    }
+}
+
+/// Returns whether there is a value contained in both ranges.
+///
+/// This is an implementation detail of [`assert_ranges_overlap!`] and [`assert_ranges_disjoint!`]
+/// and is not part of the public API.
+#[doc(hidden)]
+pub fn ranges_overlap<T, A, B>(left: &A, right: &B) -> bool
+where
+    T: PartialOrd + ?Sized,
+    A: RangeBounds<T> + ?Sized,
+    B: RangeBounds<T> + ?Sized,
+{
+    starts_before_end(left.start_bound(), left.end_bound())
+        && starts_before_end(right.start_bound(), right.end_bound())
+        && starts_before_end(left.start_bound(), right.end_bound())
+        && starts_before_end(right.start_bound(), left.end_bound())
+}
+
+/// Wrapper formatting a range using range syntax through its [`Debug`] implementation.
+///
+/// This is an implementation detail of [`assert_ranges_overlap!`] and [`assert_ranges_disjoint!`]
+/// and is not part of the public API.
+///
+/// [`Debug`]: core::fmt::Debug
+#[doc(hidden)]
+pub struct RangeSyntax<'a, T: ?Sized, R: ?Sized>(&'a R, PhantomData<&'a T>);
+
+impl<T, R> fmt::Debug for RangeSyntax<'_, T, R>
+where
+    T: fmt::Debug + ?Sized,
+    R: RangeBounds<T> + ?Sized,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self.0.start_bound() {
+            Bound::Included(start) => write!(formatter, "{:?}", start)?,
+            Bound::Excluded(start) => write!(formatter, "Excluded({:?})", start)?,
+            Bound::Unbounded => {}
+        }
+        match self.0.end_bound() {
+            Bound::Included(end) => write!(formatter, "..={:?}", end),
+            Bound::Excluded(end) => write!(formatter, "..{:?}", end),
+            Bound::Unbounded => formatter.write_str(".."),
+        }
+    }
+}
+
+/// Wraps a pair of ranges over the same type for formatting using range syntax.
+///
+/// This is an implementation detail of [`assert_ranges_overlap!`] and [`assert_ranges_disjoint!`]
+/// and is not part of the public API.
+#[doc(hidden)]
+pub fn range_syntax<'a, T, A, B>(
+    left: &'a A,
+    right: &'a B,
+) -> (RangeSyntax<'a, T, A>, RangeSyntax<'a, T, B>)
+where
+    T: ?Sized,
+    A: RangeBounds<T> + ?Sized,
+    B: RangeBounds<T> + ?Sized,
+{
+    (
+        RangeSyntax(left, PhantomData),
+        RangeSyntax(right, PhantomData),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::Bound;
+
+    #[test]
+    fn overlapping() {
+        assert_ranges_overlap!(1..5, 4..8);
+    }
+
+    #[test]
+    fn overlapping_reversed() {
+        assert_ranges_overlap!(4..8, 1..5);
+    }
+
+    #[test]
+    fn contained() {
+        assert_ranges_overlap!(1..10, 4..5);
+    }
+
+    #[test]
+    fn inclusive_touching() {
+        assert_ranges_overlap!(1..=3, 3..5);
+    }
+
+    #[test]
+    fn unbounded() {
+        assert_ranges_overlap!(..10, 7..);
+    }
+
+    #[test]
+    fn full() {
+        assert_ranges_overlap!(.., 3..4);
+    }
+
+    #[test]
+    fn floats() {
+        assert_ranges_overlap!(0.0..1.0, 0.5..=2.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected ranges to overlap\n    left: `1..3`,\n    right: `3..5`"
+    )]
+    fn exclusive_touching() {
+        assert_ranges_overlap!(1..3, 3..5);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected ranges to overlap\n    left: `3..=5`,\n    right: `Excluded(5)..=7`"
+    )]
+    fn excluded_start_touching() {
+        assert_ranges_overlap!(3..=5, (Bound::Excluded(5), Bound::Included(7)));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected ranges to overlap\n    left: `1..2`,\n    right: `5..`"
+    )]
+    fn apart() {
+        assert_ranges_overlap!(1..2, 5..);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected ranges to overlap\n    left: `3..3`,\n    right: `0..10`"
+    )]
+    fn empty() {
+        assert_ranges_overlap!(3..3, 0..10);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected ranges to overlap\n    left: `..`,\n    right: `5..=4`"
+    )]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn empty_inclusive() {
+        assert_ranges_overlap!(.., 5..=4);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected ranges to overlap\n    left: `1..3`,\n    right: `3..5`: foo"
+    )]
+    fn exclusive_touching_custom_message() {
+        assert_ranges_overlap!(1..3, 3..5, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_overlapping() {
+        debug_assert_ranges_overlap!(1..5, 4..8);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected ranges to overlap\n    left: `1..3`,\n    right: `3..5`"
+    )]
+    fn debug_exclusive_touching() {
+        debug_assert_ranges_overlap!(1..3, 3..5);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected ranges to overlap\n    left: `1..3`,\n    right: `3..5`: foo"
+    )]
+    fn debug_exclusive_touching_custom_message() {
+        debug_assert_ranges_overlap!(1..3, 3..5, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_exclusive_touching() {
+        debug_assert_ranges_overlap!(1..3, 3..5);
+    }
+}
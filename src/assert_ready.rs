@@ -79,10 +79,13 @@ macro_rules! assert_ready {
 /// [`Poll::Ready(_)`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
 #[macro_export]
 macro_rules! debug_assert_ready {
-    ($($arg:tt)*) => {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
         #[cfg(debug_assertions)]
-        $crate::assert_ready!($($arg)*);
-    }
+        {
+            $crate::assert_ready!($($arg)*);
+        }
+    }};
 }
 
 #[cfg(test)]
@@ -138,6 +141,22 @@ mod tests {
         debug_assert_ready!(Pending::<()>);
     }
 
+    #[test]
+    fn debug_ready_returns_unit() {
+        let () = debug_assert_ready!(Ready(42));
+    }
+
+    #[test]
+    fn debug_ready_returns_unit_custom_message() {
+        let () = debug_assert_ready!(Ready(42), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_ready_returns_unit() {
+        let () = debug_assert_ready!(Pending::<()>);
+    }
+
     #[test]
     fn does_not_require_ready_to_impl_debug() {
         enum Foo {
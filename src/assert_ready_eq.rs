@@ -48,7 +48,7 @@
 /// # }
 /// ```
 ///
-/// [`Poll::Ready(Ok(T))`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
+/// [`Poll::Ready(T)`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
 /// [`Poll::Pending`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Pending
 /// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
 /// [`debug_assert_ready_eq!`]: crate::debug_assert_ready_eq!
@@ -87,10 +87,13 @@ macro_rules! assert_ready_eq {
 /// [`Poll::Ready(T)`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
 #[macro_export]
 macro_rules! debug_assert_ready_eq {
-    ($($arg:tt)*) => {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
         #[cfg(debug_assertions)]
-        $crate::assert_ready_eq!($($arg)*);
-    }
+        {
+            $crate::assert_ready_eq!($($arg)*);
+        }
+    }};
 }
 
 #[cfg(test)]
@@ -171,4 +174,79 @@ mod tests {
     fn debug_release_not_ready() {
         debug_assert_ready_eq!(Pending::<usize>, 42);
     }
+
+    #[cfg(debug_assertions)]
+    fn debug_only_value() -> usize {
+        42
+    }
+
+    #[test]
+    fn debug_compiled_out_in_release() {
+        // `debug_only_value()` does not exist in release builds, so this only compiles if the
+        // assertion is removed entirely rather than skipped at runtime.
+        debug_assert_ready_eq!(Ready(debug_only_value()), 42);
+    }
+
+    mod shadowed {
+        use core::task::Poll::{Pending, Ready};
+
+        // Local definitions which would be picked up instead of the `core` macros if
+        // `assert_ready_eq!` did not use fully-qualified paths.
+        #[allow(unused_macros)]
+        macro_rules! assert_eq {
+            ($($arg:tt)*) => {
+                ::core::panic!("shadowed `assert_eq!` was used")
+            };
+        }
+
+        #[allow(unused_macros)]
+        macro_rules! panic {
+            ($($arg:tt)*) => {
+                ::core::panic!("shadowed `panic!` was used")
+            };
+        }
+
+        #[test]
+        fn equal() {
+            assert_ready_eq!(Ready(42), 42);
+        }
+
+        #[rustversion::before(1.73)]
+        #[test]
+        #[should_panic(
+            expected = "assertion failed: `(left == right)`\n  left: `42`,\n right: `100`"
+        )]
+        fn not_equal() {
+            assert_ready_eq!(Ready(42), 100);
+        }
+
+        #[rustversion::since(1.73)]
+        #[test]
+        #[should_panic(expected = "assertion `left == right` failed\n  left: 42\n right: 100")]
+        fn not_equal() {
+            assert_ready_eq!(Ready(42), 100);
+        }
+
+        #[test]
+        #[should_panic(expected = "assertion failed, expected Ready(_), got Pending")]
+        fn not_ready() {
+            assert_ready_eq!(Pending::<usize>, 42);
+        }
+    }
+
+    #[test]
+    fn debug_ready_eq_returns_unit() {
+        let () = debug_assert_ready_eq!(Ready(42), 42);
+    }
+
+    #[test]
+    fn debug_ready_eq_returns_unit_custom_message() {
+        let () = debug_assert_ready_eq!(Ready(42), 42, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_ready_eq_returns_unit() {
+        let () = debug_assert_ready_eq!(Pending::<usize>, 42);
+    }
 }
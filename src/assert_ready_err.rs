@@ -1,6 +1,9 @@
 /// Asserts that the expression matches a [`Poll::Ready(Err(_))`] variant, returning the contained
 /// value.
 ///
+/// On failure, a `Poll::Ready(Ok(_))` value is printed with its [`Debug`] implementation if it has
+/// one, and as `<non-Debug>` otherwise.
+///
 /// ## Uses
 ///
 /// Assertions are always checked in both debug and release builds, and cannot be disabled.
@@ -61,6 +64,7 @@
 ///
 /// [`Poll::Ready(Err(_))`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
 /// [`Poll::Pending`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Pending
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
 /// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
 /// [`debug_assert_ready_err!`]: crate::debug_assert_ready_err!
 #[macro_export]
@@ -68,14 +72,22 @@ macro_rules! assert_ready_err {
     ($cond:expr $(,)?) => {
         match $cond {
             ::core::task::Poll::Ready(::core::result::Result::Err(e)) => e,
-            ::core::task::Poll::Ready(::core::result::Result::Ok(t)) => ::core::panic!("assertion failed, expected Ready(Err(_)), got Ready(Ok({:?}))", t),
+            ::core::task::Poll::Ready(::core::result::Result::Ok(t)) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!("assertion failed, expected Ready(Err(_)), got Ready(Ok({:?}))", (&$crate::__private::MaybeDebug(&t)).maybe_debug());
+            }
             ::core::task::Poll::Pending => ::core::panic!("assertion failed, expected Ready(Err(_)), got Pending"),
         }
     };
     ($cond:expr, $($arg:tt)+) => {
         match $cond {
             ::core::task::Poll::Ready(::core::result::Result::Err(e)) => e,
-            ::core::task::Poll::Ready(::core::result::Result::Ok(t)) => ::core::panic!("assertion failed, expected Ready(Err(_)), got Ready(Ok({:?})): {}", t, ::core::format_args!($($arg)+)),
+            ::core::task::Poll::Ready(::core::result::Result::Ok(t)) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!("assertion failed, expected Ready(Err(_)), got Ready(Ok({:?})): {}", (&$crate::__private::MaybeDebug(&t)).maybe_debug(), ::core::format_args!($($arg)+));
+            }
             ::core::task::Poll::Pending => ::core::panic!("assertion failed, expected Ready(Err(_)), got Pending: {}", ::core::format_args!($($arg)+)),
         }
     };
@@ -89,10 +101,13 @@ macro_rules! assert_ready_err {
 /// [`Poll::Ready(Err(_))`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
 #[macro_export]
 macro_rules! debug_assert_ready_err {
-    ($($arg:tt)*) => {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
         #[cfg(debug_assertions)]
-        $crate::assert_ready_err!($($arg)*);
-    }
+        {
+            $crate::assert_ready_err!($($arg)*);
+        }
+    }};
 }
 
 #[cfg(test)]
@@ -180,6 +195,22 @@ mod tests {
         debug_assert_ready_err!(Pending::<Result<(), ()>>);
     }
 
+    #[test]
+    fn debug_ready_err_returns_unit() {
+        let () = debug_assert_ready_err!(Ready(Err::<(), _>(42)));
+    }
+
+    #[test]
+    fn debug_ready_err_returns_unit_custom_message() {
+        let () = debug_assert_ready_err!(Ready(Err::<(), _>(42)), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_ready_err_returns_unit() {
+        let () = debug_assert_ready_err!(Pending::<Result<(), ()>>);
+    }
+
     #[test]
     fn does_not_require_err_to_impl_debug() {
         enum Foo {
@@ -217,4 +248,67 @@ mod tests {
 
         debug_assert_ready_err!(Ready(Err::<(), _>(Foo::Bar)), "foo");
     }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ready(Err(_)), got Ready(Ok(<non-Debug>))"
+    )]
+    fn does_not_require_ok_to_impl_debug() {
+        enum Foo {
+            Bar,
+        }
+
+        assert_ready_err!(Ready(Ok::<_, ()>(Foo::Bar)));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Ready(Err(_)), got Ready(Ok(<non-Debug>))"
+    )]
+    fn debug_does_not_require_ok_to_impl_debug() {
+        #[allow(dead_code)]
+        enum Foo {
+            Bar,
+        }
+
+        debug_assert_ready_err!(Ready(Ok::<_, ()>(Foo::Bar)));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ready(Err(_)), got Ready(Ok(<non-Debug>)): foo"
+    )]
+    fn does_not_require_ok_to_impl_debug_custom_message() {
+        enum Foo {
+            Bar,
+        }
+
+        assert_ready_err!(Ready(Ok::<_, ()>(Foo::Bar)), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Ready(Err(_)), got Ready(Ok(<non-Debug>)): foo"
+    )]
+    fn debug_does_not_require_ok_to_impl_debug_custom_message() {
+        #[allow(dead_code)]
+        enum Foo {
+            Bar,
+        }
+
+        debug_assert_ready_err!(Ready(Ok::<_, ()>(Foo::Bar)), "foo");
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Ready(Err(_)), got Pending")]
+    fn pending_does_not_require_debug() {
+        #[allow(dead_code)]
+        enum Foo {
+            Bar,
+        }
+
+        assert_ready_err!(Pending::<Result<Foo, Foo>>);
+    }
 }
@@ -58,7 +58,7 @@
 /// # }
 /// ```
 ///
-/// [`Poll::Ready(Ok(T))`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
+/// [`Poll::Ready(Ok(_))`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
 /// [`Poll::Pending`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Pending
 /// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
 /// [`debug_assert_ready_ok!`]: crate::debug_assert_ready_ok
@@ -88,10 +88,13 @@ macro_rules! assert_ready_ok {
 /// [`Poll::Ready(Ok(_))`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
 #[macro_export]
 macro_rules! debug_assert_ready_ok {
-    ($($arg:tt)*) => {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
         #[cfg(debug_assertions)]
-        $crate::assert_ready_ok!($($arg)*);
-    }
+        {
+            $crate::assert_ready_ok!($($arg)*);
+        }
+    }};
 }
 
 #[cfg(test)]
@@ -179,6 +182,22 @@ mod tests {
         debug_assert_ready_ok!(Pending::<Result<(), ()>>);
     }
 
+    #[test]
+    fn debug_ready_ok_returns_unit() {
+        let () = debug_assert_ready_ok!(Ready(Ok::<_, ()>(42)));
+    }
+
+    #[test]
+    fn debug_ready_ok_returns_unit_custom_message() {
+        let () = debug_assert_ready_ok!(Ready(Ok::<_, ()>(42)), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_ready_ok_returns_unit() {
+        let () = debug_assert_ready_ok!(Pending::<Result<(), ()>>);
+    }
+
     #[test]
     fn does_not_require_ok_to_impl_debug() {
         enum Foo {
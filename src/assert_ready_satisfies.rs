@@ -0,0 +1,277 @@
+/// Asserts that the expression matches a [`Poll::Ready(_)`] variant whose contained value satisfies
+/// the predicate, returning the contained value.
+///
+/// The predicate is called with a reference to the contained value, and can either be a closure,
+/// such as `|v| v.len() > 3`, or a named binding followed by an expression, such as
+/// `v => v.len() > 3`. This keeps the context of the `Poll` in the failure message, which is lost
+/// when writing `let v = assert_ready!(poll); assert!(predicate(&v));`.
+///
+/// A [`Poll::Pending`] variant and a value that does not satisfy the predicate panic with distinct
+/// messages. If the value does not satisfy the predicate, the stringified predicate and the value
+/// are printed. The value is printed with its [`Debug`] implementation if it has one, and as
+/// `<non-Debug>` otherwise.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ready_satisfies!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let poll = Poll::Ready("foobar");
+///
+/// assert_ready_satisfies!(poll, |v| v.starts_with("foo"));
+/// assert_ready_satisfies!(poll, v => v.len() == 6);
+///
+/// // With a custom message
+/// assert_ready_satisfies!(poll, |v| v.starts_with("foo"), "Expecting a foo");
+/// # }
+/// ```
+///
+/// The contained value will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let poll = Poll::Ready(42);
+///
+/// let value = assert_ready_satisfies!(poll, |v| *v > 0);
+/// assert_eq!(value, 42);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let poll = Poll::Ready(-1);
+///
+/// assert_ready_satisfies!(poll, |v| *v > 0);  // Will panic
+/// # }
+/// ```
+///
+/// [`Poll::Ready(_)`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
+/// [`Poll::Pending`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Pending
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_ready_satisfies!`]: crate::debug_assert_ready_satisfies!
+#[macro_export]
+macro_rules! assert_ready_satisfies {
+    ($cond:expr, $binding:ident => $predicate:expr $(,)?) => {
+        match $cond {
+            ::core::task::Poll::Ready(t) => {
+                if !$crate::__private::satisfies(&t, |$binding| $predicate) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected Ready(_) to satisfy predicate
+    predicate: `{} => {}`,
+    value: `{:?}`"#, ::core::stringify!($binding), ::core::stringify!($predicate), (&$crate::__private::MaybeDebug(&t)).maybe_debug());
+                }
+                t
+            }
+            ::core::task::Poll::Pending => {
+                ::core::panic!(r#"assertion failed, expected Ready(_), got Pending
+    expression: `{}`"#, ::core::stringify!($cond));
+            }
+        }
+    };
+    ($cond:expr, $binding:ident => $predicate:expr, $($arg:tt)+) => {
+        match $cond {
+            ::core::task::Poll::Ready(t) => {
+                if !$crate::__private::satisfies(&t, |$binding| $predicate) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected Ready(_) to satisfy predicate
+    predicate: `{} => {}`,
+    value: `{:?}`: {}"#, ::core::stringify!($binding), ::core::stringify!($predicate), (&$crate::__private::MaybeDebug(&t)).maybe_debug(), ::core::format_args!($($arg)+));
+                }
+                t
+            }
+            ::core::task::Poll::Pending => {
+                ::core::panic!(r#"assertion failed, expected Ready(_), got Pending
+    expression: `{}`: {}"#, ::core::stringify!($cond), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+    ($cond:expr, $predicate:expr $(,)?) => {
+        match $cond {
+            ::core::task::Poll::Ready(t) => {
+                if !$crate::__private::satisfies(&t, $predicate) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected Ready(_) to satisfy predicate
+    predicate: `{}`,
+    value: `{:?}`"#, ::core::stringify!($predicate), (&$crate::__private::MaybeDebug(&t)).maybe_debug());
+                }
+                t
+            }
+            ::core::task::Poll::Pending => {
+                ::core::panic!(r#"assertion failed, expected Ready(_), got Pending
+    expression: `{}`"#, ::core::stringify!($cond));
+            }
+        }
+    };
+    ($cond:expr, $predicate:expr, $($arg:tt)+) => {
+        match $cond {
+            ::core::task::Poll::Ready(t) => {
+                if !$crate::__private::satisfies(&t, $predicate) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected Ready(_) to satisfy predicate
+    predicate: `{}`,
+    value: `{:?}`: {}"#, ::core::stringify!($predicate), (&$crate::__private::MaybeDebug(&t)).maybe_debug(), ::core::format_args!($($arg)+));
+                }
+                t
+            }
+            ::core::task::Poll::Pending => {
+                ::core::panic!(r#"assertion failed, expected Ready(_), got Pending
+    expression: `{}`: {}"#, ::core::stringify!($cond), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that the expression matches a [`Poll::Ready(_)`] variant whose contained value satisfies
+/// the predicate on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_ready_satisfies!`] on debug builds, although it
+/// does not return the value contained in the `Ready` variant. On release builds it is a no-op.
+///
+/// [`Poll::Ready(_)`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
+#[macro_export]
+macro_rules! debug_assert_ready_satisfies {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_ready_satisfies!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use core::task::Poll;
+    use std::{string::String, vec, vec::Vec};
+
+    #[test]
+    fn ready_satisfies() {
+        assert_ready_satisfies!(Poll::Ready(42), |v| *v > 0);
+    }
+
+    #[test]
+    fn ready_satisfies_binding() {
+        assert_ready_satisfies!(Poll::Ready(42), v => *v > 0);
+    }
+
+    #[test]
+    fn ready_satisfies_method_call() {
+        let poll: Poll<Vec<u32>> = Poll::Ready(vec![1, 2, 3]);
+        assert_ready_satisfies!(poll, |v| v.len() == 3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ready(_) to satisfy predicate\n    predicate: `|v| *v > 0`,\n    value: `-1`"
+    )]
+    fn ready_not_satisfies() {
+        assert_ready_satisfies!(Poll::Ready(-1), |v| *v > 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ready(_) to satisfy predicate\n    predicate: `v => *v > 0`,\n    value: `-1`"
+    )]
+    fn ready_not_satisfies_binding() {
+        assert_ready_satisfies!(Poll::Ready(-1), v => *v > 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ready(_) to satisfy predicate\n    predicate: `|_| false`,\n    value: `<non-Debug>`"
+    )]
+    fn ready_not_satisfies_non_debug() {
+        struct NonDebug;
+
+        assert_ready_satisfies!(Poll::Ready(NonDebug), |_| false);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ready(_) to satisfy predicate\n    predicate: `|v| *v > 0`,\n    value: `-1`: foo"
+    )]
+    fn ready_not_satisfies_custom_message() {
+        assert_ready_satisfies!(Poll::Ready(-1), |v| *v > 0, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ready(_), got Pending\n    expression: `poll`"
+    )]
+    fn pending() {
+        let poll: Poll<i32> = Poll::Pending;
+        assert_ready_satisfies!(poll, |v| *v > 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ready(_), got Pending\n    expression: `poll`: foo"
+    )]
+    fn pending_custom_message() {
+        let poll: Poll<i32> = Poll::Pending;
+        assert_ready_satisfies!(poll, v => *v > 0, "foo");
+    }
+
+    #[test]
+    fn value_returned() {
+        let poll = Poll::Ready(String::from("foo"));
+        let value = assert_ready_satisfies!(poll, |v| v.starts_with('f'));
+        assert_eq!(value, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_ready_satisfies() {
+        debug_assert_ready_satisfies!(Poll::Ready(42), |v| *v > 0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Ready(_) to satisfy predicate\n    predicate: `|v| *v > 0`,\n    value: `-1`"
+    )]
+    fn debug_ready_not_satisfies() {
+        debug_assert_ready_satisfies!(Poll::Ready(-1), |v| *v > 0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Ready(_) to satisfy predicate\n    predicate: `|v| *v > 0`,\n    value: `-1`: foo"
+    )]
+    fn debug_ready_not_satisfies_custom_message() {
+        debug_assert_ready_satisfies!(Poll::Ready(-1), |v| *v > 0, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_ready_not_satisfies() {
+        debug_assert_ready_satisfies!(Poll::Ready(-1), |v| *v > 0);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_ready_satisfies!(Poll::Ready(42), |v| *v > 0);
+    }
+}
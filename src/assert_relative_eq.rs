@@ -0,0 +1,342 @@
+/// Asserts that the relative error between two expressions is no greater than a maximum.
+///
+/// The relative error is computed as `|left - right| / |right|`, meaning the right expression is
+/// treated as the expected value. If the right expression is zero, a relative error is not
+/// meaningful, so the absolute difference `|left - right|` is compared against the maximum
+/// instead. Values which are exactly equal (including equal infinities) always pass.
+///
+/// Works with [`f32`] and [`f64`]. The maximum relative error can be provided using the
+/// `max_relative = ...` argument. If it is omitted, it defaults to [`f32::EPSILON`] or
+/// [`f64::EPSILON`].
+///
+/// `NaN` is never considered equal to anything, including another `NaN`. If either side is `NaN`,
+/// the panic message will point that out explicitly.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_relative_eq!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_relative_eq!(1.0e12 + 1.0, 1.0e12, max_relative = 1e-6);
+/// assert_relative_eq!(1.0e-6 + 1.0e-15, 1.0e-6, max_relative = 1e-6);
+///
+/// // The right expression is zero, so the absolute difference is compared instead.
+/// assert_relative_eq!(1.0e-9, 0.0, max_relative = 1e-6);
+///
+/// // With a custom message
+/// assert_relative_eq!(1.0e12 + 1.0, 1.0e12, max_relative = 1e-6, "Expecting {} to be close to {}", 1.0e12 + 1.0, 1.0e12);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_relative_eq!(1.1, 1.0, max_relative = 1e-6);  // Will panic
+/// # }
+/// ```
+///
+/// [`f32`]: https://doc.rust-lang.org/core/primitive.f32.html
+/// [`f64`]: https://doc.rust-lang.org/core/primitive.f64.html
+/// [`f32::EPSILON`]: https://doc.rust-lang.org/core/primitive.f32.html#associatedconstant.EPSILON
+/// [`f64::EPSILON`]: https://doc.rust-lang.org/core/primitive.f64.html#associatedconstant.EPSILON
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_relative_eq!`]: crate::debug_assert_relative_eq!
+#[macro_export]
+macro_rules! assert_relative_eq {
+    ($left:expr, $right:expr, max_relative = $max_relative:expr $(,)?) => {
+        match (&$left, &$right, &$max_relative) {
+            (left_val, right_val, max_relative_val) => {
+                if !$crate::__private::RelativeEq::relative_eq(left_val, right_val, max_relative_val) {
+                    ::core::panic!(r#"assertion failed: `(|left - right| / |right| <= max_relative)`
+    left: `{:?}`,
+    right: `{:?}`,
+    relative error: `{:?}`,
+    max_relative: `{:?}`{}"#,
+                        &*left_val,
+                        &*right_val,
+                        $crate::__private::RelativeEq::relative_error(left_val, right_val),
+                        &*max_relative_val,
+                        $crate::__private::RelativeEq::note(left_val, right_val),
+                    )
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, max_relative = $max_relative:expr, $($arg:tt)+) => {
+        match (&$left, &$right, &$max_relative) {
+            (left_val, right_val, max_relative_val) => {
+                if !$crate::__private::RelativeEq::relative_eq(left_val, right_val, max_relative_val) {
+                    ::core::panic!(r#"assertion failed: `(|left - right| / |right| <= max_relative)`
+    left: `{:?}`,
+    right: `{:?}`,
+    relative error: `{:?}`,
+    max_relative: `{:?}`{}: {}"#,
+                        &*left_val,
+                        &*right_val,
+                        $crate::__private::RelativeEq::relative_error(left_val, right_val),
+                        &*max_relative_val,
+                        $crate::__private::RelativeEq::note(left_val, right_val),
+                        ::core::format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                $crate::assert_relative_eq!(
+                    *left_val,
+                    *right_val,
+                    max_relative = $crate::__private::RelativeEq::default_max_relative(left_val)
+                )
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                $crate::assert_relative_eq!(
+                    *left_val,
+                    *right_val,
+                    max_relative = $crate::__private::RelativeEq::default_max_relative(left_val),
+                    $($arg)+
+                )
+            }
+        }
+    };
+}
+
+/// Asserts that the relative error between two expressions is no greater than a maximum on debug
+/// builds.
+///
+/// This macro behaves the same as [`assert_relative_eq!`] on debug builds. On release builds it is
+/// a no-op.
+#[macro_export]
+macro_rules! debug_assert_relative_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_relative_eq!($($arg)*);
+        }
+    }};
+}
+
+/// Types which can be compared using a relative error.
+///
+/// This is an implementation detail of [`assert_relative_eq!`] and is not part of the public API.
+#[doc(hidden)]
+pub trait RelativeEq {
+    fn default_max_relative(&self) -> Self;
+
+    fn relative_error(&self, other: &Self) -> Self;
+
+    fn relative_eq(&self, other: &Self, max_relative: &Self) -> bool;
+
+    fn note(&self, other: &Self) -> &'static str;
+}
+
+macro_rules! impl_relative_eq {
+    ($($float:ident)*) => {
+        $(
+            impl RelativeEq for $float {
+                fn default_max_relative(&self) -> $float {
+                    ::core::$float::EPSILON
+                }
+
+                fn relative_error(&self, other: &$float) -> $float {
+                    // `abs()` is not available in `core`.
+                    let difference = if *self > *other {
+                        *self - *other
+                    } else {
+                        *other - *self
+                    };
+                    if *other == 0.0 {
+                        difference
+                    } else if *other < 0.0 {
+                        difference / -*other
+                    } else {
+                        difference / *other
+                    }
+                }
+
+                fn relative_eq(&self, other: &$float, max_relative: &$float) -> bool {
+                    *self == *other || self.relative_error(other) <= *max_relative
+                }
+
+                fn note(&self, other: &$float) -> &'static str {
+                    match (self.is_nan(), other.is_nan()) {
+                        (true, true) => ",\n    note: left and right are NaN",
+                        (true, false) => ",\n    note: left is NaN",
+                        (false, true) => ",\n    note: right is NaN",
+                        (false, false) => if *other == 0.0 {
+                            ",\n    note: right is zero, so the absolute difference was used"
+                        } else {
+                            ""
+                        },
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_relative_eq! {f32 f64}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn equal() {
+        assert_relative_eq!(1.0, 1.0);
+    }
+
+    #[test]
+    fn within_default_max_relative() {
+        assert_relative_eq!(1.0e12 + 1.0e-4, 1.0e12);
+    }
+
+    #[test]
+    fn within_max_relative_large() {
+        assert_relative_eq!(1.0e12 + 1.0, 1.0e12, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn within_max_relative_small() {
+        assert_relative_eq!(1.0e-6 + 1.0e-15, 1.0e-6, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn within_max_relative_negative() {
+        assert_relative_eq!(-1.0e12 - 1.0, -1.0e12, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn within_max_relative_f32() {
+        assert_relative_eq!(1000.001f32, 1000.0, max_relative = 1e-5);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|left - right| / |right| <= max_relative)`\n    left: `3.0`,\n    right: `2.0`,\n    relative error: `0.5`,\n    max_relative: `0.25`"
+    )]
+    fn not_within_max_relative() {
+        assert_relative_eq!(3.0, 2.0, max_relative = 0.25);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|left - right| / |right| <= max_relative)`\n    left: `3.0`,\n    right: `2.0`,\n    relative error: `0.5`,\n    max_relative: `0.25`: foo"
+    )]
+    fn not_within_max_relative_custom_message() {
+        assert_relative_eq!(3.0, 2.0, max_relative = 0.25, "foo");
+    }
+
+    #[rustversion::since(1.58)]
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|left - right| / |right| <= max_relative)`\n    left: `3.0`,\n    right: `2.0`,\n    relative error: `0.5`,\n    max_relative: `2.220446049250313e-16`: foo"
+    )]
+    fn not_within_default_max_relative_custom_message() {
+        assert_relative_eq!(3.0, 2.0, "foo");
+    }
+
+    #[test]
+    fn right_zero_uses_absolute_difference() {
+        assert_relative_eq!(1.0e-9, 0.0, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn both_zero() {
+        assert_relative_eq!(0.0, -0.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|left - right| / |right| <= max_relative)`\n    left: `0.5`,\n    right: `0.0`,\n    relative error: `0.5`,\n    max_relative: `0.25`,\n    note: right is zero, so the absolute difference was used"
+    )]
+    fn right_zero_not_within_absolute_difference() {
+        assert_relative_eq!(0.5, 0.0, max_relative = 0.25);
+    }
+
+    #[test]
+    fn equal_infinities() {
+        assert_relative_eq!(core::f64::INFINITY, core::f64::INFINITY);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|left - right| / |right| <= max_relative)`\n    left: `NaN`,\n    right: `1.0`,\n    relative error: `NaN`,\n    max_relative: `1.0`,\n    note: left is NaN"
+    )]
+    fn left_nan() {
+        assert_relative_eq!(core::f64::NAN, 1.0, max_relative = 1.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|left - right| / |right| <= max_relative)`\n    left: `1.0`,\n    right: `NaN`,\n    relative error: `NaN`,\n    max_relative: `1.0`,\n    note: right is NaN"
+    )]
+    fn right_nan() {
+        assert_relative_eq!(1.0, core::f64::NAN, max_relative = 1.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|left - right| / |right| <= max_relative)`\n    left: `NaN`,\n    right: `NaN`,\n    relative error: `NaN`,\n    max_relative: `1.0`,\n    note: left and right are NaN"
+    )]
+    fn both_nan() {
+        assert_relative_eq!(core::f32::NAN, core::f32::NAN, max_relative = 1.0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_within_max_relative() {
+        debug_assert_relative_eq!(1.0e12 + 1.0, 1.0e12, max_relative = 1e-6);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(|left - right| / |right| <= max_relative)`\n    left: `3.0`,\n    right: `2.0`,\n    relative error: `0.5`,\n    max_relative: `0.25`"
+    )]
+    fn debug_not_within_max_relative() {
+        debug_assert_relative_eq!(3.0, 2.0, max_relative = 0.25);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(|left - right| / |right| <= max_relative)`\n    left: `3.0`,\n    right: `2.0`,\n    relative error: `0.5`,\n    max_relative: `0.25`: foo"
+    )]
+    fn debug_not_within_max_relative_custom_message() {
+        debug_assert_relative_eq!(3.0, 2.0, max_relative = 0.25, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_within_max_relative() {
+        debug_assert_relative_eq!(3.0, 2.0, max_relative = 0.25);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_relative_eq!(1.0, 1.0);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_returns_unit() {
+        let () = debug_assert_relative_eq!(1.0, 2.0);
+    }
+}
@@ -0,0 +1,208 @@
+/// Asserts that two collections contain the same elements the same number of times, ignoring
+/// order.
+///
+/// Both expressions can be anything implementing [`IntoIterator`], as long as they produce the
+/// same element type. To compare a `Vec` against an array, for example, pass both by reference.
+/// Unlike [`assert_set_eq!`], duplicates are significant: `[1, 1, 2]` and `[1, 2, 2]` are not
+/// considered equal. This makes it the right tool for checking that the same jobs ran, in any
+/// order, with none duplicated or dropped.
+///
+/// Occurrences are counted in a [`HashMap`] when the elements implement [`Hash`] and [`Eq`].
+/// Elements that only implement [`Ord`] are counted by sorting instead. Either way, the elements
+/// must also implement [`Debug`].
+///
+/// On failure, each element whose count differs is printed along with its count in the actual and
+/// the expected collection.
+///
+/// This macro requires the `std` feature.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_same_elements!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let completed = vec!["build", "test", "lint", "test"];
+///
+/// assert_same_elements!(&completed, &["test", "lint", "test", "build"]);
+///
+/// // With a custom message
+/// assert_same_elements!(&completed, &["test", "lint", "test", "build"], "Expecting every job");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_same_elements!(vec![1, 1, 2], vec![1, 2, 2]);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+/// [`Hash`]: https://doc.rust-lang.org/core/hash/trait.Hash.html
+/// [`Eq`]: https://doc.rust-lang.org/core/cmp/trait.Eq.html
+/// [`Ord`]: https://doc.rust-lang.org/core/cmp/trait.Ord.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_set_eq!`]: crate::assert_set_eq!
+/// [`debug_assert_same_elements!`]: crate::debug_assert_same_elements!
+#[macro_export]
+macro_rules! assert_same_elements {
+    ($actual:expr, $expected:expr $(,)?) => {
+        match ($crate::__private::collect($actual), $crate::__private::collect($expected)) {
+            (actual_val, expected_val) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{HashElements as _, OrdElements as _};
+                let differences = (&$crate::__private::Elements(&actual_val, &expected_val)).differences();
+                if !differences.is_empty() {
+                    ::core::panic!(r#"assertion failed, expected the same elements
+    differences: `{:?}`"#, differences)
+                }
+            }
+        }
+    };
+    ($actual:expr, $expected:expr, $($arg:tt)+) => {
+        match ($crate::__private::collect($actual), $crate::__private::collect($expected)) {
+            (actual_val, expected_val) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{HashElements as _, OrdElements as _};
+                let differences = (&$crate::__private::Elements(&actual_val, &expected_val)).differences();
+                if !differences.is_empty() {
+                    ::core::panic!(r#"assertion failed, expected the same elements
+    differences: `{:?}`: {}"#, differences, ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two collections contain the same elements the same number of times, ignoring
+/// order, on debug builds.
+///
+/// This macro behaves the same as [`assert_same_elements!`] on debug builds. On release builds it
+/// is a no-op.
+///
+/// This macro requires the `std` feature.
+#[macro_export]
+macro_rules! debug_assert_same_elements {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_same_elements!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{vec, vec::Vec};
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Job(u32);
+
+    #[test]
+    fn same_elements() {
+        assert_same_elements!(&vec![1, 2, 2, 3], &[2, 3, 2, 1]);
+    }
+
+    #[test]
+    fn same_elements_empty() {
+        let empty: Vec<u32> = Vec::new();
+        assert_same_elements!(&empty, &empty);
+    }
+
+    #[test]
+    fn same_elements_ord_only() {
+        assert_same_elements!(vec![Job(2), Job(1), Job(2)], vec![Job(1), Job(2), Job(2)]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected the same elements\n    differences: `[4 (actual: 1, expected: 0)]`"
+    )]
+    fn extra_element() {
+        assert_same_elements!(vec![1, 4, 2], vec![1, 2]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected the same elements\n    differences: `[5 (actual: 0, expected: 1)]`"
+    )]
+    fn missing_element() {
+        assert_same_elements!(vec![1, 2], vec![5, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected the same elements\n    differences: `[1 (actual: 2, expected: 1), 2 (actual: 1, expected: 2)]`"
+    )]
+    fn miscounted_elements() {
+        assert_same_elements!(vec![1, 1, 2], vec![1, 2, 2]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected the same elements\n    differences: `[3 (actual: 1, expected: 0), 4 (actual: 0, expected: 2)]`"
+    )]
+    fn differences_in_order_of_appearance() {
+        assert_same_elements!(vec![3, 1], vec![1, 4, 4]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected the same elements\n    differences: `[Job(1) (actual: 0, expected: 1), Job(2) (actual: 2, expected: 1), Job(3) (actual: 1, expected: 0)]`"
+    )]
+    fn miscounted_elements_ord_only() {
+        assert_same_elements!(vec![Job(3), Job(2), Job(2)], vec![Job(2), Job(1)]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected the same elements\n    differences: `[1 (actual: 2, expected: 1), 2 (actual: 1, expected: 2)]`: foo"
+    )]
+    fn miscounted_elements_custom_message() {
+        assert_same_elements!(vec![1, 1, 2], vec![1, 2, 2], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_same_elements() {
+        debug_assert_same_elements!(vec![1, 2, 2], vec![2, 1, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected the same elements\n    differences: `[1 (actual: 2, expected: 1), 2 (actual: 1, expected: 2)]`"
+    )]
+    fn debug_miscounted_elements() {
+        debug_assert_same_elements!(vec![1, 1, 2], vec![1, 2, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected the same elements\n    differences: `[1 (actual: 2, expected: 1), 2 (actual: 1, expected: 2)]`: foo"
+    )]
+    fn debug_miscounted_elements_custom_message() {
+        debug_assert_same_elements!(vec![1, 1, 2], vec![1, 2, 2], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_miscounted_elements() {
+        debug_assert_same_elements!(vec![1, 1, 2], vec![1, 2, 2]);
+    }
+}
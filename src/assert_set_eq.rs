@@ -0,0 +1,224 @@
+/// Asserts that two collections contain the same elements, ignoring order and duplicates.
+///
+/// Both expressions can be anything implementing [`IntoIterator`], as long as they produce the
+/// same element type. To compare a `Vec` against an array, for example, pass both by reference.
+/// Each side is treated as a set, so `[1, 2, 2]` and `[2, 1]` are considered equal. Use
+/// [`assert_same_elements!`] when the number of occurrences also matters.
+///
+/// Elements are looked up in a [`HashSet`] when they implement [`Hash`] and [`Eq`]. Elements that
+/// only implement [`Ord`] are looked up in a [`BTreeSet`] instead. Either way, the elements must
+/// also implement [`Debug`].
+///
+/// On failure, rather than printing both collections in full, the elements found only in the
+/// actual collection and the elements found only in the expected collection are printed, each in
+/// the order they first appear.
+///
+/// This macro requires the `std` feature.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_set_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # use std::collections::HashMap;
+/// # fn main() {
+/// let mut scores = HashMap::new();
+/// scores.insert("alice", 3);
+/// scores.insert("bob", 5);
+///
+/// assert_set_eq!(scores.keys(), &["bob", "alice"]);
+///
+/// // With a custom message
+/// assert_set_eq!(scores.keys(), &["bob", "alice"], "Expecting {} players", 2);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_set_eq!(&vec![1, 2, 3], &[1, 2, 4]);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`HashSet`]: https://doc.rust-lang.org/std/collections/struct.HashSet.html
+/// [`BTreeSet`]: https://doc.rust-lang.org/std/collections/struct.BTreeSet.html
+/// [`Hash`]: https://doc.rust-lang.org/core/hash/trait.Hash.html
+/// [`Eq`]: https://doc.rust-lang.org/core/cmp/trait.Eq.html
+/// [`Ord`]: https://doc.rust-lang.org/core/cmp/trait.Ord.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_same_elements!`]: crate::assert_same_elements!
+/// [`debug_assert_set_eq!`]: crate::debug_assert_set_eq!
+#[macro_export]
+macro_rules! assert_set_eq {
+    ($actual:expr, $expected:expr $(,)?) => {
+        match ($crate::__private::collect($actual), $crate::__private::collect($expected)) {
+            (actual_val, expected_val) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{HashElements as _, OrdElements as _};
+                let only_actual = (&$crate::__private::Elements(&actual_val, &expected_val)).not_contained();
+                let only_expected = (&$crate::__private::Elements(&expected_val, &actual_val)).not_contained();
+                if !only_actual.is_empty() || !only_expected.is_empty() {
+                    ::core::panic!(r#"assertion failed, expected sets to be equal
+    only in actual: `{:?}`,
+    only in expected: `{:?}`"#, only_actual, only_expected)
+                }
+            }
+        }
+    };
+    ($actual:expr, $expected:expr, $($arg:tt)+) => {
+        match ($crate::__private::collect($actual), $crate::__private::collect($expected)) {
+            (actual_val, expected_val) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{HashElements as _, OrdElements as _};
+                let only_actual = (&$crate::__private::Elements(&actual_val, &expected_val)).not_contained();
+                let only_expected = (&$crate::__private::Elements(&expected_val, &actual_val)).not_contained();
+                if !only_actual.is_empty() || !only_expected.is_empty() {
+                    ::core::panic!(r#"assertion failed, expected sets to be equal
+    only in actual: `{:?}`,
+    only in expected: `{:?}`: {}"#, only_actual, only_expected, ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two collections contain the same elements, ignoring order and duplicates, on debug
+/// builds.
+///
+/// This macro behaves the same as [`assert_set_eq!`] on debug builds. On release builds it is a
+/// no-op.
+///
+/// This macro requires the `std` feature.
+#[macro_export]
+macro_rules! debug_assert_set_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_set_eq!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, vec, vec::Vec};
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Scope(u32);
+
+    #[test]
+    fn equal() {
+        assert_set_eq!(&vec![1, 2, 3], &[1, 2, 3]);
+    }
+
+    #[test]
+    fn equal_different_order() {
+        assert_set_eq!(&vec![3, 1, 2], &[1, 2, 3]);
+    }
+
+    #[test]
+    fn equal_ignores_duplicates() {
+        assert_set_eq!(vec![1, 1, 2], vec![2, 1, 2, 2]);
+    }
+
+    #[test]
+    fn equal_empty() {
+        let empty: Vec<u32> = Vec::new();
+        assert_set_eq!(empty.clone(), empty);
+    }
+
+    #[test]
+    fn equal_map_keys() {
+        let mut map = HashMap::new();
+        map.insert("foo", 1);
+        map.insert("bar", 2);
+        assert_set_eq!(map.keys(), &["bar", "foo"]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected sets to be equal\n    only in actual: `[3]`,\n    only in expected: `[4]`"
+    )]
+    fn not_equal() {
+        assert_set_eq!(&vec![1, 2, 3], &[1, 2, 4]);
+    }
+
+    #[test]
+    fn equal_ord_only() {
+        assert_set_eq!(vec![Scope(2), Scope(1), Scope(2)], vec![Scope(1), Scope(2)]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected sets to be equal\n    only in actual: `[Scope(3)]`,\n    only in expected: `[Scope(4)]`"
+    )]
+    fn not_equal_ord_only() {
+        assert_set_eq!(vec![Scope(1), Scope(3)], vec![Scope(4), Scope(1)]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected sets to be equal\n    only in actual: `[4, 3]`,\n    only in expected: `[]`"
+    )]
+    fn extra_elements() {
+        assert_set_eq!(vec![4, 1, 3, 4, 2, 3], vec![1, 2]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected sets to be equal\n    only in actual: `[]`,\n    only in expected: `[5, 6]`"
+    )]
+    fn missing_elements() {
+        assert_set_eq!(vec![1, 2], vec![5, 1, 6, 2, 5]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected sets to be equal\n    only in actual: `[3]`,\n    only in expected: `[4]`: foo"
+    )]
+    fn not_equal_custom_message() {
+        assert_set_eq!(&vec![1, 2, 3], &[1, 2, 4], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_equal() {
+        debug_assert_set_eq!(&vec![3, 1, 2], &[1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected sets to be equal\n    only in actual: `[3]`,\n    only in expected: `[4]`"
+    )]
+    fn debug_not_equal() {
+        debug_assert_set_eq!(&vec![1, 2, 3], &[1, 2, 4]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected sets to be equal\n    only in actual: `[3]`,\n    only in expected: `[4]`: foo"
+    )]
+    fn debug_not_equal_custom_message() {
+        debug_assert_set_eq!(&vec![1, 2, 3], &[1, 2, 4], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_equal() {
+        debug_assert_set_eq!(&vec![1, 2, 3], &[1, 2, 4]);
+    }
+}
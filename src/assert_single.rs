@@ -0,0 +1,329 @@
+use core::fmt;
+
+/// The number of elements shown in a preview.
+const PREVIEW_LEN: usize = 3;
+
+/// Asserts that a collection or iterator contains exactly one element, returning that element by
+/// value.
+///
+/// The expression can be anything implementing [`IntoIterator`], and is consumed. This fits the
+/// common case of a query that should have matched exactly one row:
+/// `let row = assert_single!(results);`.
+///
+/// When there are no elements, the panic message states that none were found. When there are two
+/// or more, the number of elements is printed along with the first few of them. The elements are
+/// printed with their [`Debug`] implementation if they have one, and as `<non-Debug>` otherwise,
+/// so `Debug` is never required.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_single!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let rows = vec![(1, "alice"), (2, "bob")];
+///
+/// let row = assert_single!(rows.iter().filter(|(id, _)| *id == 2));
+/// assert_eq!(row.1, "bob");
+///
+/// // With a custom message
+/// assert_single!(rows.iter().filter(|(_, name)| name.starts_with('a')), "Expecting one match");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_single!(vec![1, 2, 3]);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_single!`]: crate::debug_assert_single!
+#[macro_export]
+macro_rules! assert_single {
+    ($iter:expr $(,)?) => {
+        match $crate::__private::single($iter) {
+            ::core::result::Result::Ok(element) => element,
+            ::core::result::Result::Err(::core::option::Option::None) => {
+                ::core::panic!(r#"assertion failed, expected exactly 1 element, got 0
+    expression: `{}`"#, ::core::stringify!($iter));
+            }
+            ::core::result::Result::Err(::core::option::Option::Some(preview)) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!(r#"assertion failed, expected exactly 1 element, got {}
+    expression: `{}`,
+    elements: `{:?}`"#, preview.count(), ::core::stringify!($iter), (&$crate::__private::MaybeDebug(&preview)).maybe_debug());
+            }
+        }
+    };
+    ($iter:expr, $($arg:tt)+) => {
+        match $crate::__private::single($iter) {
+            ::core::result::Result::Ok(element) => element,
+            ::core::result::Result::Err(::core::option::Option::None) => {
+                ::core::panic!(r#"assertion failed, expected exactly 1 element, got 0
+    expression: `{}`: {}"#, ::core::stringify!($iter), ::core::format_args!($($arg)+));
+            }
+            ::core::result::Result::Err(::core::option::Option::Some(preview)) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!(r#"assertion failed, expected exactly 1 element, got {}
+    expression: `{}`,
+    elements: `{:?}`: {}"#, preview.count(), ::core::stringify!($iter), (&$crate::__private::MaybeDebug(&preview)).maybe_debug(), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that a collection or iterator contains exactly one element on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_single!`] on debug builds, although it does not
+/// return the element. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_single {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_single!($($arg)*);
+        }
+    }};
+}
+
+/// The first few elements of an iterator, along with the number of elements that were left out.
+#[doc(hidden)]
+pub struct Preview<T> {
+    elements: [Option<T>; PREVIEW_LEN],
+    remaining: usize,
+}
+
+impl<T> Preview<T> {
+    /// Creates an empty preview, to be filled using [`push()`].
+    ///
+    /// [`push()`]: Preview::push
+    pub(crate) fn new() -> Self {
+        Self {
+            elements: [None, None, None],
+            remaining: 0,
+        }
+    }
+
+    /// Keeps the element if there is still room for it, and counts it as left out otherwise.
+    pub(crate) fn push(&mut self, element: T) {
+        match self.elements.iter_mut().find(|slot| slot.is_none()) {
+            Some(slot) => *slot = Some(element),
+            None => self.remaining += 1,
+        }
+    }
+
+    /// Returns the total number of elements the iterator produced.
+    pub fn count(&self) -> usize {
+        self.elements
+            .iter()
+            .filter(|element| element.is_some())
+            .count()
+            + self.remaining
+    }
+}
+
+impl<T> fmt::Debug for Preview<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("[")?;
+        for (index, element) in self.elements.iter().flatten().enumerate() {
+            if index > 0 {
+                formatter.write_str(", ")?;
+            }
+            write!(formatter, "{:?}", element)?;
+        }
+        if self.remaining > 0 {
+            write!(formatter, ", ... ({} more)", self.remaining)?;
+        }
+        formatter.write_str("]")
+    }
+}
+
+/// Returns the only element of the iterator, `None` if it produced no elements, or a preview of the
+/// elements if it produced more than one.
+///
+/// This is an implementation detail of [`assert_single!`] and is not part of the public API.
+#[doc(hidden)]
+pub fn single<I>(iter: I) -> Result<I::Item, Option<Preview<I::Item>>>
+where
+    I: IntoIterator,
+{
+    let mut iter = iter.into_iter();
+    let first = match iter.next() {
+        Some(first) => first,
+        None => return Err(None),
+    };
+    let second = match iter.next() {
+        Some(second) => second,
+        None => return Ok(first),
+    };
+    let third = iter.next();
+    Err(Some(Preview {
+        elements: [Some(first), Some(second), third],
+        remaining: iter.count(),
+    }))
+}
+
+/// Consumes the iterator, keeping only its first few elements and counting the rest.
+///
+/// This is an implementation detail of [`assert_count_eq!`] and is not part of the public API.
+#[doc(hidden)]
+pub fn preview<I>(iter: I) -> Preview<I::Item>
+where
+    I: IntoIterator,
+{
+    let mut preview = Preview::new();
+    for element in iter {
+        preview.push(element);
+    }
+    preview
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{iter, string::String, vec, vec::Vec};
+
+    #[test]
+    fn single() {
+        assert_single!(vec![1]);
+    }
+
+    #[test]
+    fn single_iterator() {
+        assert_single!((0..10).filter(|n| *n == 5));
+    }
+
+    #[test]
+    fn element_returned() {
+        let element = assert_single!(vec![String::from("foo")]);
+        assert_eq!(element, "foo");
+    }
+
+    #[test]
+    fn non_debug_returned() {
+        struct NonDebug(u32);
+
+        let element = assert_single!(iter::once(NonDebug(1)));
+        assert_eq!(element.0, 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected exactly 1 element, got 0\n    expression: `Vec::<u32>::new()`"
+    )]
+    fn zero() {
+        assert_single!(Vec::<u32>::new());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected exactly 1 element, got 2\n    expression: `vec![1, 2]`,\n    elements: `[1, 2]`"
+    )]
+    fn two() {
+        assert_single!(vec![1, 2]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected exactly 1 element, got 3\n    expression: `vec![1, 2, 3]`,\n    elements: `[1, 2, 3]`"
+    )]
+    fn three() {
+        assert_single!(vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected exactly 1 element, got 10\n    expression: `0..10`,\n    elements: `[0, 1, 2, ... (7 more)]`"
+    )]
+    fn many() {
+        assert_single!(0..10);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected exactly 1 element, got 2\n    expression: `vec![NonDebug, NonDebug]`,\n    elements: `<non-Debug>`"
+    )]
+    fn two_non_debug() {
+        struct NonDebug;
+
+        assert_single!(vec![NonDebug, NonDebug]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected exactly 1 element, got 0\n    expression: `Vec::<u32>::new()`: foo"
+    )]
+    fn zero_custom_message() {
+        assert_single!(Vec::<u32>::new(), "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected exactly 1 element, got 3\n    expression: `vec![1, 2, 3]`,\n    elements: `[1, 2, 3]`: foo"
+    )]
+    fn three_custom_message() {
+        assert_single!(vec![1, 2, 3], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_single() {
+        debug_assert_single!(vec![1]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected exactly 1 element, got 0\n    expression: `Vec::<u32>::new()`"
+    )]
+    fn debug_zero() {
+        debug_assert_single!(Vec::<u32>::new());
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected exactly 1 element, got 3\n    expression: `vec![1, 2, 3]`,\n    elements: `[1, 2, 3]`"
+    )]
+    fn debug_three() {
+        debug_assert_single!(vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected exactly 1 element, got 3\n    expression: `vec![1, 2, 3]`,\n    elements: `[1, 2, 3]`: foo"
+    )]
+    fn debug_three_custom_message() {
+        debug_assert_single!(vec![1, 2, 3], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_three() {
+        debug_assert_single!(vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_single!(vec![1]);
+    }
+}
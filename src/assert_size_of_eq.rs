@@ -0,0 +1,160 @@
+/// Asserts that the size of a type, as given by [`size_of`], is equal to the expected size in
+/// bytes.
+///
+/// This is useful for checking the layout of types used in FFI, or for guarding against types
+/// unintentionally growing in size.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_size_of_eq!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// #[repr(C)]
+/// struct Header {
+///     tag: u32,
+///     length: u32,
+/// }
+///
+/// assert_size_of_eq!(Header, 8);
+/// assert_size_of_eq!(Option<Box<u32>>, std::mem::size_of::<usize>());
+///
+/// // With a custom message
+/// assert_size_of_eq!(Header, 8, "Expecting the header to fit in {} bytes", 8);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_size_of_eq!(u32, 8);  // Will panic
+/// # }
+/// ```
+///
+/// [`size_of`]: https://doc.rust-lang.org/core/mem/fn.size_of.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_size_of_eq!`]: crate::debug_assert_size_of_eq!
+#[macro_export]
+macro_rules! assert_size_of_eq {
+    ($type:ty, $expected:expr $(,)?) => {
+        match (::core::mem::size_of::<$type>(), &$expected) {
+            (size, expected_val) => {
+                if size != *expected_val {
+                    ::core::panic!("assertion failed, size_of::<{}>() == {}, expected {}", ::core::stringify!($type), size, expected_val);
+                }
+            }
+        }
+    };
+    ($type:ty, $expected:expr, $($arg:tt)+) => {
+        match (::core::mem::size_of::<$type>(), &$expected) {
+            (size, expected_val) => {
+                if size != *expected_val {
+                    ::core::panic!("assertion failed, size_of::<{}>() == {}, expected {}: {}", ::core::stringify!($type), size, expected_val, ::core::format_args!($($arg)+));
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that the size of a type is equal to the expected size in bytes on debug builds.
+///
+/// This macro behaves the same as [`assert_size_of_eq!`] on debug builds. On release builds it is
+/// a no-op.
+#[macro_export]
+macro_rules! debug_assert_size_of_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_size_of_eq!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[repr(C)]
+    struct Header {
+        _tag: u32,
+        _length: u16,
+    }
+
+    #[test]
+    fn primitive() {
+        assert_size_of_eq!(u32, 4);
+    }
+
+    #[test]
+    fn unit() {
+        assert_size_of_eq!((), 0);
+    }
+
+    #[test]
+    fn array() {
+        assert_size_of_eq!([u16; 3], 6);
+    }
+
+    #[test]
+    fn struct_with_padding() {
+        assert_size_of_eq!(Header, 8);
+    }
+
+    #[test]
+    fn niche_optimized() {
+        assert_size_of_eq!(Option<&u64>, core::mem::size_of::<usize>());
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, size_of::<u32>() == 4, expected 8")]
+    fn primitive_mismatch() {
+        assert_size_of_eq!(u32, 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, size_of::<Header>() == 8, expected 6")]
+    fn struct_mismatch() {
+        assert_size_of_eq!(Header, 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, size_of::<u32>() == 4, expected 8: foo")]
+    fn mismatch_custom_message() {
+        assert_size_of_eq!(u32, 8, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_primitive() {
+        debug_assert_size_of_eq!(u32, 4);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, size_of::<u32>() == 4, expected 8")]
+    fn debug_primitive_mismatch() {
+        debug_assert_size_of_eq!(u32, 8);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, size_of::<u32>() == 4, expected 8: foo")]
+    fn debug_mismatch_custom_message() {
+        debug_assert_size_of_eq!(u32, 8, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_primitive_mismatch() {
+        debug_assert_size_of_eq!(u32, 8);
+    }
+}
@@ -0,0 +1,248 @@
+/// Asserts that two slices have the same length and that each pair of elements has an absolute
+/// difference no greater than an epsilon.
+///
+/// Works with anything that can be indexed by a full range (`[..]`) to give a slice, such as
+/// slices, arrays, and `Vec`s, whose elements are [`f32`], [`f64`], or any primitive integer type.
+/// The lengths are compared first. The elements are then compared pairwise in the same way as
+/// [`assert_abs_diff_eq!`], and the assertion panics at the first pair which is not within the
+/// epsilon, reporting its index.
+///
+/// `NaN` is never considered equal to anything, including another `NaN`. If either element is
+/// `NaN`, the panic message will point that out explicitly.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_slice_abs_diff_eq!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let actual = vec![0.1f32 + 0.2, 1.0, 2.0];
+/// assert_slice_abs_diff_eq!(actual, [0.3, 1.0, 2.0], 1e-6);
+///
+/// // With a custom message
+/// assert_slice_abs_diff_eq!(actual, [0.3, 1.0, 2.0], 1e-6, "Expecting the filter output to match");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_slice_abs_diff_eq!([1.0, 2.0, 3.0], [1.0, 2.5, 3.0], 1e-6);  // Will panic
+/// # }
+/// ```
+///
+/// [`f32`]: https://doc.rust-lang.org/core/primitive.f32.html
+/// [`f64`]: https://doc.rust-lang.org/core/primitive.f64.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_abs_diff_eq!`]: crate::assert_abs_diff_eq!
+/// [`debug_assert_slice_abs_diff_eq!`]: crate::debug_assert_slice_abs_diff_eq!
+#[macro_export]
+macro_rules! assert_slice_abs_diff_eq {
+    ($left:expr, $right:expr, $epsilon:expr $(,)?) => {
+        match (&$left[..], &$right[..], &$epsilon) {
+            (left_val, right_val, epsilon_val) => {
+                if left_val.len() != right_val.len() {
+                    ::core::panic!(r#"assertion failed: `(left.len() == right.len())`
+    left length: `{}`,
+    right length: `{}`"#, left_val.len(), right_val.len());
+                }
+                for (index, (left_element, right_element)) in ::core::iter::Iterator::enumerate(::core::iter::Iterator::zip(left_val.iter(), right_val.iter())) {
+                    if !$crate::__private::AbsDiffEq::abs_diff_eq(left_element, right_element, epsilon_val) {
+                        ::core::panic!(r#"assertion failed: `(|left[index] - right[index]| <= epsilon)`
+    index: `{}`,
+    left: `{:?}`,
+    right: `{:?}`,
+    difference: `{:?}`,
+    epsilon: `{:?}`{}"#,
+                            index,
+                            left_element,
+                            right_element,
+                            $crate::__private::AbsDiffEq::abs_diff(left_element, right_element),
+                            epsilon_val,
+                            $crate::__private::AbsDiffEq::nan_note(left_element, right_element),
+                        );
+                    }
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $epsilon:expr, $($arg:tt)+) => {
+        match (&$left[..], &$right[..], &$epsilon) {
+            (left_val, right_val, epsilon_val) => {
+                if left_val.len() != right_val.len() {
+                    ::core::panic!(r#"assertion failed: `(left.len() == right.len())`
+    left length: `{}`,
+    right length: `{}`: {}"#, left_val.len(), right_val.len(), ::core::format_args!($($arg)+));
+                }
+                for (index, (left_element, right_element)) in ::core::iter::Iterator::enumerate(::core::iter::Iterator::zip(left_val.iter(), right_val.iter())) {
+                    if !$crate::__private::AbsDiffEq::abs_diff_eq(left_element, right_element, epsilon_val) {
+                        ::core::panic!(r#"assertion failed: `(|left[index] - right[index]| <= epsilon)`
+    index: `{}`,
+    left: `{:?}`,
+    right: `{:?}`,
+    difference: `{:?}`,
+    epsilon: `{:?}`{}: {}"#,
+                            index,
+                            left_element,
+                            right_element,
+                            $crate::__private::AbsDiffEq::abs_diff(left_element, right_element),
+                            epsilon_val,
+                            $crate::__private::AbsDiffEq::nan_note(left_element, right_element),
+                            ::core::format_args!($($arg)+),
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two slices have the same length and that each pair of elements has an absolute
+/// difference no greater than an epsilon on debug builds.
+///
+/// This macro behaves the same as [`assert_slice_abs_diff_eq!`] on debug builds. On release builds
+/// it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_slice_abs_diff_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_slice_abs_diff_eq!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn equal() {
+        assert_slice_abs_diff_eq!([1.0, 2.0, 3.0], [1.0, 2.0, 3.0], 0.0);
+    }
+
+    #[test]
+    fn within_epsilon() {
+        assert_slice_abs_diff_eq!([0.1f32 + 0.2, 1.0], [0.3, 1.0 + 1e-7], 1e-6);
+    }
+
+    #[test]
+    fn slices() {
+        let left: &[f64] = &[1.0, 2.0];
+        let right: &[f64] = &[1.0, 2.0];
+        assert_slice_abs_diff_eq!(left, right, 1e-9);
+    }
+
+    #[test]
+    fn integers() {
+        assert_slice_abs_diff_eq!([10u32, 20], [11, 19], 1);
+    }
+
+    #[test]
+    fn empty() {
+        let empty: [f32; 0] = [];
+        assert_slice_abs_diff_eq!(empty, empty, 1e-6);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left.len() == right.len())`\n    left length: `3`,\n    right length: `2`"
+    )]
+    fn length_mismatch() {
+        assert_slice_abs_diff_eq!([1.0, 2.0, 3.0], [1.0, 2.0], 1e-6);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(left.len() == right.len())`\n    left length: `3`,\n    right length: `2`: foo"
+    )]
+    fn length_mismatch_custom_message() {
+        assert_slice_abs_diff_eq!([1.0, 2.0, 3.0], [1.0, 2.0], 1e-6, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|left[index] - right[index]| <= epsilon)`\n    index: `2`,\n    left: `3.0`,\n    right: `3.5`,\n    difference: `0.5`,\n    epsilon: `0.1`"
+    )]
+    fn divergence_middle() {
+        assert_slice_abs_diff_eq!([1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 3.5, 4.0, 9.0], 0.1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|left[index] - right[index]| <= epsilon)`\n    index: `2`,\n    left: `3.0`,\n    right: `3.5`,\n    difference: `0.5`,\n    epsilon: `0.1`: foo"
+    )]
+    fn divergence_middle_custom_message() {
+        assert_slice_abs_diff_eq!(
+            [1.0, 2.0, 3.0, 4.0, 5.0],
+            [1.0, 2.0, 3.5, 4.0, 9.0],
+            0.1,
+            "foo"
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|left[index] - right[index]| <= epsilon)`\n    index: `1`,\n    left: `NaN`,\n    right: `2.0`,\n    difference: `NaN`,\n    epsilon: `0.1`,\n    note: left is NaN"
+    )]
+    fn nan_left() {
+        assert_slice_abs_diff_eq!([1.0, core::f64::NAN], [1.0, 2.0], 0.1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|left[index] - right[index]| <= epsilon)`\n    index: `0`,\n    left: `1.0`,\n    right: `NaN`,\n    difference: `NaN`,\n    epsilon: `0.1`,\n    note: right is NaN"
+    )]
+    fn nan_right() {
+        assert_slice_abs_diff_eq!([1.0, 2.0], [core::f64::NAN, 2.0], 0.1);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_equal() {
+        debug_assert_slice_abs_diff_eq!([1.0, 2.0, 3.0], [1.0, 2.0, 3.0], 0.0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(left.len() == right.len())`\n    left length: `3`,\n    right length: `2`"
+    )]
+    fn debug_length_mismatch() {
+        debug_assert_slice_abs_diff_eq!([1.0, 2.0, 3.0], [1.0, 2.0], 1e-6);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(|left[index] - right[index]| <= epsilon)`\n    index: `2`,\n    left: `3.0`,\n    right: `3.5`,\n    difference: `0.5`,\n    epsilon: `0.1`: foo"
+    )]
+    fn debug_divergence_middle_custom_message() {
+        debug_assert_slice_abs_diff_eq!(
+            [1.0, 2.0, 3.0, 4.0, 5.0],
+            [1.0, 2.0, 3.5, 4.0, 9.0],
+            0.1,
+            "foo"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_divergence_middle() {
+        debug_assert_slice_abs_diff_eq!([1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 3.5, 4.0, 9.0], 0.1);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_slice_abs_diff_eq!([1.0], [1.0], 0.0);
+    }
+}
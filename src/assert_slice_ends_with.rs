@@ -0,0 +1,192 @@
+/// Asserts that a slice ends with the given suffix.
+///
+/// Both expressions can be anything that can be viewed as a slice through [`AsRef`], such as
+/// slices, `Vec`s, and arrays, and they do not need to be of the same type. They are only
+/// borrowed. The elements are compared using [`slice::ends_with`], and must implement
+/// [`PartialEq`] and [`Debug`]. An empty suffix is always matched.
+///
+/// On failure, the expected suffix is printed along with the end of the slice: as many elements
+/// as the suffix has, plus a few more for context. A leading `..` marks that the slice continues.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_slice_ends_with!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let frame: Vec<u8> = vec![0xde, 0xad, 0x00, 0x04, 0xbe, 0xef];
+///
+/// assert_slice_ends_with!(frame, &[0xbe, 0xef]);
+///
+/// // With a custom message
+/// assert_slice_ends_with!(frame, [0xbe, 0xef], "Expecting a trailer");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_slice_ends_with!([1, 2, 3], [1, 2]);  // Will panic
+/// # }
+/// ```
+///
+/// [`AsRef`]: https://doc.rust-lang.org/core/convert/trait.AsRef.html
+/// [`slice::ends_with`]: https://doc.rust-lang.org/core/primitive.slice.html#method.ends_with
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_slice_ends_with!`]: crate::debug_assert_slice_ends_with!
+#[macro_export]
+macro_rules! assert_slice_ends_with {
+    ($slice:expr, $suffix:expr $(,)?) => {
+        match (&$slice, &$suffix) {
+            (slice_val, suffix_val) => {
+                let suffix_val = ::core::convert::AsRef::<[_]>::as_ref(suffix_val);
+                if let ::core::result::Result::Err(end) = $crate::__private::ends_with(::core::convert::AsRef::<[_]>::as_ref(slice_val), suffix_val) {
+                    ::core::panic!(r#"assertion failed, expected slice to end with suffix
+    suffix: `{:?}`,
+    end: `{:?}`"#, suffix_val, end)
+                }
+            }
+        }
+    };
+    ($slice:expr, $suffix:expr, $($arg:tt)+) => {
+        match (&$slice, &$suffix) {
+            (slice_val, suffix_val) => {
+                let suffix_val = ::core::convert::AsRef::<[_]>::as_ref(suffix_val);
+                if let ::core::result::Result::Err(end) = $crate::__private::ends_with(::core::convert::AsRef::<[_]>::as_ref(slice_val), suffix_val) {
+                    ::core::panic!(r#"assertion failed, expected slice to end with suffix
+    suffix: `{:?}`,
+    end: `{:?}`: {}"#, suffix_val, end, ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that a slice ends with the given suffix on debug builds.
+///
+/// This macro behaves the same as [`assert_slice_ends_with!`] on debug builds. On release builds
+/// it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_slice_ends_with {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_slice_ends_with!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{vec, vec::Vec};
+
+    #[test]
+    fn ends_with() {
+        assert_slice_ends_with!(vec![0xde, 0xad, 0xbe, 0xef], &[0xbe, 0xef]);
+    }
+
+    #[test]
+    fn ends_with_array() {
+        assert_slice_ends_with!([1, 2, 3], [2, 3]);
+    }
+
+    #[test]
+    fn ends_with_slice() {
+        let slice: &[u32] = &[1, 2, 3];
+        let suffix: &[u32] = &[3];
+        assert_slice_ends_with!(slice, suffix);
+    }
+
+    #[test]
+    fn ends_with_whole_slice() {
+        assert_slice_ends_with!([1, 2, 3], [1, 2, 3]);
+    }
+
+    #[test]
+    fn ends_with_empty_suffix() {
+        assert_slice_ends_with!(Vec::<u32>::new(), []);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected slice to end with suffix\n    suffix: `[1, 2]`,\n    end: `[1, 2, 3]`"
+    )]
+    fn not_ends_with() {
+        assert_slice_ends_with!([1, 2, 3], [1, 2]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected slice to end with suffix\n    suffix: `[7, 9]`,\n    end: `[.., 4, 5, 6, 7, 8, 9]`"
+    )]
+    fn not_ends_with_context() {
+        assert_slice_ends_with!((0..10).collect::<Vec<_>>(), [7, 9]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected slice to end with suffix\n    suffix: `[1, 2, 3, 4]`,\n    end: `[3, 4]`"
+    )]
+    fn suffix_longer_than_slice() {
+        assert_slice_ends_with!([3, 4], [1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected slice to end with suffix\n    suffix: `[1]`,\n    end: `[]`"
+    )]
+    fn empty_slice() {
+        assert_slice_ends_with!(Vec::<u32>::new(), [1]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected slice to end with suffix\n    suffix: `[1, 2]`,\n    end: `[1, 2, 3]`: foo"
+    )]
+    fn not_ends_with_custom_message() {
+        assert_slice_ends_with!([1, 2, 3], [1, 2], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_ends_with() {
+        debug_assert_slice_ends_with!([1, 2, 3], [2, 3]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected slice to end with suffix\n    suffix: `[1, 2]`,\n    end: `[1, 2, 3]`"
+    )]
+    fn debug_not_ends_with() {
+        debug_assert_slice_ends_with!([1, 2, 3], [1, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected slice to end with suffix\n    suffix: `[1, 2]`,\n    end: `[1, 2, 3]`: foo"
+    )]
+    fn debug_not_ends_with_custom_message() {
+        debug_assert_slice_ends_with!([1, 2, 3], [1, 2], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_ends_with() {
+        debug_assert_slice_ends_with!([1, 2, 3], [1, 2]);
+    }
+}
@@ -0,0 +1,272 @@
+use core::fmt;
+
+/// The number of elements shown past the expected prefix or suffix in a failure message.
+const CONTEXT: usize = 4;
+
+/// Asserts that a slice starts with the given prefix.
+///
+/// Both expressions can be anything that can be viewed as a slice through [`AsRef`], such as
+/// slices, `Vec`s, and arrays, and they do not need to be of the same type. They are only
+/// borrowed. The elements are compared using [`slice::starts_with`], and must implement
+/// [`PartialEq`] and [`Debug`]. An empty prefix is always matched.
+///
+/// On failure, the expected prefix is printed along with the start of the slice: as many elements
+/// as the prefix has, plus a few more for context. A trailing `..` marks that the slice continues.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_slice_starts_with!`] for assertions that are not enabled in release builds
+/// by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let frame: Vec<u8> = vec![0xde, 0xad, 0x00, 0x04, 0xbe, 0xef];
+///
+/// assert_slice_starts_with!(frame, &[0xde, 0xad]);
+///
+/// // With a custom message
+/// assert_slice_starts_with!(frame, [0xde, 0xad], "Expecting magic bytes");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_slice_starts_with!([1, 2, 3], [2, 3]);  // Will panic
+/// # }
+/// ```
+///
+/// [`AsRef`]: https://doc.rust-lang.org/core/convert/trait.AsRef.html
+/// [`slice::starts_with`]: https://doc.rust-lang.org/core/primitive.slice.html#method.starts_with
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_slice_starts_with!`]: crate::debug_assert_slice_starts_with!
+#[macro_export]
+macro_rules! assert_slice_starts_with {
+    ($slice:expr, $prefix:expr $(,)?) => {
+        match (&$slice, &$prefix) {
+            (slice_val, prefix_val) => {
+                let prefix_val = ::core::convert::AsRef::<[_]>::as_ref(prefix_val);
+                if let ::core::result::Result::Err(start) = $crate::__private::starts_with(::core::convert::AsRef::<[_]>::as_ref(slice_val), prefix_val) {
+                    ::core::panic!(r#"assertion failed, expected slice to start with prefix
+    prefix: `{:?}`,
+    start: `{:?}`"#, prefix_val, start)
+                }
+            }
+        }
+    };
+    ($slice:expr, $prefix:expr, $($arg:tt)+) => {
+        match (&$slice, &$prefix) {
+            (slice_val, prefix_val) => {
+                let prefix_val = ::core::convert::AsRef::<[_]>::as_ref(prefix_val);
+                if let ::core::result::Result::Err(start) = $crate::__private::starts_with(::core::convert::AsRef::<[_]>::as_ref(slice_val), prefix_val) {
+                    ::core::panic!(r#"assertion failed, expected slice to start with prefix
+    prefix: `{:?}`,
+    start: `{:?}`: {}"#, prefix_val, start, ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that a slice starts with the given prefix on debug builds.
+///
+/// This macro behaves the same as [`assert_slice_starts_with!`] on debug builds. On release builds
+/// it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_slice_starts_with {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_slice_starts_with!($($arg)*);
+        }
+    }};
+}
+
+/// A contiguous part of a slice, printed with `..` on the sides where the slice continues.
+#[doc(hidden)]
+pub struct Window<'a, T> {
+    elements: &'a [T],
+    before: bool,
+    after: bool,
+}
+
+impl<T> fmt::Debug for Window<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("[")?;
+        if self.before {
+            formatter.write_str("..")?;
+        }
+        for (index, element) in self.elements.iter().enumerate() {
+            if index > 0 || self.before {
+                formatter.write_str(", ")?;
+            }
+            write!(formatter, "{:?}", element)?;
+        }
+        if self.after {
+            if self.before || !self.elements.is_empty() {
+                formatter.write_str(", ")?;
+            }
+            formatter.write_str("..")?;
+        }
+        formatter.write_str("]")
+    }
+}
+
+/// Checks that the slice starts with the prefix, returning the start of the slice otherwise.
+///
+/// This is an implementation detail of [`assert_slice_starts_with!`] and is not part of the public
+/// API.
+#[doc(hidden)]
+pub fn starts_with<'a, T>(slice: &'a [T], prefix: &[T]) -> Result<(), Window<'a, T>>
+where
+    T: PartialEq,
+{
+    if slice.starts_with(prefix) {
+        Ok(())
+    } else {
+        let end = core::cmp::min(slice.len(), prefix.len() + CONTEXT);
+        Err(Window {
+            elements: &slice[..end],
+            before: false,
+            after: end < slice.len(),
+        })
+    }
+}
+
+/// Checks that the slice ends with the suffix, returning the end of the slice otherwise.
+///
+/// This is an implementation detail of [`assert_slice_ends_with!`] and is not part of the public
+/// API.
+#[doc(hidden)]
+pub fn ends_with<'a, T>(slice: &'a [T], suffix: &[T]) -> Result<(), Window<'a, T>>
+where
+    T: PartialEq,
+{
+    if slice.ends_with(suffix) {
+        Ok(())
+    } else {
+        let start = slice.len().saturating_sub(suffix.len() + CONTEXT);
+        Err(Window {
+            elements: &slice[start..],
+            before: start > 0,
+            after: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{vec, vec::Vec};
+
+    #[test]
+    fn starts_with() {
+        assert_slice_starts_with!(vec![0xde, 0xad, 0xbe, 0xef], &[0xde, 0xad]);
+    }
+
+    #[test]
+    fn starts_with_array() {
+        assert_slice_starts_with!([1, 2, 3], [1, 2]);
+    }
+
+    #[test]
+    fn starts_with_slice() {
+        let slice: &[u32] = &[1, 2, 3];
+        let prefix: &[u32] = &[1];
+        assert_slice_starts_with!(slice, prefix);
+    }
+
+    #[test]
+    fn starts_with_whole_slice() {
+        assert_slice_starts_with!([1, 2, 3], [1, 2, 3]);
+    }
+
+    #[test]
+    fn starts_with_empty_prefix() {
+        assert_slice_starts_with!(Vec::<u32>::new(), []);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected slice to start with prefix\n    prefix: `[2, 3]`,\n    start: `[1, 2, 3]`"
+    )]
+    fn not_starts_with() {
+        assert_slice_starts_with!([1, 2, 3], [2, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected slice to start with prefix\n    prefix: `[0, 2]`,\n    start: `[0, 1, 2, 3, 4, 5, ..]`"
+    )]
+    fn not_starts_with_context() {
+        assert_slice_starts_with!((0..10).collect::<Vec<_>>(), [0, 2]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected slice to start with prefix\n    prefix: `[1, 2, 3, 4]`,\n    start: `[1, 2]`"
+    )]
+    fn prefix_longer_than_slice() {
+        assert_slice_starts_with!([1, 2], [1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected slice to start with prefix\n    prefix: `[1]`,\n    start: `[]`"
+    )]
+    fn empty_slice() {
+        assert_slice_starts_with!(Vec::<u32>::new(), [1]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected slice to start with prefix\n    prefix: `[2, 3]`,\n    start: `[1, 2, 3]`: foo"
+    )]
+    fn not_starts_with_custom_message() {
+        assert_slice_starts_with!([1, 2, 3], [2, 3], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_starts_with() {
+        debug_assert_slice_starts_with!([1, 2, 3], [1, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected slice to start with prefix\n    prefix: `[2, 3]`,\n    start: `[1, 2, 3]`"
+    )]
+    fn debug_not_starts_with() {
+        debug_assert_slice_starts_with!([1, 2, 3], [2, 3]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected slice to start with prefix\n    prefix: `[2, 3]`,\n    start: `[1, 2, 3]`: foo"
+    )]
+    fn debug_not_starts_with_custom_message() {
+        debug_assert_slice_starts_with!([1, 2, 3], [2, 3], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_starts_with() {
+        debug_assert_slice_starts_with!([1, 2, 3], [2, 3]);
+    }
+}
@@ -1,5 +1,7 @@
 /// Asserts that the expression matches a [`Some(_)`] variant, returning the contained value.
 ///
+/// On failure, the panic message includes the asserted expression as written in the source.
+///
 /// ## Uses
 ///
 /// Assertions are always checked in both debug and release builds, and cannot be disabled.
@@ -44,7 +46,8 @@ macro_rules! assert_some {
         match $cond {
             ::core::option::Option::Some(t) => t,
             ::core::option::Option::None => {
-                ::core::panic!("assertion failed, expected Some(_), got None");
+                ::core::panic!(r#"assertion failed, expected Some(_), got None
+    expression: `{}`"#, ::core::stringify!($cond));
             }
         }
     };
@@ -52,7 +55,8 @@ macro_rules! assert_some {
         match $cond {
             ::core::option::Option::Some(t) => t,
             ::core::option::Option::None => {
-                ::core::panic!("assertion failed, expected Some(_), got None: {}", ::core::format_args!($($arg)+));
+                ::core::panic!(r#"assertion failed, expected Some(_), got None
+    expression: `{}`: {}"#, ::core::stringify!($cond), ::core::format_args!($($arg)+));
             }
         }
     };
@@ -66,10 +70,13 @@ macro_rules! assert_some {
 /// [`Some(_)`]: https://doc.rust-lang.org/core/option/enum.Option.html#variant.Some
 #[macro_export]
 macro_rules! debug_assert_some {
-    ($($arg:tt)*) => {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
         #[cfg(debug_assertions)]
-        $crate::assert_some!($($arg)*);
-    }
+        {
+            $crate::assert_some!($($arg)*);
+        }
+    }};
 }
 
 #[cfg(test)]
@@ -80,15 +87,21 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "assertion failed, expected Some(_), got None")]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_), got None\n    expression: `maybe`"
+    )]
     fn not_some() {
-        assert_some!(None::<()>);
+        let maybe = None::<()>;
+        assert_some!(maybe);
     }
 
     #[test]
-    #[should_panic(expected = "assertion failed, expected Some(_), got None: foo")]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_), got None\n    expression: `maybe`: foo"
+    )]
     fn not_some_custom_message() {
-        assert_some!(None::<()>, "foo");
+        let maybe = None::<()>;
+        assert_some!(maybe, "foo");
     }
 
     #[test]
@@ -105,16 +118,22 @@ mod tests {
 
     #[test]
     #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
-    #[should_panic(expected = "assertion failed, expected Some(_), got None")]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_), got None\n    expression: `maybe`"
+    )]
     fn debug_not_some() {
-        debug_assert_some!(None::<()>);
+        let maybe = None::<()>;
+        debug_assert_some!(maybe);
     }
 
     #[test]
     #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
-    #[should_panic(expected = "assertion failed, expected Some(_), got None: foo")]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_), got None\n    expression: `maybe`: foo"
+    )]
     fn debug_not_some_custom_message() {
-        debug_assert_some!(None::<()>, "foo");
+        let maybe = None::<()>;
+        debug_assert_some!(maybe, "foo");
     }
 
     #[test]
@@ -123,6 +142,22 @@ mod tests {
         debug_assert_some!(None::<()>);
     }
 
+    #[test]
+    fn debug_some_returns_unit() {
+        let () = debug_assert_some!(Some(42));
+    }
+
+    #[test]
+    fn debug_some_returns_unit_custom_message() {
+        let () = debug_assert_some!(Some(42), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_some_returns_unit() {
+        let () = debug_assert_some!(None::<()>);
+    }
+
     #[test]
     fn does_not_require_some_to_impl_debug() {
         enum Foo {
@@ -160,4 +195,16 @@ mod tests {
 
         debug_assert_some!(Some(Foo::Bar), "foo");
     }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_), got None\n    expression: `lookup()`"
+    )]
+    fn expression_in_message() {
+        fn lookup() -> Option<u32> {
+            None
+        }
+
+        assert_some!(lookup());
+    }
 }
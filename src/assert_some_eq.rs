@@ -74,10 +74,13 @@ macro_rules! assert_some_eq {
 /// [`Some(T)`]: https://doc.rust-lang.org/core/option/enum.Option.html#variant.Some
 #[macro_export]
 macro_rules! debug_assert_some_eq {
-    ($($arg:tt)*) => {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
         #[cfg(debug_assertions)]
-        $crate::assert_some_eq!($($arg)*);
-    }
+        {
+            $crate::assert_some_eq!($($arg)*);
+        }
+    }};
 }
 
 #[cfg(test)]
@@ -156,4 +159,20 @@ mod tests {
     fn debug_release_not_some() {
         debug_assert_some_eq!(None::<usize>, 42);
     }
+
+    #[test]
+    fn debug_some_eq_returns_unit() {
+        let () = debug_assert_some_eq!(Some(42), 42);
+    }
+
+    #[test]
+    fn debug_some_eq_returns_unit_custom_message() {
+        let () = debug_assert_some_eq!(Some(42), 42, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_some_eq_returns_unit() {
+        let () = debug_assert_some_eq!(None::<usize>, 42);
+    }
 }
@@ -0,0 +1,218 @@
+/// Asserts that the expression matches a [`Some(Err(_))`] variant, returning the innermost error.
+///
+/// This is useful for `Option<Result<T, E>>` values, such as those produced by optional error
+/// reporting mechanisms or fallible iterators. A `None` and a `Some(Ok(_))` panic with distinct
+/// messages, so that it is clear whether no result was produced or the operation succeeded. On
+/// failure, the panic message includes the asserted expression as written in the source. Neither
+/// `T` nor `E` is required to implement [`Debug`].
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_some_err!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let report: Option<Result<i32, &str>> = Some(Err("disk full"));
+///
+/// assert_some_err!(report);
+///
+/// // With a custom message
+/// assert_some_err!(report, "Expecting the failure to be reported");
+/// # }
+/// ```
+///
+/// The innermost error will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let report: Option<Result<i32, &str>> = Some(Err("disk full"));
+///
+/// let error = assert_some_err!(report);
+/// assert_eq!(error, "disk full");
+/// # }
+/// ```
+///
+/// A `Some(Ok(_))` variant will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let report: Option<Result<i32, &str>> = Some(Ok(1));
+///
+/// assert_some_err!(report);  // Will panic
+/// # }
+/// ```
+///
+/// [`Some(Err(_))`]: https://doc.rust-lang.org/core/option/enum.Option.html#variant.Some
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_some_err!`]: crate::debug_assert_some_err!
+#[macro_export]
+macro_rules! assert_some_err {
+    ($cond:expr $(,)?) => {
+        match $cond {
+            ::core::option::Option::Some(::core::result::Result::Err(e)) => e,
+            ::core::option::Option::Some(::core::result::Result::Ok(_)) => {
+                ::core::panic!(r#"assertion failed, expected Some(Err(_)), got Some(Ok(_))
+    expression: `{}`"#, ::core::stringify!($cond));
+            }
+            ::core::option::Option::None => {
+                ::core::panic!(r#"assertion failed, expected Some(Err(_)), got None
+    expression: `{}`"#, ::core::stringify!($cond));
+            }
+        }
+    };
+    ($cond:expr, $($arg:tt)+) => {
+        match $cond {
+            ::core::option::Option::Some(::core::result::Result::Err(e)) => e,
+            ::core::option::Option::Some(::core::result::Result::Ok(_)) => {
+                ::core::panic!(r#"assertion failed, expected Some(Err(_)), got Some(Ok(_))
+    expression: `{}`: {}"#, ::core::stringify!($cond), ::core::format_args!($($arg)+));
+            }
+            ::core::option::Option::None => {
+                ::core::panic!(r#"assertion failed, expected Some(Err(_)), got None
+    expression: `{}`: {}"#, ::core::stringify!($cond), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that the expression matches a [`Some(Err(_))`] variant on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_some_err!`] on debug builds, although it does
+/// not return the innermost error. On release builds it is a no-op.
+///
+/// [`Some(Err(_))`]: https://doc.rust-lang.org/core/option/enum.Option.html#variant.Some
+#[macro_export]
+macro_rules! debug_assert_some_err {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_some_err!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    type Nested = Option<Result<&'static str, u32>>;
+
+    #[test]
+    fn some_err() {
+        let opt: Nested = Some(Err(1));
+        assert_some_err!(opt);
+    }
+
+    #[test]
+    fn some_err_non_debug() {
+        struct NonDebug;
+
+        let opt: Option<Result<(), NonDebug>> = Some(Err(NonDebug));
+        assert_some_err!(opt);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Err(_)), got Some(Ok(_))\n    expression: `opt`"
+    )]
+    fn some_ok() {
+        let opt: Nested = Some(Ok("fine"));
+        assert_some_err!(opt);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Err(_)), got None\n    expression: `opt`"
+    )]
+    fn none() {
+        let opt: Nested = None;
+        assert_some_err!(opt);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Err(_)), got Some(Ok(_))\n    expression: `opt`: foo"
+    )]
+    fn some_ok_custom_message() {
+        let opt: Nested = Some(Ok("fine"));
+        assert_some_err!(opt, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Err(_)), got None\n    expression: `opt`: foo"
+    )]
+    fn none_custom_message() {
+        let opt: Nested = None;
+        assert_some_err!(opt, "foo");
+    }
+
+    #[test]
+    fn error_returned() {
+        let opt: Nested = Some(Err(42));
+        let error = assert_some_err!(opt);
+        assert_eq!(error, 42);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_some_err() {
+        let opt: Nested = Some(Err(1));
+        debug_assert_some_err!(opt);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Err(_)), got Some(Ok(_))\n    expression: `opt`"
+    )]
+    fn debug_some_ok() {
+        let opt: Nested = Some(Ok("fine"));
+        debug_assert_some_err!(opt);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Err(_)), got None\n    expression: `opt`"
+    )]
+    fn debug_none() {
+        let opt: Nested = None;
+        debug_assert_some_err!(opt);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Err(_)), got Some(Ok(_))\n    expression: `opt`: foo"
+    )]
+    fn debug_some_ok_custom_message() {
+        let opt: Nested = Some(Ok("fine"));
+        debug_assert_some_err!(opt, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_some_ok() {
+        let opt: Nested = Some(Ok("fine"));
+        debug_assert_some_err!(opt);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let opt: Nested = Some(Err(1));
+        let () = debug_assert_some_err!(opt);
+    }
+}
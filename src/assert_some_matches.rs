@@ -0,0 +1,245 @@
+/// Asserts that the expression matches a [`Some(_)`] variant whose contained value matches the
+/// provided pattern.
+///
+/// This combines [`assert_some!`] and [`assert_matches!`] into a single call. As with
+/// [`assert_matches!`], the pattern can contain multiple alternatives separated by `|` and can be
+/// followed by an `if` guard.
+///
+/// Since the value only needs to be matched, its type does not need to implement [`PartialEq`] or
+/// [`Debug`]. If the value does not match, it is printed with its [`Debug`] implementation if it
+/// has one, and as `<non-Debug>` otherwise.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_some_matches!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// enum Response {
+///     Created { id: u32 },
+///     Updated { id: u32 },
+///     Deleted,
+/// }
+///
+/// let option = Some(Response::Created { id: 42 });
+///
+/// assert_some_matches!(&option, Response::Created { .. });
+/// assert_some_matches!(&option, Response::Created { id } | Response::Updated { id } if *id > 0);
+///
+/// // With a custom message
+/// assert_some_matches!(option, Response::Created { .. }, "Expecting a new entry");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let option: Option<Option<i32>> = Some(None);
+///
+/// assert_some_matches!(option, Some(_));  // Will panic
+/// # }
+/// ```
+///
+/// [`Some(_)`]: https://doc.rust-lang.org/core/option/enum.Option.html#variant.Some
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_some!`]: crate::assert_ok!
+/// [`assert_matches!`]: crate::assert_matches!
+/// [`debug_assert_some_matches!`]: crate::debug_assert_some_matches!
+#[macro_export]
+macro_rules! assert_some_matches {
+    ($cond:expr, $($pattern:pat)|+ $(if $guard:expr)? $(,)?) => {
+        match $cond {
+            ::core::option::Option::Some(t) => {
+                #[allow(unreachable_patterns)]
+                match t {
+                    $($pattern)|+ $(if $guard)? => {},
+                    other => {
+                        #[allow(unused_imports)]
+                        use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                        ::core::panic!(r#"assertion failed, expected Some(_) to match the given pattern
+    value: `{:?}`,
+    pattern: `{}`"#, (&$crate::__private::MaybeDebug(&other)).maybe_debug(), ::core::stringify!($($pattern)|+ $(if $guard)?));
+                    }
+                }
+            }
+            ::core::option::Option::None => {
+                ::core::panic!(r#"assertion failed, expected Some(_), got None
+    expression: `{}`"#, ::core::stringify!($cond));
+            }
+        }
+    };
+    ($cond:expr, $($pattern:pat)|+ $(if $guard:expr)?, $($arg:tt)+) => {
+        match $cond {
+            ::core::option::Option::Some(t) => {
+                #[allow(unreachable_patterns)]
+                match t {
+                    $($pattern)|+ $(if $guard)? => {},
+                    other => {
+                        #[allow(unused_imports)]
+                        use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                        ::core::panic!(r#"assertion failed, expected Some(_) to match the given pattern
+    value: `{:?}`,
+    pattern: `{}`: {}"#, (&$crate::__private::MaybeDebug(&other)).maybe_debug(), ::core::stringify!($($pattern)|+ $(if $guard)?), ::core::format_args!($($arg)+));
+                    }
+                }
+            }
+            ::core::option::Option::None => {
+                ::core::panic!(r#"assertion failed, expected Some(_), got None
+    expression: `{}`: {}"#, ::core::stringify!($cond), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that the expression matches a [`Some(_)`] variant whose contained value matches the
+/// provided pattern on debug builds.
+///
+/// This macro behaves the same as [`assert_some_matches!`] on debug builds. On release builds it is
+/// a no-op.
+///
+/// [`Some(_)`]: https://doc.rust-lang.org/core/option/enum.Option.html#variant.Some
+#[macro_export]
+macro_rules! debug_assert_some_matches {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_some_matches!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    enum Foo {
+        Bar(usize),
+        Baz(usize),
+    }
+
+    #[test]
+    fn matches() {
+        assert_some_matches!(Some(Foo::Bar(42)), Foo::Bar(_));
+    }
+
+    #[test]
+    fn matches_multiple_variants() {
+        assert_some_matches!(Some(Foo::Baz(42)), Foo::Bar(_) | Foo::Baz(_));
+    }
+
+    #[test]
+    fn matches_with_guard() {
+        assert_some_matches!(Some(Foo::Bar(42)), Foo::Bar(x) if x > 40);
+    }
+
+    #[test]
+    fn matches_multiple_variants_with_guard() {
+        assert_some_matches!(Some(Foo::Baz(42)), Foo::Bar(x) | Foo::Baz(x) if x > 40);
+    }
+
+    #[test]
+    fn matches_reference() {
+        let option = Some(Foo::Bar(42));
+        assert_some_matches!(&option, Foo::Bar(_));
+        assert_some_matches!(option, Foo::Bar(42));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_) to match the given pattern\n    value: `Baz(42)`,\n    pattern: `Foo::Bar(_)`"
+    )]
+    fn not_matches() {
+        assert_some_matches!(Some(Foo::Baz(42)), Foo::Bar(_));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_) to match the given pattern\n    value: `Bar(42)`,\n    pattern: `Foo::Bar(x) if x > 100`"
+    )]
+    fn not_matches_guard() {
+        assert_some_matches!(Some(Foo::Bar(42)), Foo::Bar(x) if x > 100);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_) to match the given pattern\n    value: `Baz(42)`,\n    pattern: `Foo::Bar(_)`: foo"
+    )]
+    fn not_matches_custom_message() {
+        assert_some_matches!(Some(Foo::Baz(42)), Foo::Bar(_), "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_) to match the given pattern\n    value: `<non-Debug>`,\n    pattern: `NonDebug::Bar(_)`"
+    )]
+    fn not_matches_non_debug() {
+        #[allow(dead_code)]
+        enum NonDebug {
+            Bar(usize),
+            Baz(usize),
+        }
+
+        assert_some_matches!(Some(NonDebug::Baz(42)), NonDebug::Bar(_));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_), got None\n    expression: `option`"
+    )]
+    fn none() {
+        let option: Option<Foo> = None;
+        assert_some_matches!(option, Foo::Bar(_));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_), got None\n    expression: `option`: foo"
+    )]
+    fn none_custom_message() {
+        let option: Option<Foo> = None;
+        assert_some_matches!(option, Foo::Bar(_), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_matches() {
+        debug_assert_some_matches!(Some(Foo::Bar(42)), Foo::Bar(_));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_) to match the given pattern\n    value: `Baz(42)`,\n    pattern: `Foo::Bar(_)`"
+    )]
+    fn debug_not_matches() {
+        debug_assert_some_matches!(Some(Foo::Baz(42)), Foo::Bar(_));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_) to match the given pattern\n    value: `Baz(42)`,\n    pattern: `Foo::Bar(_)`: foo"
+    )]
+    fn debug_not_matches_custom_message() {
+        debug_assert_some_matches!(Some(Foo::Baz(42)), Foo::Bar(_), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_matches() {
+        debug_assert_some_matches!(Some(Foo::Baz(42)), Foo::Bar(_));
+    }
+}
@@ -0,0 +1,226 @@
+/// Asserts that the expression matches a [`Some(Ok(_))`] variant, returning the innermost value.
+///
+/// This is useful for `Option<Result<T, E>>` values, such as those produced by fallible iterators
+/// or by parsing an optional field. A `None` and a `Some(Err(_))` panic with distinct messages, so
+/// that it is clear whether the value was missing or failed. On failure, the panic message
+/// includes the asserted expression as written in the source. For `Some(Err(_))`, the error is
+/// also printed with its [`Debug`] implementation if it has one, and as `<non-Debug>` otherwise.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_some_ok!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let next: Option<Result<i32, &str>> = Some(Ok(1));
+///
+/// assert_some_ok!(next);
+///
+/// // With a custom message
+/// assert_some_ok!(next, "Expecting a successfully read line");
+/// # }
+/// ```
+///
+/// The innermost value will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let next: Option<Result<i32, &str>> = Some(Ok(1));
+///
+/// let value = assert_some_ok!(next);
+/// assert_eq!(value, 1);
+/// # }
+/// ```
+///
+/// A `Some(Err(_))` variant will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let next: Option<Result<i32, &str>> = Some(Err("read error"));
+///
+/// assert_some_ok!(next);  // Will panic
+/// # }
+/// ```
+///
+/// [`Some(Ok(_))`]: https://doc.rust-lang.org/core/option/enum.Option.html#variant.Some
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_some_ok!`]: crate::debug_assert_some_ok!
+#[macro_export]
+macro_rules! assert_some_ok {
+    ($cond:expr $(,)?) => {
+        match $cond {
+            ::core::option::Option::Some(::core::result::Result::Ok(t)) => t,
+            ::core::option::Option::Some(::core::result::Result::Err(e)) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!(r#"assertion failed, expected Some(Ok(_)), got Some(Err(_))
+    expression: `{}`,
+    error: `{:?}`"#, ::core::stringify!($cond), (&$crate::__private::MaybeDebug(&e)).maybe_debug());
+            }
+            ::core::option::Option::None => {
+                ::core::panic!(r#"assertion failed, expected Some(Ok(_)), got None
+    expression: `{}`"#, ::core::stringify!($cond));
+            }
+        }
+    };
+    ($cond:expr, $($arg:tt)+) => {
+        match $cond {
+            ::core::option::Option::Some(::core::result::Result::Ok(t)) => t,
+            ::core::option::Option::Some(::core::result::Result::Err(e)) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                ::core::panic!(r#"assertion failed, expected Some(Ok(_)), got Some(Err(_))
+    expression: `{}`,
+    error: `{:?}`: {}"#, ::core::stringify!($cond), (&$crate::__private::MaybeDebug(&e)).maybe_debug(), ::core::format_args!($($arg)+));
+            }
+            ::core::option::Option::None => {
+                ::core::panic!(r#"assertion failed, expected Some(Ok(_)), got None
+    expression: `{}`: {}"#, ::core::stringify!($cond), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that the expression matches a [`Some(Ok(_))`] variant on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_some_ok!`] on debug builds, although it does not
+/// return the innermost value. On release builds it is a no-op.
+///
+/// [`Some(Ok(_))`]: https://doc.rust-lang.org/core/option/enum.Option.html#variant.Some
+#[macro_export]
+macro_rules! debug_assert_some_ok {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_some_ok!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    type Nested = Option<Result<u32, &'static str>>;
+
+    #[test]
+    fn some_ok() {
+        let opt: Nested = Some(Ok(1));
+        assert_some_ok!(opt);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Ok(_)), got Some(Err(_))\n    expression: `opt`,\n    error: `\"failed\"`"
+    )]
+    fn some_err() {
+        let opt: Nested = Some(Err("failed"));
+        assert_some_ok!(opt);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Ok(_)), got None\n    expression: `opt`"
+    )]
+    fn none() {
+        let opt: Nested = None;
+        assert_some_ok!(opt);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Ok(_)), got Some(Err(_))\n    expression: `opt`,\n    error: `<non-Debug>`"
+    )]
+    fn some_err_non_debug() {
+        struct NonDebug;
+
+        let opt: Option<Result<(), NonDebug>> = Some(Err(NonDebug));
+        assert_some_ok!(opt);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Ok(_)), got Some(Err(_))\n    expression: `opt`,\n    error: `\"failed\"`: foo"
+    )]
+    fn some_err_custom_message() {
+        let opt: Nested = Some(Err("failed"));
+        assert_some_ok!(opt, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Ok(_)), got None\n    expression: `opt`: foo"
+    )]
+    fn none_custom_message() {
+        let opt: Nested = None;
+        assert_some_ok!(opt, "foo");
+    }
+
+    #[test]
+    fn value_returned() {
+        let opt: Nested = Some(Ok(42));
+        let value = assert_some_ok!(opt);
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_some_ok() {
+        let opt: Nested = Some(Ok(1));
+        debug_assert_some_ok!(opt);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Ok(_)), got Some(Err(_))\n    expression: `opt`,\n    error: `\"failed\"`"
+    )]
+    fn debug_some_err() {
+        let opt: Nested = Some(Err("failed"));
+        debug_assert_some_ok!(opt);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Ok(_)), got None\n    expression: `opt`"
+    )]
+    fn debug_none() {
+        let opt: Nested = None;
+        debug_assert_some_ok!(opt);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Ok(_)), got Some(Err(_))\n    expression: `opt`,\n    error: `\"failed\"`: foo"
+    )]
+    fn debug_some_err_custom_message() {
+        let opt: Nested = Some(Err("failed"));
+        debug_assert_some_ok!(opt, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_some_err() {
+        let opt: Nested = Some(Err("failed"));
+        debug_assert_some_ok!(opt);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let opt: Nested = Some(Ok(1));
+        let () = debug_assert_some_ok!(opt);
+    }
+}
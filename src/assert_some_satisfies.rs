@@ -0,0 +1,271 @@
+/// Asserts that the expression matches a [`Some(_)`] variant whose contained value satisfies the
+/// predicate, returning the contained value.
+///
+/// The predicate is called with a reference to the contained value, and can either be a closure,
+/// such as `|v| v.len() > 3`, or a named binding followed by an expression, such as
+/// `v => v.len() > 3`. This keeps the context of the `Option` in the failure message, which is lost
+/// when writing `let v = assert_some!(option); assert!(predicate(&v));`.
+///
+/// If the value does not satisfy the predicate, the stringified predicate and the value are
+/// printed. The value is printed with its [`Debug`] implementation if it has one, and as
+/// `<non-Debug>` otherwise.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_some_satisfies!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let option = Some("foobar");
+///
+/// assert_some_satisfies!(option, |v| v.starts_with("foo"));
+/// assert_some_satisfies!(option, v => v.len() == 6);
+///
+/// // With a custom message
+/// assert_some_satisfies!(option, |v| v.starts_with("foo"), "Expecting a foo");
+/// # }
+/// ```
+///
+/// The contained value will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let option = Some(42);
+///
+/// let value = assert_some_satisfies!(option, |v| *v > 0);
+/// assert_eq!(value, 42);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let option = Some(-1);
+///
+/// assert_some_satisfies!(option, |v| *v > 0);  // Will panic
+/// # }
+/// ```
+///
+/// [`Some(_)`]: https://doc.rust-lang.org/core/option/enum.Option.html#variant.Some
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_some_satisfies!`]: crate::debug_assert_some_satisfies!
+#[macro_export]
+macro_rules! assert_some_satisfies {
+    ($cond:expr, $binding:ident => $predicate:expr $(,)?) => {
+        match $cond {
+            ::core::option::Option::Some(t) => {
+                if !$crate::__private::satisfies(&t, |$binding| $predicate) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected Some(_) to satisfy predicate
+    predicate: `{} => {}`,
+    value: `{:?}`"#, ::core::stringify!($binding), ::core::stringify!($predicate), (&$crate::__private::MaybeDebug(&t)).maybe_debug());
+                }
+                t
+            }
+            ::core::option::Option::None => {
+                ::core::panic!(r#"assertion failed, expected Some(_), got None
+    expression: `{}`"#, ::core::stringify!($cond));
+            }
+        }
+    };
+    ($cond:expr, $binding:ident => $predicate:expr, $($arg:tt)+) => {
+        match $cond {
+            ::core::option::Option::Some(t) => {
+                if !$crate::__private::satisfies(&t, |$binding| $predicate) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected Some(_) to satisfy predicate
+    predicate: `{} => {}`,
+    value: `{:?}`: {}"#, ::core::stringify!($binding), ::core::stringify!($predicate), (&$crate::__private::MaybeDebug(&t)).maybe_debug(), ::core::format_args!($($arg)+));
+                }
+                t
+            }
+            ::core::option::Option::None => {
+                ::core::panic!(r#"assertion failed, expected Some(_), got None
+    expression: `{}`: {}"#, ::core::stringify!($cond), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+    ($cond:expr, $predicate:expr $(,)?) => {
+        match $cond {
+            ::core::option::Option::Some(t) => {
+                if !$crate::__private::satisfies(&t, $predicate) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected Some(_) to satisfy predicate
+    predicate: `{}`,
+    value: `{:?}`"#, ::core::stringify!($predicate), (&$crate::__private::MaybeDebug(&t)).maybe_debug());
+                }
+                t
+            }
+            ::core::option::Option::None => {
+                ::core::panic!(r#"assertion failed, expected Some(_), got None
+    expression: `{}`"#, ::core::stringify!($cond));
+            }
+        }
+    };
+    ($cond:expr, $predicate:expr, $($arg:tt)+) => {
+        match $cond {
+            ::core::option::Option::Some(t) => {
+                if !$crate::__private::satisfies(&t, $predicate) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{IsDebug as _, IsNotDebug as _};
+                    ::core::panic!(r#"assertion failed, expected Some(_) to satisfy predicate
+    predicate: `{}`,
+    value: `{:?}`: {}"#, ::core::stringify!($predicate), (&$crate::__private::MaybeDebug(&t)).maybe_debug(), ::core::format_args!($($arg)+));
+                }
+                t
+            }
+            ::core::option::Option::None => {
+                ::core::panic!(r#"assertion failed, expected Some(_), got None
+    expression: `{}`: {}"#, ::core::stringify!($cond), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that the expression matches a [`Some(_)`] variant whose contained value satisfies the
+/// predicate on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_some_satisfies!`] on debug builds, although it
+/// does not return the value contained in the `Some` variant. On release builds it is a no-op.
+///
+/// [`Some(_)`]: https://doc.rust-lang.org/core/option/enum.Option.html#variant.Some
+#[macro_export]
+macro_rules! debug_assert_some_satisfies {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_some_satisfies!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{string::String, vec, vec::Vec};
+
+    #[test]
+    fn some_satisfies() {
+        assert_some_satisfies!(Some(42), |v| *v > 0);
+    }
+
+    #[test]
+    fn some_satisfies_binding() {
+        assert_some_satisfies!(Some(42), v => *v > 0);
+    }
+
+    #[test]
+    fn some_satisfies_method_call() {
+        let option: Option<Vec<u32>> = Some(vec![1, 2, 3]);
+        assert_some_satisfies!(option, |v| v.len() == 3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_) to satisfy predicate\n    predicate: `|v| *v > 0`,\n    value: `-1`"
+    )]
+    fn some_not_satisfies() {
+        assert_some_satisfies!(Some(-1), |v| *v > 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_) to satisfy predicate\n    predicate: `v => *v > 0`,\n    value: `-1`"
+    )]
+    fn some_not_satisfies_binding() {
+        assert_some_satisfies!(Some(-1), v => *v > 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_) to satisfy predicate\n    predicate: `|_| false`,\n    value: `<non-Debug>`"
+    )]
+    fn some_not_satisfies_non_debug() {
+        struct NonDebug;
+
+        assert_some_satisfies!(Some(NonDebug), |_| false);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_) to satisfy predicate\n    predicate: `|v| *v > 0`,\n    value: `-1`: foo"
+    )]
+    fn some_not_satisfies_custom_message() {
+        assert_some_satisfies!(Some(-1), |v| *v > 0, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_), got None\n    expression: `option`"
+    )]
+    fn none() {
+        let option: Option<i32> = None;
+        assert_some_satisfies!(option, |v| *v > 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_), got None\n    expression: `option`: foo"
+    )]
+    fn none_custom_message() {
+        let option: Option<i32> = None;
+        assert_some_satisfies!(option, v => *v > 0, "foo");
+    }
+
+    #[test]
+    fn value_returned() {
+        let option = Some(String::from("foo"));
+        let value = assert_some_satisfies!(option, |v| v.starts_with('f'));
+        assert_eq!(value, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_some_satisfies() {
+        debug_assert_some_satisfies!(Some(42), |v| *v > 0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_) to satisfy predicate\n    predicate: `|v| *v > 0`,\n    value: `-1`"
+    )]
+    fn debug_some_not_satisfies() {
+        debug_assert_some_satisfies!(Some(-1), |v| *v > 0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Some(_) to satisfy predicate\n    predicate: `|v| *v > 0`,\n    value: `-1`: foo"
+    )]
+    fn debug_some_not_satisfies_custom_message() {
+        debug_assert_some_satisfies!(Some(-1), |v| *v > 0, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_some_not_satisfies() {
+        debug_assert_some_satisfies!(Some(-1), |v| *v > 0);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_some_satisfies!(Some(42), |v| *v > 0);
+    }
+}
@@ -0,0 +1,203 @@
+/// Asserts that the expression matches a [`Some(Some(_))`] variant, returning the innermost
+/// value.
+///
+/// This is useful for nested `Option<Option<T>>` values, such as those returned when looking up an
+/// optional value in a sparse data structure. An outer `None` and a `Some(None)` panic with
+/// distinct messages, so that it is clear which level of the value was missing. On failure, the
+/// panic message includes the asserted expression as written in the source.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_some_some!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let cells: Vec<Option<u32>> = vec![Some(1), None, Some(3)];
+///
+/// assert_some_some!(cells.get(0).cloned());
+///
+/// // With a custom message
+/// assert_some_some!(cells.get(2).cloned(), "Expecting cell {} to be set", 2);
+/// # }
+/// ```
+///
+/// The innermost value will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let nested = Some(Some(42));
+///
+/// let value = assert_some_some!(nested);
+/// assert_eq!(value, 42);
+/// # }
+/// ```
+///
+/// A `Some(None)` variant will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let nested: Option<Option<u32>> = Some(None);
+///
+/// assert_some_some!(nested);  // Will panic
+/// # }
+/// ```
+///
+/// [`Some(Some(_))`]: https://doc.rust-lang.org/core/option/enum.Option.html#variant.Some
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_some_some!`]: crate::debug_assert_some_some!
+#[macro_export]
+macro_rules! assert_some_some {
+    ($cond:expr $(,)?) => {
+        match $cond {
+            ::core::option::Option::Some(::core::option::Option::Some(t)) => t,
+            ::core::option::Option::Some(::core::option::Option::None) => {
+                ::core::panic!(r#"assertion failed, expected Some(Some(_)), got Some(None)
+    expression: `{}`"#, ::core::stringify!($cond));
+            }
+            ::core::option::Option::None => {
+                ::core::panic!(r#"assertion failed, expected Some(Some(_)), got None
+    expression: `{}`"#, ::core::stringify!($cond));
+            }
+        }
+    };
+    ($cond:expr, $($arg:tt)+) => {
+        match $cond {
+            ::core::option::Option::Some(::core::option::Option::Some(t)) => t,
+            ::core::option::Option::Some(::core::option::Option::None) => {
+                ::core::panic!(r#"assertion failed, expected Some(Some(_)), got Some(None)
+    expression: `{}`: {}"#, ::core::stringify!($cond), ::core::format_args!($($arg)+));
+            }
+            ::core::option::Option::None => {
+                ::core::panic!(r#"assertion failed, expected Some(Some(_)), got None
+    expression: `{}`: {}"#, ::core::stringify!($cond), ::core::format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that the expression matches a [`Some(Some(_))`] variant on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_some_some!`] on debug builds, although it does
+/// not return the innermost value. On release builds it is a no-op.
+///
+/// [`Some(Some(_))`]: https://doc.rust-lang.org/core/option/enum.Option.html#variant.Some
+#[macro_export]
+macro_rules! debug_assert_some_some {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_some_some!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn some_some() {
+        assert_some_some!(Some(Some(())));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Some(_)), got Some(None)\n    expression: `nested`"
+    )]
+    fn some_none() {
+        let nested = Some(None::<()>);
+        assert_some_some!(nested);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Some(_)), got None\n    expression: `nested`"
+    )]
+    fn none() {
+        let nested = None::<Option<()>>;
+        assert_some_some!(nested);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Some(_)), got Some(None)\n    expression: `nested`: foo"
+    )]
+    fn some_none_custom_message() {
+        let nested = Some(None::<()>);
+        assert_some_some!(nested, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Some(_)), got None\n    expression: `nested`: foo"
+    )]
+    fn none_custom_message() {
+        let nested = None::<Option<()>>;
+        assert_some_some!(nested, "foo");
+    }
+
+    #[test]
+    fn value_returned() {
+        let value = assert_some_some!(Some(Some(42)));
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_some_some() {
+        debug_assert_some_some!(Some(Some(())));
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Some(_)), got Some(None)\n    expression: `nested`"
+    )]
+    fn debug_some_none() {
+        let nested = Some(None::<()>);
+        debug_assert_some_some!(nested);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Some(_)), got None\n    expression: `nested`"
+    )]
+    fn debug_none() {
+        let nested = None::<Option<()>>;
+        debug_assert_some_some!(nested);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected Some(Some(_)), got Some(None)\n    expression: `nested`: foo"
+    )]
+    fn debug_some_none_custom_message() {
+        let nested = Some(None::<()>);
+        debug_assert_some_some!(nested, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_some_none() {
+        let nested = Some(None::<()>);
+        debug_assert_some_some!(nested);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_some_some!(Some(Some(())));
+    }
+}
@@ -0,0 +1,233 @@
+/// Asserts that the elements of a collection or iterator are sorted in non-decreasing order.
+///
+/// Works with anything that can be iterated over by reference, such as arrays, slices, and `Vec`s,
+/// as well as with cloneable iterators. The collection is only borrowed. Adjacent elements are
+/// compared using [`PartialOrd`], and each element must be less than or equal to the next one.
+/// Elements that cannot be compared, such as `NaN`, are therefore reported as not sorted. See
+/// [`assert_strictly_sorted!`] to also reject equal adjacent elements.
+///
+/// On failure, the index of the first element that is greater than its successor is printed, along
+/// with both elements.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_sorted!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let values = vec![1, 2, 2, 3];
+/// assert_sorted!(values);
+///
+/// assert_sorted!(values.iter().map(|value| value * 2));
+///
+/// // With a custom message
+/// assert_sorted!(values, "Expecting {:?} to be sorted before merging", values);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_sorted!([1, 3, 2]);  // Will panic
+/// # }
+/// ```
+///
+/// [`PartialOrd`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html
+/// [`assert_strictly_sorted!`]: crate::assert_strictly_sorted!
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_sorted!`]: crate::debug_assert_sorted!
+#[macro_export]
+macro_rules! assert_sorted {
+    ($collection:expr $(,)?) => {
+        match &$collection {
+            collection_val => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+                if let ::core::option::Option::Some(inversion) = (&&&&&$crate::__private::Collection(collection_val)).view().first_inversion_by(|left, right| left <= right) {
+                    ::core::panic!(r#"assertion failed: `(collection[index] <= collection[index + 1])`
+    index: `{}`,
+    collection[index]: `{:?}`,
+    collection[index + 1]: `{:?}`"#, inversion.index, inversion.left, inversion.right)
+                }
+            }
+        }
+    };
+    ($collection:expr, $($arg:tt)+) => {
+        match &$collection {
+            collection_val => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+                if let ::core::option::Option::Some(inversion) = (&&&&&$crate::__private::Collection(collection_val)).view().first_inversion_by(|left, right| left <= right) {
+                    ::core::panic!(r#"assertion failed: `(collection[index] <= collection[index + 1])`
+    index: `{}`,
+    collection[index]: `{:?}`,
+    collection[index + 1]: `{:?}`: {}"#, inversion.index, inversion.left, inversion.right, ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that the elements of a collection or iterator are sorted in non-decreasing order on
+/// debug builds.
+///
+/// This macro behaves the same as [`assert_sorted!`] on debug builds. On release builds it is a
+/// no-op.
+#[macro_export]
+macro_rules! debug_assert_sorted {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_sorted!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{vec, vec::Vec};
+
+    #[test]
+    fn array() {
+        assert_sorted!([1, 2, 3]);
+    }
+
+    #[test]
+    fn slice() {
+        let values: &[u32] = &[1, 2, 3];
+        assert_sorted!(values);
+    }
+
+    #[test]
+    fn vec() {
+        let values = vec![1, 2, 3];
+        assert_sorted!(values);
+        assert_sorted!(&values);
+    }
+
+    #[test]
+    fn equal_adjacent_elements() {
+        assert_sorted!([1, 2, 2, 3]);
+    }
+
+    #[test]
+    fn empty() {
+        let values: Vec<u32> = Vec::new();
+        assert_sorted!(values);
+    }
+
+    #[test]
+    fn single_element() {
+        assert_sorted!([1]);
+    }
+
+    #[test]
+    fn floats() {
+        assert_sorted!([-1.5, 0.0, 0.0, 2.5]);
+    }
+
+    #[test]
+    fn iterator() {
+        let values = [3, 1, 2];
+        assert_sorted!(values.iter().filter(|value| **value < 3));
+        assert_sorted!(0..10);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] <= collection[index + 1])`\n    index: `0`,\n    collection[index]: `2`,\n    collection[index + 1]: `1`"
+    )]
+    fn inversion_at_start() {
+        assert_sorted!([2, 1, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] <= collection[index + 1])`\n    index: `1`,\n    collection[index]: `3`,\n    collection[index + 1]: `2`"
+    )]
+    fn inversion_in_middle() {
+        assert_sorted!(vec![1, 3, 2, 4]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] <= collection[index + 1])`\n    index: `2`,\n    collection[index]: `4`,\n    collection[index + 1]: `3`"
+    )]
+    fn inversion_at_end() {
+        let values: &[u32] = &[1, 2, 4, 3];
+        assert_sorted!(values);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] <= collection[index + 1])`\n    index: `1`,\n    collection[index]: `3`,\n    collection[index + 1]: `2`"
+    )]
+    fn first_inversion_reported() {
+        assert_sorted!([1, 3, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] <= collection[index + 1])`\n    index: `0`,\n    collection[index]: `1.0`,\n    collection[index + 1]: `NaN`"
+    )]
+    fn nan() {
+        assert_sorted!([1.0, core::f64::NAN, 2.0]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] <= collection[index + 1])`\n    index: `1`,\n    collection[index]: `6`,\n    collection[index + 1]: `2`"
+    )]
+    fn iterator_not_sorted() {
+        let values = [1, 3, 1];
+        assert_sorted!(values.iter().map(|value| value * 2));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] <= collection[index + 1])`\n    index: `1`,\n    collection[index]: `3`,\n    collection[index + 1]: `2`: foo"
+    )]
+    fn not_sorted_custom_message() {
+        assert_sorted!([1, 3, 2], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_sorted() {
+        debug_assert_sorted!([1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] <= collection[index + 1])`\n    index: `1`,\n    collection[index]: `3`,\n    collection[index + 1]: `2`"
+    )]
+    fn debug_not_sorted() {
+        debug_assert_sorted!([1, 3, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] <= collection[index + 1])`\n    index: `1`,\n    collection[index]: `3`,\n    collection[index + 1]: `2`: foo"
+    )]
+    fn debug_not_sorted_custom_message() {
+        debug_assert_sorted!([1, 3, 2], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_sorted() {
+        debug_assert_sorted!([1, 3, 2]);
+    }
+}
@@ -0,0 +1,189 @@
+/// Asserts that the elements of a collection or iterator are sorted according to the given
+/// comparison function.
+///
+/// Works with the same values as [`assert_sorted!`]. The comparison function is called with
+/// references to each pair of adjacent elements, and must return `true` if they are in order. This
+/// matches the comparison function of [`slice::is_sorted_by`].
+///
+/// On failure, the index of the first element for which the comparison function returned `false`
+/// is printed, along with both elements. Elements that do not implement [`Debug`] are printed as
+/// `<non-Debug>`.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_sorted_by!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let values = vec![5, 3, 3, 1];
+/// assert_sorted_by!(values, |a, b| a >= b);
+///
+/// let words = ["apple", "Banana", "cherry"];
+/// assert_sorted_by!(words, |a, b| a.to_lowercase() <= b.to_lowercase());
+///
+/// // With a custom message
+/// assert_sorted_by!(values, |a, b| a >= b, "Expecting {:?} in descending order", values);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_sorted_by!([5, 1, 3], |a, b| a >= b);  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_sorted!`]: crate::assert_sorted!
+/// [`slice::is_sorted_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.is_sorted_by
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_sorted_by!`]: crate::debug_assert_sorted_by!
+#[macro_export]
+macro_rules! assert_sorted_by {
+    ($collection:expr, $compare:expr $(,)?) => {
+        match &$collection {
+            collection_val => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _, ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+                if let ::core::option::Option::Some(inversion) = (&&&&&$crate::__private::Collection(collection_val)).view().first_inversion_by($compare) {
+                    ::core::panic!(r#"assertion failed: `compare(collection[index], collection[index + 1])`
+    index: `{}`,
+    collection[index]: `{:?}`,
+    collection[index + 1]: `{:?}`"#, inversion.index, (&$crate::__private::MaybeDebug(&inversion.left)).maybe_debug(), (&$crate::__private::MaybeDebug(&inversion.right)).maybe_debug())
+                }
+            }
+        }
+    };
+    ($collection:expr, $compare:expr, $($arg:tt)+) => {
+        match &$collection {
+            collection_val => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _, ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+                if let ::core::option::Option::Some(inversion) = (&&&&&$crate::__private::Collection(collection_val)).view().first_inversion_by($compare) {
+                    ::core::panic!(r#"assertion failed: `compare(collection[index], collection[index + 1])`
+    index: `{}`,
+    collection[index]: `{:?}`,
+    collection[index + 1]: `{:?}`: {}"#, inversion.index, (&$crate::__private::MaybeDebug(&inversion.left)).maybe_debug(), (&$crate::__private::MaybeDebug(&inversion.right)).maybe_debug(), ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that the elements of a collection or iterator are sorted according to the given
+/// comparison function on debug builds.
+///
+/// This macro behaves the same as [`assert_sorted_by!`] on debug builds. On release builds it is
+/// a no-op.
+#[macro_export]
+macro_rules! debug_assert_sorted_by {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_sorted_by!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{vec, vec::Vec};
+
+    struct NonDebug(u32);
+
+    #[test]
+    fn descending() {
+        let values = vec![5, 3, 3, 1];
+        assert_sorted_by!(values, |a, b| a >= b);
+        assert_sorted_by!(&values, |a, b| a >= b);
+    }
+
+    #[test]
+    fn empty() {
+        let values: Vec<u32> = Vec::new();
+        assert_sorted_by!(values, |a, b| a >= b);
+    }
+
+    #[test]
+    fn iterator() {
+        assert_sorted_by!((0..10).rev(), |a, b| a > b);
+    }
+
+    #[test]
+    fn non_debug() {
+        assert_sorted_by!([NonDebug(1), NonDebug(2)], |a, b| a.0 < b.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `compare(collection[index], collection[index + 1])`\n    index: `0`,\n    collection[index]: `5`,\n    collection[index + 1]: `6`"
+    )]
+    fn not_sorted_at_start() {
+        assert_sorted_by!([5, 6, 3], |a, b| a >= b);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `compare(collection[index], collection[index + 1])`\n    index: `1`,\n    collection[index]: `1`,\n    collection[index + 1]: `3`"
+    )]
+    fn not_sorted_in_middle() {
+        assert_sorted_by!(vec![5, 1, 3, 0], |a, b| a >= b);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `compare(collection[index], collection[index + 1])`\n    index: `0`,\n    collection[index]: `<non-Debug>`,\n    collection[index + 1]: `<non-Debug>`"
+    )]
+    fn not_sorted_non_debug() {
+        assert_sorted_by!([NonDebug(2), NonDebug(1)], |a, b| a.0 < b.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `compare(collection[index], collection[index + 1])`\n    index: `0`,\n    collection[index]: `5`,\n    collection[index + 1]: `6`: foo"
+    )]
+    fn not_sorted_custom_message() {
+        assert_sorted_by!([5, 6, 3], |a, b| a >= b, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_descending() {
+        debug_assert_sorted_by!([5, 3, 1], |a, b| a >= b);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `compare(collection[index], collection[index + 1])`\n    index: `0`,\n    collection[index]: `5`,\n    collection[index + 1]: `6`"
+    )]
+    fn debug_not_sorted() {
+        debug_assert_sorted_by!([5, 6, 3], |a, b| a >= b);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `compare(collection[index], collection[index + 1])`\n    index: `0`,\n    collection[index]: `5`,\n    collection[index + 1]: `6`: foo"
+    )]
+    fn debug_not_sorted_custom_message() {
+        debug_assert_sorted_by!([5, 6, 3], |a, b| a >= b, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_sorted() {
+        debug_assert_sorted_by!([5, 6, 3], |a, b| a >= b);
+    }
+}
@@ -0,0 +1,254 @@
+/// Asserts that the elements of a collection or iterator are sorted in non-decreasing order of
+/// the given key function.
+///
+/// Works with the same values as [`assert_sorted!`]. The key function is called once for each
+/// element with a reference to it, and the keys are compared using [`PartialOrd`]. Elements with
+/// equal keys may appear in any order.
+///
+/// On failure, the index of the first element whose key is greater than its successor's key is
+/// printed, along with both keys and both elements. Elements that do not implement [`Debug`] are
+/// printed as `<non-Debug>`.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_sorted_by_key!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// struct Event {
+///     timestamp: u64,
+///     name: &'static str,
+/// }
+///
+/// let events = vec![
+///     Event { timestamp: 1, name: "start" },
+///     Event { timestamp: 5, name: "tick" },
+///     Event { timestamp: 5, name: "tock" },
+/// ];
+/// assert_sorted_by_key!(events, |event| event.timestamp);
+///
+/// // With a custom message
+/// assert_sorted_by_key!(events, |event| event.timestamp, "Expecting events in order");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_sorted_by_key!([-1i32, 3, -2], |value| value.abs());  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_sorted!`]: crate::assert_sorted!
+/// [`PartialOrd`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_sorted_by_key!`]: crate::debug_assert_sorted_by_key!
+#[macro_export]
+macro_rules! assert_sorted_by_key {
+    ($collection:expr, $key:expr $(,)?) => {
+        match &$collection {
+            collection_val => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _, ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+                if let ::core::option::Option::Some((inversion, left_key, right_key)) = (&&&&&$crate::__private::Collection(collection_val)).view().first_inversion_by_key($key) {
+                    ::core::panic!(r#"assertion failed: `(key(collection[index]) <= key(collection[index + 1]))`
+    index: `{}`,
+    key(collection[index]): `{:?}`,
+    key(collection[index + 1]): `{:?}`,
+    collection[index]: `{:?}`,
+    collection[index + 1]: `{:?}`"#, inversion.index, left_key, right_key, (&$crate::__private::MaybeDebug(&inversion.left)).maybe_debug(), (&$crate::__private::MaybeDebug(&inversion.right)).maybe_debug())
+                }
+            }
+        }
+    };
+    ($collection:expr, $key:expr, $($arg:tt)+) => {
+        match &$collection {
+            collection_val => {
+                #[allow(unused_imports)]
+                use $crate::__private::{IsDebug as _, IsNotDebug as _, ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+                if let ::core::option::Option::Some((inversion, left_key, right_key)) = (&&&&&$crate::__private::Collection(collection_val)).view().first_inversion_by_key($key) {
+                    ::core::panic!(r#"assertion failed: `(key(collection[index]) <= key(collection[index + 1]))`
+    index: `{}`,
+    key(collection[index]): `{:?}`,
+    key(collection[index + 1]): `{:?}`,
+    collection[index]: `{:?}`,
+    collection[index + 1]: `{:?}`: {}"#, inversion.index, left_key, right_key, (&$crate::__private::MaybeDebug(&inversion.left)).maybe_debug(), (&$crate::__private::MaybeDebug(&inversion.right)).maybe_debug(), ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that the elements of a collection or iterator are sorted in non-decreasing order of
+/// the given key function on debug builds.
+///
+/// This macro behaves the same as [`assert_sorted_by_key!`] on debug builds. On release builds it
+/// is a no-op.
+#[macro_export]
+macro_rules! debug_assert_sorted_by_key {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_sorted_by_key!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, vec, vec::Vec};
+
+    #[derive(Debug)]
+    struct Event {
+        timestamp: u64,
+        name: &'static str,
+    }
+
+    struct NonDebugEvent {
+        timestamp: u64,
+    }
+
+    #[test]
+    fn sorted() {
+        let events = vec![
+            Event {
+                timestamp: 1,
+                name: "start",
+            },
+            Event {
+                timestamp: 2,
+                name: "tick",
+            },
+        ];
+        assert_sorted_by_key!(events, |event| event.timestamp);
+        assert_sorted_by_key!(&events, |event| event.name);
+    }
+
+    #[test]
+    fn equal_keys_different_elements() {
+        let events = [
+            Event {
+                timestamp: 1,
+                name: "start",
+            },
+            Event {
+                timestamp: 1,
+                name: "tick",
+            },
+            Event {
+                timestamp: 1,
+                name: "stop",
+            },
+        ];
+        assert_sorted_by_key!(events, |event| event.timestamp);
+    }
+
+    #[test]
+    fn empty() {
+        let events: Vec<Event> = Vec::new();
+        assert_sorted_by_key!(events, |event| event.timestamp);
+    }
+
+    #[test]
+    fn iterator() {
+        assert_sorted_by_key!((0..10).map(|value| value * 2), |value| value / 4);
+    }
+
+    #[test]
+    fn key_called_once_per_element() {
+        let calls = Cell::new(0);
+        assert_sorted_by_key!([1, 2, 3, 4], |value| {
+            calls.set(calls.get() + 1);
+            *value
+        });
+        assert_eq!(calls.get(), 4);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(key(collection[index]) <= key(collection[index + 1]))`\n    index: `0`,\n    key(collection[index]): `2`,\n    key(collection[index + 1]): `1`,\n    collection[index]: `Event { timestamp: 2, name: \"tick\" }`,\n    collection[index + 1]: `Event { timestamp: 1, name: \"start\" }`"
+    )]
+    fn not_sorted() {
+        let events = vec![
+            Event {
+                timestamp: 2,
+                name: "tick",
+            },
+            Event {
+                timestamp: 1,
+                name: "start",
+            },
+        ];
+        assert_sorted_by_key!(events, |event| event.timestamp);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(key(collection[index]) <= key(collection[index + 1]))`\n    index: `1`,\n    key(collection[index]): `3`,\n    key(collection[index + 1]): `2`,\n    collection[index]: `3`,\n    collection[index + 1]: `-2`"
+    )]
+    fn not_sorted_in_middle() {
+        assert_sorted_by_key!([-1i32, 3, -2], |value| value.abs());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(key(collection[index]) <= key(collection[index + 1]))`\n    index: `0`,\n    key(collection[index]): `2`,\n    key(collection[index + 1]): `1`,\n    collection[index]: `<non-Debug>`,\n    collection[index + 1]: `<non-Debug>`"
+    )]
+    fn not_sorted_non_debug() {
+        let events = [
+            NonDebugEvent { timestamp: 2 },
+            NonDebugEvent { timestamp: 1 },
+        ];
+        assert_sorted_by_key!(events, |event| event.timestamp);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(key(collection[index]) <= key(collection[index + 1]))`\n    index: `1`,\n    key(collection[index]): `3`,\n    key(collection[index + 1]): `2`,\n    collection[index]: `3`,\n    collection[index + 1]: `-2`: foo"
+    )]
+    fn not_sorted_custom_message() {
+        assert_sorted_by_key!([-1i32, 3, -2], |value| value.abs(), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_sorted() {
+        debug_assert_sorted_by_key!([-1i32, 2, -3], |value| value.abs());
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(key(collection[index]) <= key(collection[index + 1]))`\n    index: `1`,\n    key(collection[index]): `3`,\n    key(collection[index + 1]): `2`,\n    collection[index]: `3`,\n    collection[index + 1]: `-2`"
+    )]
+    fn debug_not_sorted() {
+        debug_assert_sorted_by_key!([-1i32, 3, -2], |value| value.abs());
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(key(collection[index]) <= key(collection[index + 1]))`\n    index: `1`,\n    key(collection[index]): `3`,\n    key(collection[index + 1]): `2`,\n    collection[index]: `3`,\n    collection[index + 1]: `-2`: foo"
+    )]
+    fn debug_not_sorted_custom_message() {
+        debug_assert_sorted_by_key!([-1i32, 3, -2], |value| value.abs(), "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_sorted() {
+        debug_assert_sorted_by_key!([-1i32, 3, -2], |value| value.abs());
+    }
+}
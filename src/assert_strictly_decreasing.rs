@@ -0,0 +1,114 @@
+/// Asserts that the elements of a collection or iterator are strictly decreasing.
+///
+/// Works with anything that can be iterated over by reference, such as arrays, slices, and `Vec`s,
+/// as well as with cloneable iterators. The collection is only borrowed. Adjacent elements are
+/// compared using [`PartialOrd`], and each element must be strictly greater than the next one.
+/// Collections with zero or one elements always pass. Elements that cannot be compared, such as
+/// `NaN`, are reported as a violation. This is [`assert_sorted_by!`] with `>` as the comparison
+/// function. See [`assert_monotonic_decreasing!`] to allow equal adjacent elements.
+///
+/// On failure, the index of the first element that is less than or equal to its successor is
+/// printed, along with both elements, pointing at where the sequence stopped being strictly
+/// decreasing.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled. See
+/// [`debug_assert_strictly_decreasing!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let countdown = vec![10, 5, 3, 1];
+/// assert_strictly_decreasing!(countdown);
+///
+/// assert_strictly_decreasing!(countdown.iter().map(|n| n * 2));
+///
+/// // With a custom message
+/// assert_strictly_decreasing!(countdown, "Expecting {:?} to be strictly decreasing", countdown);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_strictly_decreasing!([3, 2, 2, 1]);  // Will panic
+/// # }
+/// ```
+///
+/// [`PartialOrd`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html
+/// [`assert_monotonic_decreasing!`]: crate::assert_monotonic_decreasing!
+/// [`assert_sorted_by!`]: crate::assert_sorted_by!
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_strictly_decreasing!`]: crate::debug_assert_strictly_decreasing!
+#[macro_export]
+macro_rules! assert_strictly_decreasing {
+    ($collection:expr $(,)?) => {
+        $crate::assert_sorted_by!($collection, |left, right| left > right)
+    };
+    ($collection:expr, $($arg:tt)+) => {
+        $crate::assert_sorted_by!($collection, |left, right| left > right, $($arg)+)
+    };
+}
+
+/// Asserts that the elements of a collection or iterator are strictly decreasing on debug builds.
+///
+/// This macro behaves the same as [`assert_strictly_decreasing!`] on debug builds. On release
+/// builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_strictly_decreasing {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_strictly_decreasing!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn strictly_decreasing() {
+        assert_strictly_decreasing!([8, 5, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `compare(collection[index], collection[index + 1])`\n    index: `2`,\n    collection[index]: `1`,\n    collection[index + 1]: `1`"
+    )]
+    fn violation() {
+        assert_strictly_decreasing!([3, 2, 1, 1]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `compare(collection[index], collection[index + 1])`\n    index: `2`,\n    collection[index]: `1`,\n    collection[index + 1]: `1`: foo"
+    )]
+    fn violation_custom_message() {
+        assert_strictly_decreasing!([3, 2, 1, 1], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `compare(collection[index], collection[index + 1])`\n    index: `2`,\n    collection[index]: `1`,\n    collection[index + 1]: `1`"
+    )]
+    fn debug_violation() {
+        debug_assert_strictly_decreasing!([3, 2, 1, 1]);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_violation() {
+        debug_assert_strictly_decreasing!([3, 2, 1, 1]);
+    }
+}
@@ -0,0 +1,112 @@
+/// Asserts that the elements of a collection or iterator are strictly increasing.
+///
+/// Works with anything that can be iterated over by reference, such as arrays, slices, and `Vec`s,
+/// as well as with cloneable iterators. The collection is only borrowed. Adjacent elements are
+/// compared using [`PartialOrd`], and each element must be strictly less than the next one.
+/// Collections with zero or one elements always pass. Elements that cannot be compared, such as
+/// `NaN`, are reported as a violation. This is an alias of [`assert_strictly_sorted!`], named for
+/// sequences such as sequence numbers. See [`assert_monotonic_increasing!`] to allow equal
+/// adjacent elements.
+///
+/// On failure, the index of the first element that is greater than or equal to its successor is
+/// printed, along with both elements, pointing at where the sequence stopped being strictly
+/// increasing.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled. See
+/// [`debug_assert_strictly_increasing!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let sequence_numbers = vec![1, 2, 5, 8];
+/// assert_strictly_increasing!(sequence_numbers);
+///
+/// assert_strictly_increasing!(sequence_numbers.iter().map(|n| n * 2));
+///
+/// // With a custom message
+/// assert_strictly_increasing!(sequence_numbers, "Expecting no reordered or repeated numbers");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_strictly_increasing!([1, 2, 2, 3]);  // Will panic
+/// # }
+/// ```
+///
+/// [`PartialOrd`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html
+/// [`assert_strictly_sorted!`]: crate::assert_strictly_sorted!
+/// [`assert_monotonic_increasing!`]: crate::assert_monotonic_increasing!
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_strictly_increasing!`]: crate::debug_assert_strictly_increasing!
+#[macro_export]
+macro_rules! assert_strictly_increasing {
+    ($($arg:tt)*) => {
+        $crate::assert_strictly_sorted!($($arg)*)
+    };
+}
+
+/// Asserts that the elements of a collection or iterator are strictly increasing on debug builds.
+///
+/// This macro behaves the same as [`assert_strictly_increasing!`] on debug builds. On release
+/// builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_strictly_increasing {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_strictly_increasing!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn strictly_increasing() {
+        assert_strictly_increasing!([1, 2, 5, 8]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] < collection[index + 1])`\n    index: `2`,\n    collection[index]: `3`,\n    collection[index + 1]: `3`"
+    )]
+    fn violation() {
+        assert_strictly_increasing!([1, 2, 3, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] < collection[index + 1])`\n    index: `2`,\n    collection[index]: `3`,\n    collection[index + 1]: `3`: foo"
+    )]
+    fn violation_custom_message() {
+        assert_strictly_increasing!([1, 2, 3, 3], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] < collection[index + 1])`\n    index: `2`,\n    collection[index]: `3`,\n    collection[index + 1]: `3`"
+    )]
+    fn debug_violation() {
+        debug_assert_strictly_increasing!([1, 2, 3, 3]);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_violation() {
+        debug_assert_strictly_increasing!([1, 2, 3, 3]);
+    }
+}
@@ -0,0 +1,189 @@
+/// Asserts that the elements of a collection or iterator are sorted in strictly increasing order.
+///
+/// This macro behaves the same as [`assert_sorted!`], except that each element must be strictly
+/// less than the next one, so equal adjacent elements are rejected. This is useful for checking
+/// that a sorted collection also contains no duplicates.
+///
+/// On failure, the index of the first element that is not less than its successor is printed,
+/// along with both elements.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_strictly_sorted!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let keys = vec![1, 2, 5];
+/// assert_strictly_sorted!(keys);
+///
+/// // With a custom message
+/// assert_strictly_sorted!(keys, "Expecting {:?} to be sorted without duplicates", keys);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_strictly_sorted!([1, 2, 2, 3]);  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_sorted!`]: crate::assert_sorted!
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_strictly_sorted!`]: crate::debug_assert_strictly_sorted!
+#[macro_export]
+macro_rules! assert_strictly_sorted {
+    ($collection:expr $(,)?) => {
+        match &$collection {
+            collection_val => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+                if let ::core::option::Option::Some(inversion) = (&&&&&$crate::__private::Collection(collection_val)).view().first_inversion_by(|left, right| left < right) {
+                    ::core::panic!(r#"assertion failed: `(collection[index] < collection[index + 1])`
+    index: `{}`,
+    collection[index]: `{:?}`,
+    collection[index + 1]: `{:?}`"#, inversion.index, inversion.left, inversion.right)
+                }
+            }
+        }
+    };
+    ($collection:expr, $($arg:tt)+) => {
+        match &$collection {
+            collection_val => {
+                #[allow(unused_imports)]
+                use $crate::__private::{ViewCollection as _, ViewCollectionRef as _, ViewIterator as _, ViewString as _, ViewTakenIterator as _};
+                if let ::core::option::Option::Some(inversion) = (&&&&&$crate::__private::Collection(collection_val)).view().first_inversion_by(|left, right| left < right) {
+                    ::core::panic!(r#"assertion failed: `(collection[index] < collection[index + 1])`
+    index: `{}`,
+    collection[index]: `{:?}`,
+    collection[index + 1]: `{:?}`: {}"#, inversion.index, inversion.left, inversion.right, ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that the elements of a collection or iterator are sorted in strictly increasing order
+/// on debug builds.
+///
+/// This macro behaves the same as [`assert_strictly_sorted!`] on debug builds. On release builds
+/// it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_strictly_sorted {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_strictly_sorted!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{vec, vec::Vec};
+
+    #[test]
+    fn array() {
+        assert_strictly_sorted!([1, 2, 3]);
+    }
+
+    #[test]
+    fn vec() {
+        let values = vec![1, 2, 3];
+        assert_strictly_sorted!(values);
+        assert_strictly_sorted!(&values);
+    }
+
+    #[test]
+    fn empty() {
+        let values: Vec<u32> = Vec::new();
+        assert_strictly_sorted!(values);
+    }
+
+    #[test]
+    fn iterator() {
+        assert_strictly_sorted!(0..10);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] < collection[index + 1])`\n    index: `0`,\n    collection[index]: `1`,\n    collection[index + 1]: `1`"
+    )]
+    fn equal_at_start() {
+        assert_strictly_sorted!([1, 1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] < collection[index + 1])`\n    index: `1`,\n    collection[index]: `2`,\n    collection[index + 1]: `2`"
+    )]
+    fn equal_in_middle() {
+        assert_strictly_sorted!(vec![1, 2, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] < collection[index + 1])`\n    index: `2`,\n    collection[index]: `3`,\n    collection[index + 1]: `3`"
+    )]
+    fn equal_at_end() {
+        let values: &[u32] = &[1, 2, 3, 3];
+        assert_strictly_sorted!(values);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] < collection[index + 1])`\n    index: `1`,\n    collection[index]: `3`,\n    collection[index + 1]: `2`"
+    )]
+    fn inversion() {
+        assert_strictly_sorted!([1, 3, 2]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] < collection[index + 1])`\n    index: `1`,\n    collection[index]: `2`,\n    collection[index + 1]: `2`: foo"
+    )]
+    fn not_sorted_custom_message() {
+        assert_strictly_sorted!([1, 2, 2], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_sorted() {
+        debug_assert_strictly_sorted!([1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] < collection[index + 1])`\n    index: `1`,\n    collection[index]: `2`,\n    collection[index + 1]: `2`"
+    )]
+    fn debug_not_sorted() {
+        debug_assert_strictly_sorted!([1, 2, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(collection[index] < collection[index + 1])`\n    index: `1`,\n    collection[index]: `2`,\n    collection[index + 1]: `2`: foo"
+    )]
+    fn debug_not_sorted_custom_message() {
+        debug_assert_strictly_sorted!([1, 2, 2], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_sorted() {
+        debug_assert_strictly_sorted!([1, 2, 2]);
+    }
+}
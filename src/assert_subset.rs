@@ -0,0 +1,212 @@
+/// Asserts that every element of the left collection is contained in the right collection.
+///
+/// Both expressions can be anything implementing [`IntoIterator`], as long as they produce the
+/// same element type. To compare a `Vec` against an array, for example, pass both by reference.
+/// Elements are looked up in a [`HashSet`] when they implement [`Hash`] and [`Eq`]. Elements that
+/// only implement [`Ord`] are looked up in a [`BTreeSet`] instead. Either way, the elements must
+/// also implement [`Debug`].
+///
+/// An empty left collection is a subset of any collection. See [`assert_superset!`] for the
+/// mirrored assertion.
+///
+/// On failure, the elements of the left collection that are missing from the right collection are
+/// printed once each, in the order they first appear, along with how many of them there are.
+///
+/// This macro requires the `std` feature.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_subset!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let granted = vec!["read", "write"];
+/// let allowed = vec!["read", "write", "delete"];
+///
+/// assert_subset!(&granted, &allowed);
+///
+/// // With a custom message
+/// assert_subset!(&granted, &allowed, "Expecting only allowed scopes");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_subset!(&vec![1, 2, 4], &[1, 2, 3]);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`HashSet`]: https://doc.rust-lang.org/std/collections/struct.HashSet.html
+/// [`BTreeSet`]: https://doc.rust-lang.org/std/collections/struct.BTreeSet.html
+/// [`Hash`]: https://doc.rust-lang.org/core/hash/trait.Hash.html
+/// [`Eq`]: https://doc.rust-lang.org/core/cmp/trait.Eq.html
+/// [`Ord`]: https://doc.rust-lang.org/core/cmp/trait.Ord.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_superset!`]: crate::assert_superset!
+/// [`debug_assert_subset!`]: crate::debug_assert_subset!
+#[macro_export]
+macro_rules! assert_subset {
+    ($left:expr, $right:expr $(,)?) => {
+        match ($crate::__private::collect($left), $crate::__private::collect($right)) {
+            (left_val, right_val) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{HashElements as _, OrdElements as _};
+                let missing = (&$crate::__private::Elements(&left_val, &right_val)).not_contained();
+                if !missing.is_empty() {
+                    ::core::panic!(r#"assertion failed, expected left to be a subset of right
+    not in superset: `{:?}`,
+    count: `{}`"#, missing, missing.len())
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match ($crate::__private::collect($left), $crate::__private::collect($right)) {
+            (left_val, right_val) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{HashElements as _, OrdElements as _};
+                let missing = (&$crate::__private::Elements(&left_val, &right_val)).not_contained();
+                if !missing.is_empty() {
+                    ::core::panic!(r#"assertion failed, expected left to be a subset of right
+    not in superset: `{:?}`,
+    count: `{}`: {}"#, missing, missing.len(), ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that every element of the left collection is contained in the right collection on debug
+/// builds.
+///
+/// This macro behaves the same as [`assert_subset!`] on debug builds. On release builds it is a
+/// no-op.
+///
+/// This macro requires the `std` feature.
+#[macro_export]
+macro_rules! debug_assert_subset {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_subset!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{vec, vec::Vec};
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Scope(u32);
+
+    #[test]
+    fn subset() {
+        assert_subset!(&vec![1, 2], &[1, 2, 3]);
+    }
+
+    #[test]
+    fn subset_equal() {
+        assert_subset!(vec![1, 2, 3], vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn subset_duplicates() {
+        assert_subset!(vec![1, 1, 2, 2], vec![2, 1]);
+    }
+
+    #[test]
+    fn subset_ord_only() {
+        assert_subset!(vec![Scope(2)], vec![Scope(1), Scope(2)]);
+    }
+
+    #[test]
+    fn empty_left() {
+        let empty: Vec<u32> = Vec::new();
+        assert_subset!(&empty, &[1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected left to be a subset of right\n    not in superset: `[1, 2]`,\n    count: `2`"
+    )]
+    fn empty_right() {
+        let empty: Vec<u32> = Vec::new();
+        assert_subset!(vec![1, 2, 1], empty);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected left to be a subset of right\n    not in superset: `[4]`,\n    count: `1`"
+    )]
+    fn not_subset() {
+        assert_subset!(&vec![1, 4, 2], &[1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected left to be a subset of right\n    not in superset: `[5, 4]`,\n    count: `2`"
+    )]
+    fn not_subset_multiple() {
+        assert_subset!(vec![5, 1, 4, 5], vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected left to be a subset of right\n    not in superset: `[Scope(4)]`,\n    count: `1`"
+    )]
+    fn not_subset_ord_only() {
+        assert_subset!(vec![Scope(1), Scope(4)], vec![Scope(1), Scope(2)]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected left to be a subset of right\n    not in superset: `[4]`,\n    count: `1`: foo"
+    )]
+    fn not_subset_custom_message() {
+        assert_subset!(&vec![1, 4, 2], &[1, 2, 3], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_subset() {
+        debug_assert_subset!(&vec![1, 2], &[1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected left to be a subset of right\n    not in superset: `[4]`,\n    count: `1`"
+    )]
+    fn debug_not_subset() {
+        debug_assert_subset!(&vec![1, 4, 2], &[1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected left to be a subset of right\n    not in superset: `[4]`,\n    count: `1`: foo"
+    )]
+    fn debug_not_subset_custom_message() {
+        debug_assert_subset!(&vec![1, 4, 2], &[1, 2, 3], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_subset() {
+        debug_assert_subset!(&vec![1, 4, 2], &[1, 2, 3]);
+    }
+}
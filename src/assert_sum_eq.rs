@@ -0,0 +1,335 @@
+/// Asserts that the sum of the integers produced by an iterator is equal to the expected value,
+/// returning the sum.
+///
+/// The expression can be anything implementing [`IntoIterator`] over a primitive integer type, or
+/// references to one, and is consumed. The elements are added using checked arithmetic: if the sum
+/// overflows, the assertion panics with a distinct message showing the partial sum and the element
+/// that caused the overflow, rather than wrapping around. For floating point values, use
+/// [`assert_sum_near!`].
+///
+/// On failure, the computed sum is printed along with the expected value and the number of
+/// elements summed.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_sum_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let data = [1, 2, 3, 4, 5, 6, 7];
+/// let parts: Vec<&[u32]> = data.chunks(3).collect();
+///
+/// assert_sum_eq!(parts.iter().map(|part| part.len()), data.len());
+///
+/// // With a custom message
+/// assert_sum_eq!(&data, 28, "Expecting the triangular number");
+/// # }
+/// ```
+///
+/// The sum will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let sum = assert_sum_eq!(&[1, 2, 3], 6);
+/// assert_eq!(sum, 6);
+/// # }
+/// ```
+///
+/// A different sum will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_sum_eq!(&[1, 2, 3], 7);  // Will panic
+/// # }
+/// ```
+///
+/// An overflowing sum will also panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_sum_eq!(&[200u8, 100], 44);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_sum_near!`]: crate::assert_sum_near!
+/// [`debug_assert_sum_eq!`]: crate::debug_assert_sum_eq!
+#[macro_export]
+macro_rules! assert_sum_eq {
+    ($iter:expr, $expected:expr $(,)?) => {
+        match ($iter, &$expected) {
+            (iter, expected) => match $crate::__private::checked_sum(iter, expected) {
+                ::core::result::Result::Ok((sum, count)) => {
+                    if sum != *expected {
+                        ::core::panic!(r#"assertion failed: `(sum(iter) == expected)`
+    sum: `{:?}`,
+    expected: `{:?}`,
+    count: `{}`"#, sum, &*expected, count);
+                    }
+                    sum
+                }
+                ::core::result::Result::Err(overflow) => {
+                    ::core::panic!(r#"assertion failed, sum of iterator overflowed
+    partial sum: `{:?}`,
+    element: `{:?}`,
+    index: `{}`"#, overflow.sum, overflow.element, overflow.index);
+                }
+            },
+        }
+    };
+    ($iter:expr, $expected:expr, $($arg:tt)+) => {
+        match ($iter, &$expected) {
+            (iter, expected) => match $crate::__private::checked_sum(iter, expected) {
+                ::core::result::Result::Ok((sum, count)) => {
+                    if sum != *expected {
+                        ::core::panic!(r#"assertion failed: `(sum(iter) == expected)`
+    sum: `{:?}`,
+    expected: `{:?}`,
+    count: `{}`: {}"#, sum, &*expected, count, ::core::format_args!($($arg)+));
+                    }
+                    sum
+                }
+                ::core::result::Result::Err(overflow) => {
+                    ::core::panic!(r#"assertion failed, sum of iterator overflowed
+    partial sum: `{:?}`,
+    element: `{:?}`,
+    index: `{}`: {}"#, overflow.sum, overflow.element, overflow.index, ::core::format_args!($($arg)+));
+                }
+            },
+        }
+    };
+}
+
+/// Asserts that the sum of the integers produced by an iterator is equal to the expected value on
+/// debug builds.
+///
+/// This macro behaves nearly the same as [`assert_sum_eq!`] on debug builds, although it does not
+/// return the sum. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_sum_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_sum_eq!($($arg)*);
+        }
+    }};
+}
+
+/// Integer types which can be summed with overflow checking.
+///
+/// This is an implementation detail of [`assert_sum_eq!`] and is not part of the public API.
+#[doc(hidden)]
+pub trait Summand {
+    type Sum: Copy;
+
+    const ZERO: Self::Sum;
+
+    fn into_sum(self) -> Self::Sum;
+
+    fn checked_add(sum: Self::Sum, element: Self::Sum) -> Option<Self::Sum>;
+}
+
+macro_rules! impl_summand {
+    ($($int:ident)*) => {
+        $(
+            impl Summand for $int {
+                type Sum = $int;
+
+                const ZERO: $int = 0;
+
+                fn into_sum(self) -> $int {
+                    self
+                }
+
+                fn checked_add(sum: $int, element: $int) -> Option<$int> {
+                    sum.checked_add(element)
+                }
+            }
+
+            impl Summand for &$int {
+                type Sum = $int;
+
+                const ZERO: $int = 0;
+
+                fn into_sum(self) -> $int {
+                    *self
+                }
+
+                fn checked_add(sum: $int, element: $int) -> Option<$int> {
+                    sum.checked_add(element)
+                }
+            }
+        )*
+    };
+}
+
+impl_summand! {u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize}
+
+/// The state of a sum when adding an element overflowed.
+///
+/// This is an implementation detail of [`assert_sum_eq!`] and is not part of the public API.
+#[doc(hidden)]
+pub struct Overflow<T> {
+    pub sum: T,
+    pub element: T,
+    pub index: usize,
+}
+
+/// Sums the elements of the iterator, returning the sum along with the number of elements, or the
+/// state of the sum when it overflowed.
+///
+/// The expected value is only taken to infer the type of the sum.
+///
+/// This is an implementation detail of [`assert_sum_eq!`] and is not part of the public API.
+#[doc(hidden)]
+pub fn checked_sum<I, S>(iter: I, _expected: &S) -> Result<(S, usize), Overflow<S>>
+where
+    I: IntoIterator,
+    I::Item: Summand<Sum = S>,
+    S: Copy,
+{
+    let mut sum = <I::Item as Summand>::ZERO;
+    let mut count = 0;
+    for element in iter {
+        let element = element.into_sum();
+        sum = match <I::Item as Summand>::checked_add(sum, element) {
+            Some(sum) => sum,
+            None => {
+                return Err(Overflow {
+                    sum,
+                    element,
+                    index: count,
+                })
+            }
+        };
+        count += 1;
+    }
+    Ok((sum, count))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{iter, vec};
+
+    #[test]
+    fn equal() {
+        assert_sum_eq!(vec![1, 2, 3], 6);
+    }
+
+    #[test]
+    fn equal_references() {
+        let values = [1u64, 2, 3];
+        assert_sum_eq!(values.iter(), 6);
+    }
+
+    #[test]
+    fn equal_empty() {
+        assert_sum_eq!(iter::empty::<i32>(), 0);
+    }
+
+    #[test]
+    fn equal_negative() {
+        assert_sum_eq!(vec![-5i8, 3, -1], -3);
+    }
+
+    #[test]
+    fn equal_max() {
+        assert_sum_eq!(vec![u8::max_value() - 1, 1], u8::max_value());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(sum(iter) == expected)`\n    sum: `6`,\n    expected: `7`,\n    count: `3`"
+    )]
+    fn not_equal() {
+        assert_sum_eq!(vec![1, 2, 3], 7);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(sum(iter) == expected)`\n    sum: `6`,\n    expected: `7`,\n    count: `3`: foo"
+    )]
+    fn not_equal_custom_message() {
+        assert_sum_eq!(vec![1, 2, 3], 7, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, sum of iterator overflowed\n    partial sum: `250`,\n    element: `10`,\n    index: `2`"
+    )]
+    fn overflow() {
+        assert_sum_eq!(vec![200u8, 50, 10, 1], 5);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, sum of iterator overflowed\n    partial sum: `-128`,\n    element: `-1`,\n    index: `1`"
+    )]
+    fn overflow_negative() {
+        let values = [i8::min_value(), -1];
+        assert_sum_eq!(&values, 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, sum of iterator overflowed\n    partial sum: `250`,\n    element: `10`,\n    index: `2`: foo"
+    )]
+    fn overflow_custom_message() {
+        assert_sum_eq!(vec![200u8, 50, 10, 1], 5, "foo");
+    }
+
+    #[test]
+    fn sum_returned() {
+        let sum = assert_sum_eq!(vec![1, 2, 3], 6);
+        assert_eq!(sum, 6);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_equal() {
+        debug_assert_sum_eq!(vec![1, 2, 3], 6);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(sum(iter) == expected)`\n    sum: `6`,\n    expected: `7`,\n    count: `3`"
+    )]
+    fn debug_not_equal() {
+        debug_assert_sum_eq!(vec![1, 2, 3], 7);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, sum of iterator overflowed\n    partial sum: `250`,\n    element: `10`,\n    index: `2`: foo"
+    )]
+    fn debug_overflow_custom_message() {
+        debug_assert_sum_eq!(vec![200u8, 50, 10, 1], 5, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_equal() {
+        debug_assert_sum_eq!(vec![1, 2, 3], 7);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_sum_eq!(vec![1, 2, 3], 6);
+    }
+}
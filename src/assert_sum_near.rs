@@ -0,0 +1,216 @@
+/// Asserts that the sum of the floating point values produced by an iterator is within a tolerance
+/// of the expected value, returning the sum.
+///
+/// The expression can be anything implementing [`IntoIterator`] over `f32` or `f64` values, or
+/// references to them, and is consumed. The assertion passes if the absolute difference between
+/// the sum and the expected value is no greater than the tolerance, which makes it suitable for
+/// checks such as probabilities summing to `1.0`, where rounding errors accumulate. A `NaN` sum
+/// always fails. For integers, which can be compared exactly, use [`assert_sum_eq!`].
+///
+/// On failure, the computed sum is printed along with the expected value, their difference, the
+/// tolerance, and the number of elements summed.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_sum_near!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let probabilities = [0.1, 0.2, 0.3, 0.4];
+/// assert_sum_near!(&probabilities, 1.0, 1e-9);
+///
+/// // With a custom message
+/// assert_sum_near!(&probabilities, 1.0, 1e-9, "Expecting a probability distribution");
+/// # }
+/// ```
+///
+/// The sum will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let sum = assert_sum_near!(&[0.5, 0.25], 0.75, 1e-9);
+/// assert_eq!(sum, 0.75);
+/// # }
+/// ```
+///
+/// A sum outside of the tolerance will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_sum_near!(&[0.1, 0.2, 0.3], 1.0, 1e-9);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_sum_eq!`]: crate::assert_sum_eq!
+/// [`debug_assert_sum_near!`]: crate::debug_assert_sum_near!
+#[macro_export]
+macro_rules! assert_sum_near {
+    ($iter:expr, $expected:expr, $tolerance:expr $(,)?) => {
+        match ($iter, $expected, $tolerance) {
+            (iter, expected, tolerance) => {
+                let (sum, count) = $crate::__private::sum_count(iter, &expected);
+                if !$crate::__private::AbsDiffEq::abs_diff_eq(&sum, &expected, &tolerance) {
+                    ::core::panic!(r#"assertion failed: `(|sum(iter) - expected| <= tolerance)`
+    sum: `{:?}`,
+    expected: `{:?}`,
+    difference: `{:?}`,
+    tolerance: `{:?}`,
+    count: `{}`"#, sum, expected, $crate::__private::AbsDiffEq::abs_diff(&sum, &expected), tolerance, count);
+                }
+                sum
+            }
+        }
+    };
+    ($iter:expr, $expected:expr, $tolerance:expr, $($arg:tt)+) => {
+        match ($iter, $expected, $tolerance) {
+            (iter, expected, tolerance) => {
+                let (sum, count) = $crate::__private::sum_count(iter, &expected);
+                if !$crate::__private::AbsDiffEq::abs_diff_eq(&sum, &expected, &tolerance) {
+                    ::core::panic!(r#"assertion failed: `(|sum(iter) - expected| <= tolerance)`
+    sum: `{:?}`,
+    expected: `{:?}`,
+    difference: `{:?}`,
+    tolerance: `{:?}`,
+    count: `{}`: {}"#, sum, expected, $crate::__private::AbsDiffEq::abs_diff(&sum, &expected), tolerance, count, ::core::format_args!($($arg)+));
+                }
+                sum
+            }
+        }
+    };
+}
+
+/// Asserts that the sum of the floating point values produced by an iterator is within a tolerance
+/// of the expected value on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_sum_near!`] on debug builds, although it does not
+/// return the sum. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_sum_near {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_sum_near!($($arg)*);
+        }
+    }};
+}
+
+/// Sums the elements of the iterator, returning the sum along with the number of elements.
+///
+/// The expected value is only taken to infer the type of the sum.
+///
+/// This is an implementation detail of [`assert_sum_near!`] and is not part of the public API.
+#[doc(hidden)]
+pub fn sum_count<I, S>(iter: I, _expected: &S) -> (S, usize)
+where
+    I: IntoIterator,
+    S: core::iter::Sum<I::Item>,
+{
+    let mut count = 0;
+    let sum = iter.into_iter().inspect(|_| count += 1).sum();
+    (sum, count)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{iter, vec};
+
+    #[test]
+    fn within_tolerance() {
+        assert_sum_near!(vec![0.1, 0.2, 0.3, 0.4], 1.0, 1e-9);
+    }
+
+    #[test]
+    fn within_tolerance_references() {
+        let values = [0.25f32, 0.25, 0.5];
+        assert_sum_near!(values.iter(), 1.0, 1e-6);
+    }
+
+    #[test]
+    fn exact_tolerance() {
+        assert_sum_near!(vec![1.0, 1.0], 3.0, 1.0);
+    }
+
+    #[test]
+    fn empty() {
+        assert_sum_near!(iter::empty::<f64>(), 0.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|sum(iter) - expected| <= tolerance)`\n    sum: `1.5`,\n    expected: `1.0`,\n    difference: `0.5`,\n    tolerance: `0.1`,\n    count: `2`"
+    )]
+    fn outside_tolerance() {
+        assert_sum_near!(vec![1.0, 0.5], 1.0, 0.1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|sum(iter) - expected| <= tolerance)`\n    sum: `NaN`,\n    expected: `1.0`,\n    difference: `NaN`,\n    tolerance: `0.1`,\n    count: `2`"
+    )]
+    fn nan() {
+        assert_sum_near!(vec![1.0, core::f64::NAN], 1.0, 0.1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(|sum(iter) - expected| <= tolerance)`\n    sum: `1.5`,\n    expected: `1.0`,\n    difference: `0.5`,\n    tolerance: `0.1`,\n    count: `2`: foo"
+    )]
+    fn outside_tolerance_custom_message() {
+        assert_sum_near!(vec![1.0, 0.5], 1.0, 0.1, "foo");
+    }
+
+    #[test]
+    fn sum_returned() {
+        let sum = assert_sum_near!(vec![0.5, 0.25], 0.75, 0.0);
+        assert_eq!(sum, 0.75);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_within_tolerance() {
+        debug_assert_sum_near!(vec![0.1, 0.2, 0.3, 0.4], 1.0, 1e-9);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(|sum(iter) - expected| <= tolerance)`\n    sum: `1.5`,\n    expected: `1.0`,\n    difference: `0.5`,\n    tolerance: `0.1`,\n    count: `2`"
+    )]
+    fn debug_outside_tolerance() {
+        debug_assert_sum_near!(vec![1.0, 0.5], 1.0, 0.1);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(|sum(iter) - expected| <= tolerance)`\n    sum: `1.5`,\n    expected: `1.0`,\n    difference: `0.5`,\n    tolerance: `0.1`,\n    count: `2`: foo"
+    )]
+    fn debug_outside_tolerance_custom_message() {
+        debug_assert_sum_near!(vec![1.0, 0.5], 1.0, 0.1, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_outside_tolerance() {
+        debug_assert_sum_near!(vec![1.0, 0.5], 1.0, 0.1);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_sum_near!(vec![0.5, 0.25], 0.75, 0.0);
+    }
+}
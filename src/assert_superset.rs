@@ -0,0 +1,212 @@
+/// Asserts that every element of the right collection is contained in the left collection.
+///
+/// Both expressions can be anything implementing [`IntoIterator`], as long as they produce the
+/// same element type. To compare a `Vec` against an array, for example, pass both by reference.
+/// Elements are looked up in a [`HashSet`] when they implement [`Hash`] and [`Eq`]. Elements that
+/// only implement [`Ord`] are looked up in a [`BTreeSet`] instead. Either way, the elements must
+/// also implement [`Debug`].
+///
+/// Any collection is a superset of an empty right collection. See [`assert_subset!`] for the
+/// mirrored assertion.
+///
+/// On failure, the elements of the right collection that are missing from the left collection are
+/// printed once each, in the order they first appear, along with how many of them there are.
+///
+/// This macro requires the `std` feature.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_superset!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let granted = vec!["read", "write"];
+/// let allowed = vec!["read", "write", "delete"];
+///
+/// assert_superset!(&allowed, &granted);
+///
+/// // With a custom message
+/// assert_superset!(&allowed, &granted, "Expecting only allowed scopes");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_superset!(&[1, 2, 3], &vec![1, 2, 4]);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`HashSet`]: https://doc.rust-lang.org/std/collections/struct.HashSet.html
+/// [`BTreeSet`]: https://doc.rust-lang.org/std/collections/struct.BTreeSet.html
+/// [`Hash`]: https://doc.rust-lang.org/core/hash/trait.Hash.html
+/// [`Eq`]: https://doc.rust-lang.org/core/cmp/trait.Eq.html
+/// [`Ord`]: https://doc.rust-lang.org/core/cmp/trait.Ord.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_subset!`]: crate::assert_subset!
+/// [`debug_assert_superset!`]: crate::debug_assert_superset!
+#[macro_export]
+macro_rules! assert_superset {
+    ($left:expr, $right:expr $(,)?) => {
+        match ($crate::__private::collect($left), $crate::__private::collect($right)) {
+            (left_val, right_val) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{HashElements as _, OrdElements as _};
+                let missing = (&$crate::__private::Elements(&right_val, &left_val)).not_contained();
+                if !missing.is_empty() {
+                    ::core::panic!(r#"assertion failed, expected left to be a superset of right
+    not in superset: `{:?}`,
+    count: `{}`"#, missing, missing.len())
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match ($crate::__private::collect($left), $crate::__private::collect($right)) {
+            (left_val, right_val) => {
+                #[allow(unused_imports)]
+                use $crate::__private::{HashElements as _, OrdElements as _};
+                let missing = (&$crate::__private::Elements(&right_val, &left_val)).not_contained();
+                if !missing.is_empty() {
+                    ::core::panic!(r#"assertion failed, expected left to be a superset of right
+    not in superset: `{:?}`,
+    count: `{}`: {}"#, missing, missing.len(), ::core::format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that every element of the right collection is contained in the left collection on debug
+/// builds.
+///
+/// This macro behaves the same as [`assert_superset!`] on debug builds. On release builds it is a
+/// no-op.
+///
+/// This macro requires the `std` feature.
+#[macro_export]
+macro_rules! debug_assert_superset {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_superset!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{vec, vec::Vec};
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Scope(u32);
+
+    #[test]
+    fn superset() {
+        assert_superset!(&[1, 2, 3], &vec![1, 2]);
+    }
+
+    #[test]
+    fn superset_equal() {
+        assert_superset!(vec![1, 2, 3], vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn superset_duplicates() {
+        assert_superset!(vec![1, 1, 2, 2], vec![2, 1]);
+    }
+
+    #[test]
+    fn superset_ord_only() {
+        assert_superset!(vec![Scope(1), Scope(2)], vec![Scope(2)]);
+    }
+
+    #[test]
+    fn empty_right() {
+        let empty: Vec<u32> = Vec::new();
+        assert_superset!(&[1, 2, 3], &empty);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected left to be a superset of right\n    not in superset: `[1, 2]`,\n    count: `2`"
+    )]
+    fn empty_left() {
+        let empty: Vec<u32> = Vec::new();
+        assert_superset!(empty, vec![1, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected left to be a superset of right\n    not in superset: `[4]`,\n    count: `1`"
+    )]
+    fn not_superset() {
+        assert_superset!(&[1, 2, 3], &vec![1, 4, 2]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected left to be a superset of right\n    not in superset: `[5, 4]`,\n    count: `2`"
+    )]
+    fn not_superset_multiple() {
+        assert_superset!(vec![1, 2, 3], vec![5, 1, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected left to be a superset of right\n    not in superset: `[Scope(4)]`,\n    count: `1`"
+    )]
+    fn not_superset_ord_only() {
+        assert_superset!(vec![Scope(1), Scope(2)], vec![Scope(1), Scope(4)]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected left to be a superset of right\n    not in superset: `[4]`,\n    count: `1`: foo"
+    )]
+    fn not_superset_custom_message() {
+        assert_superset!(&[1, 2, 3], &vec![1, 4, 2], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_superset() {
+        debug_assert_superset!(&[1, 2, 3], &vec![1, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected left to be a superset of right\n    not in superset: `[4]`,\n    count: `1`"
+    )]
+    fn debug_not_superset() {
+        debug_assert_superset!(&[1, 2, 3], &vec![1, 4, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed, expected left to be a superset of right\n    not in superset: `[4]`,\n    count: `1`: foo"
+    )]
+    fn debug_not_superset_custom_message() {
+        debug_assert_superset!(&[1, 2, 3], &vec![1, 4, 2], "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_superset() {
+        debug_assert_superset!(&[1, 2, 3], &vec![1, 4, 2]);
+    }
+}
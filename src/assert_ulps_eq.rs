@@ -0,0 +1,379 @@
+/// Asserts that two floating point expressions are within a number of [units in the last place]
+/// (ULPs) of each other.
+///
+/// The distance is computed by reinterpreting both values as sign-magnitude integers and taking
+/// the difference. Values which are exactly equal (including `0.0` and `-0.0`) always pass. Values
+/// with different signs, as well as `NaN` values, always fail, since their distance is not
+/// meaningful.
+///
+/// Works with [`f32`] and [`f64`]. The maximum distance can be provided using the
+/// `max_ulps = ...` argument. If it is omitted, it defaults to `4`.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ulps_eq!`] for assertions that are not enabled in release builds by
+/// default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_ulps_eq!(0.1 + 0.2, 0.3);
+/// assert_ulps_eq!(1.0f32, 1.0000005, max_ulps = 4);
+///
+/// // With a custom message
+/// assert_ulps_eq!(1.0f32, 1.0000005, max_ulps = 4, "Expecting {} to be close to {}", 1.0, 1.0000005);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_ulps_eq!(1.0f32, 1.0000005, max_ulps = 2);  // Will panic
+/// # }
+/// ```
+///
+/// [units in the last place]: https://en.wikipedia.org/wiki/Unit_in_the_last_place
+/// [`f32`]: https://doc.rust-lang.org/core/primitive.f32.html
+/// [`f64`]: https://doc.rust-lang.org/core/primitive.f64.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_ulps_eq!`]: crate::debug_assert_ulps_eq!
+#[macro_export]
+macro_rules! assert_ulps_eq {
+    ($left:expr, $right:expr, max_ulps = $max_ulps:expr $(,)?) => {
+        match (&$left, &$right, &$max_ulps) {
+            (left_val, right_val, max_ulps_val) => {
+                if !$crate::__private::UlpsEq::ulps_eq(left_val, right_val, max_ulps_val) {
+                    ::core::panic!(r#"assertion failed: `(ulps(left, right) <= max_ulps)`
+    left: `{:?}` ({:#0width$x}),
+    right: `{:?}` ({:#0width$x}),
+    ulps: `{}`,
+    max_ulps: `{:?}`{}"#,
+                        &*left_val,
+                        $crate::__private::UlpsEq::bits(left_val),
+                        &*right_val,
+                        $crate::__private::UlpsEq::bits(right_val),
+                        $crate::__private::UlpsEq::ulps(left_val, right_val),
+                        &*max_ulps_val,
+                        $crate::__private::UlpsEq::note(left_val, right_val),
+                        width = $crate::__private::UlpsEq::hex_width(left_val),
+                    )
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, max_ulps = $max_ulps:expr, $($arg:tt)+) => {
+        match (&$left, &$right, &$max_ulps) {
+            (left_val, right_val, max_ulps_val) => {
+                if !$crate::__private::UlpsEq::ulps_eq(left_val, right_val, max_ulps_val) {
+                    ::core::panic!(r#"assertion failed: `(ulps(left, right) <= max_ulps)`
+    left: `{:?}` ({:#0width$x}),
+    right: `{:?}` ({:#0width$x}),
+    ulps: `{}`,
+    max_ulps: `{:?}`{}: {}"#,
+                        &*left_val,
+                        $crate::__private::UlpsEq::bits(left_val),
+                        &*right_val,
+                        $crate::__private::UlpsEq::bits(right_val),
+                        $crate::__private::UlpsEq::ulps(left_val, right_val),
+                        &*max_ulps_val,
+                        $crate::__private::UlpsEq::note(left_val, right_val),
+                        ::core::format_args!($($arg)+),
+                        width = $crate::__private::UlpsEq::hex_width(left_val),
+                    )
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                $crate::assert_ulps_eq!(
+                    *left_val,
+                    *right_val,
+                    max_ulps = $crate::__private::UlpsEq::default_max_ulps(left_val)
+                )
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                $crate::assert_ulps_eq!(
+                    *left_val,
+                    *right_val,
+                    max_ulps = $crate::__private::UlpsEq::default_max_ulps(left_val),
+                    $($arg)+
+                )
+            }
+        }
+    };
+}
+
+/// Asserts that two floating point expressions are within a number of units in the last place
+/// (ULPs) of each other on debug builds.
+///
+/// This macro behaves the same as [`assert_ulps_eq!`] on debug builds. On release builds it is a
+/// no-op.
+#[macro_export]
+macro_rules! debug_assert_ulps_eq {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_ulps_eq!($($arg)*);
+        }
+    }};
+}
+
+/// The distance between two floating point values in ULPs.
+///
+/// This is an implementation detail of [`assert_ulps_eq!`] and is not part of the public API.
+#[doc(hidden)]
+pub enum Ulps<T> {
+    Distance(T),
+    Undefined,
+}
+
+impl<T> core::fmt::Display for Ulps<T>
+where
+    T: core::fmt::Display,
+{
+    fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Ulps::Distance(distance) => distance.fmt(formatter),
+            Ulps::Undefined => formatter.write_str("undefined"),
+        }
+    }
+}
+
+/// Types which can be compared by their distance in ULPs.
+///
+/// This is an implementation detail of [`assert_ulps_eq!`] and is not part of the public API.
+#[doc(hidden)]
+pub trait UlpsEq {
+    type Bits;
+
+    fn default_max_ulps(&self) -> Self::Bits;
+
+    fn bits(&self) -> Self::Bits;
+
+    fn hex_width(&self) -> usize;
+
+    fn ulps(&self, other: &Self) -> Ulps<Self::Bits>;
+
+    fn ulps_eq(&self, other: &Self, max_ulps: &Self::Bits) -> bool;
+
+    fn note(&self, other: &Self) -> &'static str;
+}
+
+macro_rules! impl_ulps_eq {
+    ($($float:ident => $bits:ident)*) => {
+        $(
+            impl UlpsEq for $float {
+                type Bits = $bits;
+
+                fn default_max_ulps(&self) -> $bits {
+                    4
+                }
+
+                fn bits(&self) -> $bits {
+                    self.to_bits()
+                }
+
+                fn hex_width(&self) -> usize {
+                    // Two characters for the `0x` prefix, then two per byte.
+                    2 + 2 * core::mem::size_of::<$bits>()
+                }
+
+                fn ulps(&self, other: &$float) -> Ulps<$bits> {
+                    if self.is_nan()
+                        || other.is_nan()
+                        || self.is_sign_negative() != other.is_sign_negative()
+                    {
+                        return Ulps::Undefined;
+                    }
+                    // With matching signs, the magnitudes are ordered the same way as the values.
+                    let magnitude_mask = !0 >> 1;
+                    let left = self.to_bits() & magnitude_mask;
+                    let right = other.to_bits() & magnitude_mask;
+                    Ulps::Distance(if left > right {
+                        left - right
+                    } else {
+                        right - left
+                    })
+                }
+
+                fn ulps_eq(&self, other: &$float, max_ulps: &$bits) -> bool {
+                    if *self == *other {
+                        return true;
+                    }
+                    match self.ulps(other) {
+                        Ulps::Distance(distance) => distance <= *max_ulps,
+                        Ulps::Undefined => false,
+                    }
+                }
+
+                fn note(&self, other: &$float) -> &'static str {
+                    match (self.is_nan(), other.is_nan()) {
+                        (true, true) => ",\n    note: left and right are NaN",
+                        (true, false) => ",\n    note: left is NaN",
+                        (false, true) => ",\n    note: right is NaN",
+                        (false, false) => {
+                            if self.is_sign_negative() != other.is_sign_negative() {
+                                ",\n    note: left and right have different signs"
+                            } else {
+                                ""
+                            }
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_ulps_eq! {
+    f32 => u32
+    f64 => u64
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn equal() {
+        assert_ulps_eq!(1.0, 1.0);
+    }
+
+    #[test]
+    fn within_default_max_ulps() {
+        assert_ulps_eq!(0.1 + 0.2, 0.3);
+    }
+
+    #[test]
+    fn within_max_ulps() {
+        assert_ulps_eq!(1.0f32, 1.0000005, max_ulps = 4);
+    }
+
+    #[test]
+    fn within_max_ulps_negative() {
+        assert_ulps_eq!(-1.0f32, -1.0000005, max_ulps = 4);
+    }
+
+    #[test]
+    fn zeros_with_different_signs() {
+        assert_ulps_eq!(0.0, -0.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(ulps(left, right) <= max_ulps)`\n    left: `1.0` (0x3f800000),\n    right: `1.0000005` (0x3f800004),\n    ulps: `4`,\n    max_ulps: `2`"
+    )]
+    fn not_within_max_ulps_f32() {
+        assert_ulps_eq!(1.0f32, 1.0000005, max_ulps = 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(ulps(left, right) <= max_ulps)`\n    left: `1.0` (0x3ff0000000000000),\n    right: `1.0000000000000009` (0x3ff0000000000004),\n    ulps: `4`,\n    max_ulps: `2`"
+    )]
+    fn not_within_max_ulps_f64() {
+        assert_ulps_eq!(1.0f64, 1.0000000000000009, max_ulps = 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(ulps(left, right) <= max_ulps)`\n    left: `1.0` (0x3f800000),\n    right: `1.0000015` (0x3f80000d),\n    ulps: `13`,\n    max_ulps: `4`: foo"
+    )]
+    fn not_within_default_max_ulps_custom_message() {
+        assert_ulps_eq!(1.0f32, 1.0000015, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(ulps(left, right) <= max_ulps)`\n    left: `1.0` (0x3f800000),\n    right: `1.0000005` (0x3f800004),\n    ulps: `4`,\n    max_ulps: `2`: foo"
+    )]
+    fn not_within_max_ulps_custom_message() {
+        assert_ulps_eq!(1.0f32, 1.0000005, max_ulps = 2, "foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(ulps(left, right) <= max_ulps)`\n    left: `1.0` (0x3f800000),\n    right: `-1.0` (0xbf800000),\n    ulps: `undefined`,\n    max_ulps: `4`,\n    note: left and right have different signs"
+    )]
+    fn different_signs() {
+        assert_ulps_eq!(1.0f32, -1.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(ulps(left, right) <= max_ulps)`\n    left: `NaN` (0x7fc00000),\n    right: `1.0` (0x3f800000),\n    ulps: `undefined`,\n    max_ulps: `4`,\n    note: left is NaN"
+    )]
+    fn left_nan() {
+        assert_ulps_eq!(core::f32::NAN, 1.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(ulps(left, right) <= max_ulps)`\n    left: `1.0` (0x3f800000),\n    right: `NaN` (0x7fc00000),\n    ulps: `undefined`,\n    max_ulps: `4`,\n    note: right is NaN"
+    )]
+    fn right_nan() {
+        assert_ulps_eq!(1.0, core::f32::NAN);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: `(ulps(left, right) <= max_ulps)`\n    left: `NaN` (0x7ff8000000000000),\n    right: `NaN` (0x7ff8000000000000),\n    ulps: `undefined`,\n    max_ulps: `4`,\n    note: left and right are NaN"
+    )]
+    fn both_nan() {
+        assert_ulps_eq!(core::f64::NAN, core::f64::NAN);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_within_max_ulps() {
+        debug_assert_ulps_eq!(1.0f32, 1.0000005, max_ulps = 4);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(ulps(left, right) <= max_ulps)`\n    left: `1.0` (0x3f800000),\n    right: `1.0000005` (0x3f800004),\n    ulps: `4`,\n    max_ulps: `2`"
+    )]
+    fn debug_not_within_max_ulps() {
+        debug_assert_ulps_eq!(1.0f32, 1.0000005, max_ulps = 2);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(
+        expected = "assertion failed: `(ulps(left, right) <= max_ulps)`\n    left: `1.0` (0x3f800000),\n    right: `1.0000005` (0x3f800004),\n    ulps: `4`,\n    max_ulps: `2`: foo"
+    )]
+    fn debug_not_within_max_ulps_custom_message() {
+        debug_assert_ulps_eq!(1.0f32, 1.0000005, max_ulps = 2, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_within_max_ulps() {
+        debug_assert_ulps_eq!(1.0f32, 1.0000005, max_ulps = 2);
+    }
+
+    #[test]
+    fn debug_returns_unit() {
+        let () = debug_assert_ulps_eq!(1.0, 1.0);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_returns_unit() {
+        let () = debug_assert_ulps_eq!(1.0, 2.0);
+    }
+}
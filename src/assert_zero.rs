@@ -0,0 +1,237 @@
+/// Asserts that the expression is equal to zero, returning the value.
+///
+/// Works with all primitive integer and floating point types, as well as [`Duration`]. Both `0.0`
+/// and `-0.0` are considered zero.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_zero!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided with or without
+/// arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # use std::time::Duration;
+/// # fn main() {
+/// assert_zero!(0u64);
+/// assert_zero!(-0.0f32);
+/// assert_zero!(Duration::from_secs(0));
+///
+/// // With a custom message
+/// assert_zero!(0, "Expecting {} to be zero", 0);
+/// # }
+/// ```
+///
+/// The value will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let value = assert_zero!(0);
+/// assert_eq!(value, 0);
+/// # }
+/// ```
+///
+/// A non-zero value will panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_zero!(1);  // Will panic
+/// # }
+/// ```
+///
+/// [`Duration`]: https://doc.rust-lang.org/core/time/struct.Duration.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_zero!`]: crate::debug_assert_zero!
+#[macro_export]
+macro_rules! assert_zero {
+    ($value:expr $(,)?) => {
+        match $value {
+            value => {
+                if !::core::cmp::PartialEq::eq(&value, &$crate::__private::Zero::zero(&value)) {
+                    ::core::panic!("assertion failed, expected zero, got {:?}", value);
+                }
+                value
+            }
+        }
+    };
+    ($value:expr, $($arg:tt)+) => {
+        match $value {
+            value => {
+                if !::core::cmp::PartialEq::eq(&value, &$crate::__private::Zero::zero(&value)) {
+                    ::core::panic!("assertion failed, expected zero, got {:?}: {}", value, ::core::format_args!($($arg)+));
+                }
+                value
+            }
+        }
+    };
+}
+
+/// Asserts that the expression is equal to zero on debug builds.
+///
+/// This macro behaves nearly the same as [`assert_zero!`] on debug builds, although it does not
+/// return the value. On release builds it is a no-op.
+#[macro_export]
+macro_rules! debug_assert_zero {
+    ($($arg:tt)*) => {{
+        #[allow(unused_must_use)]
+        #[cfg(debug_assertions)]
+        {
+            $crate::assert_zero!($($arg)*);
+        }
+    }};
+}
+
+/// Types which have a zero value to compare against.
+///
+/// This is an implementation detail of the sign assertion macros and is not part of the public
+/// API.
+#[doc(hidden)]
+pub trait Zero: PartialOrd {
+    fn zero(&self) -> Self;
+}
+
+macro_rules! impl_zero {
+    ($($ty:ident => $zero:expr)*) => {
+        $(
+            impl Zero for $ty {
+                fn zero(&self) -> $ty {
+                    $zero
+                }
+            }
+        )*
+    };
+}
+
+impl_zero! {
+    u8 => 0
+    u16 => 0
+    u32 => 0
+    u64 => 0
+    u128 => 0
+    usize => 0
+    i8 => 0
+    i16 => 0
+    i32 => 0
+    i64 => 0
+    i128 => 0
+    isize => 0
+    f32 => 0.0
+    f64 => 0.0
+}
+
+impl Zero for core::time::Duration {
+    fn zero(&self) -> core::time::Duration {
+        core::time::Duration::from_secs(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    #[test]
+    fn zero() {
+        assert_zero!(0);
+    }
+
+    #[test]
+    fn zero_float() {
+        assert_zero!(0.0);
+    }
+
+    #[test]
+    fn negative_zero_float() {
+        assert_zero!(-0.0f32);
+    }
+
+    #[test]
+    fn zero_duration() {
+        assert_zero!(Duration::from_secs(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected zero, got 1")]
+    fn positive() {
+        assert_zero!(1u64);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected zero, got -1")]
+    fn negative() {
+        assert_zero!(-1i8);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected zero, got NaN")]
+    fn nan() {
+        assert_zero!(core::f64::NAN);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected zero, got 1ns")]
+    fn non_zero_duration() {
+        assert_zero!(Duration::from_nanos(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected zero, got 1: foo")]
+    fn non_zero_custom_message() {
+        assert_zero!(1, "foo");
+    }
+
+    #[test]
+    fn zero_value_returned() {
+        let value = assert_zero!(0);
+        assert_eq!(value, 0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    fn debug_zero() {
+        debug_assert_zero!(0);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, expected zero, got 1")]
+    fn debug_non_zero() {
+        debug_assert_zero!(1);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only run in debug mode")]
+    #[should_panic(expected = "assertion failed, expected zero, got 1: foo")]
+    fn debug_non_zero_custom_message() {
+        debug_assert_zero!(1, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_non_zero() {
+        debug_assert_zero!(1);
+    }
+
+    #[test]
+    fn debug_zero_returns_unit() {
+        let () = debug_assert_zero!(0);
+    }
+
+    #[test]
+    fn debug_zero_returns_unit_custom_message() {
+        let () = debug_assert_zero!(0, "foo");
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "only run in release mode")]
+    fn debug_release_not_zero_returns_unit() {
+        let () = debug_assert_zero!(1);
+    }
+}
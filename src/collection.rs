@@ -0,0 +1,531 @@
+use crate::{assert_contains::Truncated, assert_single::Preview};
+use core::{
+    borrow::Borrow,
+    cell::Cell,
+    cmp::Ordering,
+    fmt,
+    iter::Flatten,
+    ops::{Range, RangeFrom, RangeInclusive},
+    option,
+};
+
+/// The number of characters printed before the rest of a string is left out.
+const TRUNCATE_STR_AFTER: usize = 64;
+
+/// Wrapper used to view the string, collection, or iterator given to a macro as a sequence of
+/// elements.
+///
+/// The view is chosen through autoref-based specialization: calling `view()` on a
+/// `&&&&&Collection<C>` resolves, in order of preference, to [`ViewCollection`] when `C` is a
+/// reference to something that can be iterated over by reference, to [`ViewString`] when `C` is a
+/// reference to something that can be viewed as a `str`, to [`ViewCollectionRef`] when `C` is a
+/// reference to a reference to something that can be iterated over by reference, to
+/// [`ViewIterator`] when `C` is a reference to a cloneable iterator, and to [`ViewTakenIterator`]
+/// when `C` holds an iterator that was taken by value. All five traits must be in scope at the call
+/// site.
+///
+/// [`assert_contains!`] and [`assert_contains_all!`] additionally bring [`ViewRange`] into scope.
+/// It applies at the same level as [`ViewCollection`], which never applies to ranges, so that a
+/// range is checked against its bounds instead of being iterated over.
+///
+/// The wrapped value is usually a reference to the macro argument. [`assert_all!`] and
+/// [`assert_any!`] instead call `wrap_collection()` on the argument itself, which resolves to
+/// [`TakeIterator`] for iterators, taking them by value, and to [`BorrowCollection`] for anything
+/// else, only borrowing it. Both traits must then be in scope as well.
+///
+/// This is an implementation detail of the collection assertions and is not part of the public
+/// API.
+#[doc(hidden)]
+pub struct Collection<C>(pub C);
+
+#[doc(hidden)]
+pub trait BorrowCollection {
+    fn wrap_collection(&self) -> Collection<&Self>;
+}
+
+impl<C> BorrowCollection for C
+where
+    C: ?Sized,
+{
+    fn wrap_collection(&self) -> Collection<&C> {
+        Collection(self)
+    }
+}
+
+#[doc(hidden)]
+pub trait TakeIterator: Sized {
+    fn wrap_collection(self) -> Collection<Cell<Option<Self>>>;
+}
+
+impl<I> TakeIterator for I
+where
+    I: Iterator,
+{
+    fn wrap_collection(self) -> Collection<Cell<Option<I>>> {
+        Collection(Cell::new(Some(self)))
+    }
+}
+
+#[doc(hidden)]
+pub trait ViewCollection {
+    type View;
+
+    fn view(&self) -> Self::View;
+}
+
+impl<'a, C> ViewCollection for &&&&Collection<&'a C>
+where
+    C: ?Sized,
+    &'a C: IntoIterator,
+{
+    type View = CollectionView<'a, C>;
+
+    fn view(&self) -> CollectionView<'a, C> {
+        CollectionView(self.0)
+    }
+}
+
+#[doc(hidden)]
+pub trait ViewRange {
+    type View;
+
+    fn view(&self) -> Self::View;
+}
+
+macro_rules! impl_view_range {
+    ($($range:ident),*) => {
+        $(
+            impl<'a, T> ViewRange for &&&&Collection<&'a $range<T>> {
+                type View = RangeView<'a, $range<T>>;
+
+                fn view(&self) -> RangeView<'a, $range<T>> {
+                    RangeView(self.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_view_range!(Range, RangeInclusive, RangeFrom);
+
+#[doc(hidden)]
+pub trait ViewString {
+    type View;
+
+    fn view(&self) -> Self::View;
+}
+
+impl<'a, S> ViewString for &&&Collection<&'a S>
+where
+    S: AsRef<str> + ?Sized,
+{
+    type View = StringView<'a>;
+
+    fn view(&self) -> StringView<'a> {
+        StringView(self.0.as_ref())
+    }
+}
+
+#[doc(hidden)]
+pub trait ViewCollectionRef {
+    type View;
+
+    fn view(&self) -> Self::View;
+}
+
+impl<'b, C> ViewCollectionRef for &&Collection<&'_ &'b C>
+where
+    C: ?Sized,
+    &'b C: IntoIterator,
+{
+    type View = CollectionView<'b, C>;
+
+    fn view(&self) -> CollectionView<'b, C> {
+        CollectionView(*self.0)
+    }
+}
+
+#[doc(hidden)]
+pub trait ViewIterator {
+    type View;
+
+    fn view(&self) -> Self::View;
+}
+
+impl<I> ViewIterator for &Collection<&'_ I>
+where
+    I: Iterator + Clone,
+{
+    type View = IteratorView<I>;
+
+    fn view(&self) -> IteratorView<I> {
+        IteratorView(self.0.clone())
+    }
+}
+
+#[doc(hidden)]
+pub trait ViewTakenIterator {
+    type View;
+
+    fn view(&self) -> Self::View;
+}
+
+impl<I> ViewTakenIterator for Collection<Cell<Option<I>>>
+where
+    I: Iterator,
+{
+    type View = IteratorView<Flatten<option::IntoIter<I>>>;
+
+    /// The iterator can only be viewed once. Any later view is empty.
+    fn view(&self) -> IteratorView<Flatten<option::IntoIter<I>>> {
+        IteratorView(self.0.take().into_iter().flatten())
+    }
+}
+
+/// View of a collection, which is iterated over by reference.
+#[doc(hidden)]
+pub struct CollectionView<'a, C: ?Sized>(&'a C);
+
+/// View of an iterator, which is cloned if it has to be traversed more than once.
+#[doc(hidden)]
+pub struct IteratorView<I>(I);
+
+/// View of a string, which is searched for substrings and otherwise seen as a sequence of
+/// characters.
+#[doc(hidden)]
+pub struct StringView<'a>(&'a str);
+
+/// View of a range, which is checked against its bounds instead of being iterated over.
+#[doc(hidden)]
+pub struct RangeView<'a, R>(&'a R);
+
+/// Description of a string, collection, or iterator, used when printing it in a panic message.
+#[doc(hidden)]
+pub struct Description<D> {
+    pub kind: &'static str,
+    pub length: usize,
+    pub contents: D,
+}
+
+/// A pair of adjacent elements that are out of order.
+#[doc(hidden)]
+pub struct Inversion<T> {
+    pub index: usize,
+    pub left: T,
+    pub right: T,
+}
+
+/// Reason for no element of an iterator satisfying a predicate.
+#[doc(hidden)]
+pub enum NoMatch<T> {
+    Empty,
+    Mismatch {
+        examined: usize,
+        elements: Preview<T>,
+    },
+}
+
+/// Wrapper printing a string, leaving out all but the first few characters.
+#[doc(hidden)]
+pub struct TruncatedStr<'a>(&'a str);
+
+impl fmt::Debug for TruncatedStr<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self.0.char_indices().nth(TRUNCATE_STR_AFTER) {
+            Some((index, _)) => write!(
+                formatter,
+                "{:?}... ({} more)",
+                &self.0[..index],
+                self.0[index..].chars().count()
+            ),
+            None => write!(formatter, "{:?}", self.0),
+        }
+    }
+}
+
+impl<'a, C> CollectionView<'a, C>
+where
+    C: ?Sized,
+    &'a C: IntoIterator,
+{
+    pub fn search<N>(self, needle: &N) -> Result<(), Truncated<<&'a C as IntoIterator>::IntoIter>>
+    where
+        N: ?Sized,
+        <&'a C as IntoIterator>::Item: PartialEq<N>,
+    {
+        if self.0.into_iter().any(|element| element == *needle) {
+            Ok(())
+        } else {
+            Err(Truncated::new(self.0.into_iter()))
+        }
+    }
+
+    pub fn describe(self) -> Description<Truncated<<&'a C as IntoIterator>::IntoIter>> {
+        Description {
+            kind: "collection",
+            length: self.0.into_iter().count(),
+            contents: Truncated::new(self.0.into_iter()),
+        }
+    }
+
+    pub fn first(self) -> Result<<&'a C as IntoIterator>::Item, &'static str> {
+        self.0.into_iter().next().ok_or("collection")
+    }
+
+    pub fn last(self) -> Result<<&'a C as IntoIterator>::Item, &'static str> {
+        self.0.into_iter().last().ok_or("collection")
+    }
+}
+
+impl<'a, C, T> CollectionView<'a, C>
+where
+    C: ?Sized,
+    T: ?Sized + 'a,
+    &'a C: IntoIterator<Item = &'a T>,
+{
+    pub fn first_inversion_by<F>(self, in_order: F) -> Option<Inversion<&'a T>>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        first_inversion_by(self.0.into_iter(), deref, in_order)
+    }
+
+    pub fn first_inversion_by_key<K, F>(self, key: F) -> Option<(Inversion<&'a T>, K, K)>
+    where
+        K: PartialOrd,
+        F: FnMut(&T) -> K,
+    {
+        first_inversion_by_key(self.0.into_iter(), deref, key)
+    }
+
+    pub fn first_failing<F>(self, predicate: F) -> Option<(usize, &'a T)>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        first_failing(self.0.into_iter(), deref, predicate)
+    }
+
+    pub fn any_matching<F>(self, predicate: F) -> Result<(), NoMatch<&'a T>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        any_matching(self.0.into_iter(), deref, predicate)
+    }
+}
+
+impl<I> IteratorView<I>
+where
+    I: Iterator,
+{
+    pub fn search<N>(self, needle: &N) -> Result<(), Truncated<I>>
+    where
+        I: Clone,
+        N: ?Sized,
+        for<'x> &'x I::Item: PartialEq<N>,
+    {
+        if self
+            .0
+            .clone()
+            .any(|element| PartialEq::eq(&&element, needle))
+        {
+            Ok(())
+        } else {
+            Err(Truncated::new(self.0))
+        }
+    }
+
+    pub fn describe(self) -> Description<Truncated<I>>
+    where
+        I: Clone,
+    {
+        Description {
+            kind: "iterator",
+            length: self.0.clone().count(),
+            contents: Truncated::new(self.0),
+        }
+    }
+
+    pub fn first(mut self) -> Result<I::Item, &'static str> {
+        self.0.next().ok_or("iterator")
+    }
+
+    pub fn last(self) -> Result<I::Item, &'static str> {
+        self.0.last().ok_or("iterator")
+    }
+
+    pub fn first_inversion_by<F>(self, in_order: F) -> Option<Inversion<I::Item>>
+    where
+        F: FnMut(&I::Item, &I::Item) -> bool,
+    {
+        first_inversion_by(self.0, identity, in_order)
+    }
+
+    pub fn first_inversion_by_key<K, F>(self, key: F) -> Option<(Inversion<I::Item>, K, K)>
+    where
+        K: PartialOrd,
+        F: FnMut(&I::Item) -> K,
+    {
+        first_inversion_by_key(self.0, identity, key)
+    }
+
+    pub fn first_failing<F>(self, predicate: F) -> Option<(usize, I::Item)>
+    where
+        F: FnMut(&I::Item) -> bool,
+    {
+        first_failing(self.0, identity, predicate)
+    }
+
+    pub fn any_matching<F>(self, predicate: F) -> Result<(), NoMatch<I::Item>>
+    where
+        F: FnMut(&I::Item) -> bool,
+    {
+        any_matching(self.0, identity, predicate)
+    }
+}
+
+impl<'a> StringView<'a> {
+    pub fn search<N>(self, needle: &N) -> Result<(), TruncatedStr<'a>>
+    where
+        N: AsRef<str> + ?Sized,
+    {
+        if self.0.contains(needle.as_ref()) {
+            Ok(())
+        } else {
+            Err(TruncatedStr(self.0))
+        }
+    }
+
+    pub fn describe(self) -> Description<&'a str> {
+        Description {
+            kind: "string",
+            length: self.0.len(),
+            contents: self.0,
+        }
+    }
+
+    pub fn first(self) -> Result<char, &'static str> {
+        self.0.chars().next().ok_or("string")
+    }
+
+    pub fn last(self) -> Result<char, &'static str> {
+        self.0.chars().next_back().ok_or("string")
+    }
+}
+
+macro_rules! impl_range_view {
+    ($($range:ident),*) => {
+        $(
+            impl<'a, T> RangeView<'a, $range<T>>
+            where
+                T: PartialOrd,
+            {
+                pub fn search<N>(self, needle: &N) -> Result<(), &'a $range<T>>
+                where
+                    N: Borrow<T> + ?Sized,
+                {
+                    if self.0.contains(needle.borrow()) {
+                        Ok(())
+                    } else {
+                        Err(self.0)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_range_view!(Range, RangeInclusive, RangeFrom);
+
+/// Returns the first pair of adjacent elements for which `in_order` returns `false`.
+fn first_inversion_by<I, E, B, F>(
+    mut iter: I,
+    borrow: B,
+    mut in_order: F,
+) -> Option<Inversion<I::Item>>
+where
+    I: Iterator,
+    E: ?Sized,
+    B: Fn(&I::Item) -> &E,
+    F: FnMut(&E, &E) -> bool,
+{
+    let mut left = iter.next()?;
+    for (index, right) in iter.enumerate() {
+        if !in_order(borrow(&left), borrow(&right)) {
+            return Some(Inversion { index, left, right });
+        }
+        left = right;
+    }
+    None
+}
+
+/// Returns the first pair of adjacent elements whose keys are not in non-decreasing order, along
+/// with their keys. The key function is called exactly once per element.
+fn first_inversion_by_key<I, E, B, K, F>(
+    mut iter: I,
+    borrow: B,
+    mut key: F,
+) -> Option<(Inversion<I::Item>, K, K)>
+where
+    I: Iterator,
+    E: ?Sized,
+    B: Fn(&I::Item) -> &E,
+    K: PartialOrd,
+    F: FnMut(&E) -> K,
+{
+    let mut left = iter.next()?;
+    let mut left_key = key(borrow(&left));
+    for (index, right) in iter.enumerate() {
+        let right_key = key(borrow(&right));
+        match left_key.partial_cmp(&right_key) {
+            Some(Ordering::Less) | Some(Ordering::Equal) => {}
+            _ => return Some((Inversion { index, left, right }, left_key, right_key)),
+        }
+        left = right;
+        left_key = right_key;
+    }
+    None
+}
+
+/// Returns the first element, along with its index, for which the predicate returns `false`.
+fn first_failing<I, E, B, F>(iter: I, borrow: B, mut predicate: F) -> Option<(usize, I::Item)>
+where
+    I: Iterator,
+    E: ?Sized,
+    B: Fn(&I::Item) -> &E,
+    F: FnMut(&E) -> bool,
+{
+    iter.enumerate()
+        .find(|(_, element)| !predicate(borrow(element)))
+}
+
+/// Searches for an element for which the predicate returns `true`.
+///
+/// The elements which don't satisfy the predicate are kept in a preview as they are examined, so
+/// the iterator is only traversed once.
+fn any_matching<I, E, B, F>(iter: I, borrow: B, mut predicate: F) -> Result<(), NoMatch<I::Item>>
+where
+    I: Iterator,
+    E: ?Sized,
+    B: Fn(&I::Item) -> &E,
+    F: FnMut(&E) -> bool,
+{
+    let mut elements = Preview::new();
+    for element in iter {
+        if predicate(borrow(&element)) {
+            return Ok(());
+        }
+        elements.push(element);
+    }
+    match elements.count() {
+        0 => Err(NoMatch::Empty),
+        examined => Err(NoMatch::Mismatch { examined, elements }),
+    }
+}
+
+fn deref<'a, T: ?Sized>(item: &'a &T) -> &'a T {
+    item
+}
+
+fn identity<T>(item: &T) -> &T {
+    item
+}
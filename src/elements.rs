@@ -0,0 +1,214 @@
+use core::{cmp::Ordering, fmt, hash::Hash};
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    vec::Vec,
+};
+
+/// Collects the elements of an iterator so that they can be compared.
+///
+/// This is an implementation detail of the unordered collection macros and is not part of the
+/// public API.
+#[doc(hidden)]
+pub fn collect<I>(iter: I) -> Vec<I::Item>
+where
+    I: IntoIterator,
+{
+    iter.into_iter().collect()
+}
+
+/// The left and right elements being compared.
+///
+/// The way elements are compared is chosen through autoref-based specialization: calling a method
+/// on a `&Elements<T>` resolves to [`HashElements`] when `T: Hash + Eq`, and falls back to
+/// [`OrdElements`] otherwise. Both traits must be in scope at the call site.
+///
+/// This is an implementation detail of the unordered collection macros and is not part of the
+/// public API.
+#[doc(hidden)]
+pub struct Elements<'a, T>(pub &'a [T], pub &'a [T]);
+
+/// An element that occurs a different number of times on the left and the right.
+struct Difference<'a, T> {
+    element: &'a T,
+    actual: usize,
+    expected: usize,
+}
+
+impl<T> fmt::Debug for Difference<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{:?} (actual: {}, expected: {})",
+            self.element, self.actual, self.expected
+        )
+    }
+}
+
+/// The elements whose counts differ.
+#[doc(hidden)]
+pub struct Differences<'a, T>(Vec<Difference<'a, T>>);
+
+impl<T> Differences<'_, T> {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<T> fmt::Debug for Differences<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.debug_list().entries(&self.0).finish()
+    }
+}
+
+#[doc(hidden)]
+pub trait HashElements<'a, T> {
+    fn differences(&self) -> Differences<'a, T>;
+
+    fn not_contained(&self) -> Vec<&'a T>;
+
+    fn common(&self) -> Vec<&'a T>;
+}
+
+impl<'a, T> HashElements<'a, T> for Elements<'a, T>
+where
+    T: Hash + Eq,
+{
+    /// Counts the elements in a map, reporting differences in order of first appearance.
+    fn differences(&self) -> Differences<'a, T> {
+        let mut order = Vec::new();
+        let mut counts: HashMap<&T, (usize, usize)> = HashMap::new();
+        for element in self.0 {
+            let count = counts.entry(element).or_insert_with(|| {
+                order.push(element);
+                (0, 0)
+            });
+            count.0 += 1;
+        }
+        for element in self.1 {
+            let count = counts.entry(element).or_insert_with(|| {
+                order.push(element);
+                (0, 0)
+            });
+            count.1 += 1;
+        }
+        Differences(
+            order
+                .into_iter()
+                .filter_map(|element| {
+                    let (actual, expected) = counts[element];
+                    if actual == expected {
+                        None
+                    } else {
+                        Some(Difference {
+                            element,
+                            actual,
+                            expected,
+                        })
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the distinct elements on the left that are not on the right, in order of first
+    /// appearance.
+    fn not_contained(&self) -> Vec<&'a T> {
+        let right: HashSet<&T> = self.1.iter().collect();
+        let mut seen = HashSet::new();
+        self.0
+            .iter()
+            .filter(|element| !right.contains(element) && seen.insert(*element))
+            .collect()
+    }
+
+    /// Returns the distinct elements found on both sides, in order of first appearance on the
+    /// left.
+    fn common(&self) -> Vec<&'a T> {
+        let right: HashSet<&T> = self.1.iter().collect();
+        let mut seen = HashSet::new();
+        self.0
+            .iter()
+            .filter(|element| right.contains(element) && seen.insert(*element))
+            .collect()
+    }
+}
+
+#[doc(hidden)]
+pub trait OrdElements<'a, T> {
+    fn differences(&self) -> Differences<'a, T>;
+
+    fn not_contained(&self) -> Vec<&'a T>;
+
+    fn common(&self) -> Vec<&'a T>;
+}
+
+impl<'a, T> OrdElements<'a, T> for &Elements<'a, T>
+where
+    T: Ord,
+{
+    /// Counts the elements by sorting them, reporting differences in sorted order.
+    fn differences(&self) -> Differences<'a, T> {
+        let mut actual: Vec<&T> = self.0.iter().collect();
+        let mut expected: Vec<&T> = self.1.iter().collect();
+        actual.sort();
+        expected.sort();
+
+        let mut differences = Vec::new();
+        let mut actual = actual.as_slice();
+        let mut expected = expected.as_slice();
+        loop {
+            let element = match (actual.first(), expected.first()) {
+                (Some(a), Some(e)) => {
+                    if a.cmp(e) == Ordering::Greater {
+                        *e
+                    } else {
+                        *a
+                    }
+                }
+                (Some(a), None) => *a,
+                (None, Some(e)) => *e,
+                (None, None) => break,
+            };
+            let actual_count = actual.iter().take_while(|a| **a == element).count();
+            let expected_count = expected.iter().take_while(|e| **e == element).count();
+            if actual_count != expected_count {
+                differences.push(Difference {
+                    element,
+                    actual: actual_count,
+                    expected: expected_count,
+                });
+            }
+            actual = &actual[actual_count..];
+            expected = &expected[expected_count..];
+        }
+        Differences(differences)
+    }
+
+    /// Returns the distinct elements on the left that are not on the right, in order of first
+    /// appearance.
+    fn not_contained(&self) -> Vec<&'a T> {
+        let right: BTreeSet<&T> = self.1.iter().collect();
+        let mut seen = BTreeSet::new();
+        self.0
+            .iter()
+            .filter(|element| !right.contains(element) && seen.insert(*element))
+            .collect()
+    }
+
+    /// Returns the distinct elements found on both sides, in order of first appearance on the
+    /// left.
+    fn common(&self) -> Vec<&'a T> {
+        let right: BTreeSet<&T> = self.1.iter().collect();
+        let mut seen = BTreeSet::new();
+        self.0
+            .iter()
+            .filter(|element| right.contains(element) && seen.insert(*element))
+            .collect()
+    }
+}
@@ -5,16 +5,175 @@
 //! ## Available macros
 //!
 //! Note that, like [`core`]/[`std`] macros, all macros in this crate have [`debug_*`](#macros)
-//! counterparts.
+//! counterparts. The `debug_*` macros never return a value, and evaluate to `()` in both debug
+//! and release builds.
 //!
 //! ### Comparison
 //!
 //! Assertions similar to [`assert_eq`] or [`assert_ne`]:
 //!
+//! * [`assert_cmp!`]
+//! * [`assert_default_eq!`]
+//! * [`assert_default_ne!`]
+//! * [`assert_is_default!`]
+//! * [`assert_clone_eq!`]
 //! * [`assert_ge!`]
 //! * [`assert_gt!`]
 //! * [`assert_le!`]
 //! * [`assert_lt!`]
+//! * [`assert_ge_all!`]
+//! * [`assert_gt_all!`]
+//! * [`assert_le_all!`]
+//! * [`assert_lt_all!`]
+//! * [`assert_ge_by_key!`]
+//! * [`assert_gt_by_key!`]
+//! * [`assert_le_by_key!`]
+//! * [`assert_lt_by_key!`]
+//!
+//! ### Collections and iterators
+//!
+//! Assertions for the contents of collections and the values produced by iterators:
+//!
+//! * [`assert_contains!`]
+//! * [`assert_contains_all!`]
+//! * [`assert_contains_subslice!`]
+//! * [`assert_contains_in_order!`]
+//! * [`assert_slice_starts_with!`]
+//! * [`assert_slice_ends_with!`]
+//! * [`assert_empty!`]
+//! * [`assert_not_empty!`]
+//! * [`assert_len_eq!`]
+//! * [`assert_len_in_range!`]
+//! * [`assert_count_eq!`]
+//! * [`assert_count_ne!`]
+//! * [`assert_count_lt!`]
+//! * [`assert_count_le!`]
+//! * [`assert_count_gt!`]
+//! * [`assert_count_ge!`]
+//! * [`assert_exactly_one!`]
+//! * [`assert_single!`]
+//! * [`assert_sorted!`]
+//! * [`assert_strictly_sorted!`]
+//! * [`assert_sorted_by!`]
+//! * [`assert_sorted_by_key!`]
+//! * [`assert_monotonic_increasing!`]
+//! * [`assert_monotonic_decreasing!`]
+//! * [`assert_strictly_increasing!`]
+//! * [`assert_strictly_decreasing!`]
+//! * [`assert_all!`]
+//! * [`assert_any!`]
+//! * [`assert_iter_eq!`]
+//! * [`assert_max_eq!`]
+//! * [`assert_min_eq!`]
+//! * [`assert_first_eq!`]
+//! * [`assert_last_eq!`]
+//! * [`assert_next_eq!`]
+//! * [`assert_next_some!`]
+//! * [`assert_next_none!`]
+//! * [`assert_exhausted!`]
+//! * [`assert_sum_eq!`]
+//!
+//! ### Unordered collections
+//!
+//! Assertions comparing the contents of collections regardless of order. These require the `std`
+//! feature:
+//!
+//! * [`assert_set_eq!`]
+//! * [`assert_same_elements!`]
+//! * [`assert_subset!`]
+//! * [`assert_superset!`]
+//! * [`assert_disjoint!`]
+//!
+//! ### Maps
+//!
+//! Assertions for the keys and values of maps. These require the `std` feature:
+//!
+//! * [`assert_map_contains_key!`]
+//! * [`assert_map_get_eq!`]
+//! * [`assert_map_eq!`]
+//!
+//! ### Sign
+//!
+//! Assertions comparing numeric values against zero:
+//!
+//! * [`assert_positive!`]
+//! * [`assert_negative!`]
+//! * [`assert_non_negative!`]
+//! * [`assert_zero!`]
+//!
+//! ### Integers
+//!
+//! Assertions for properties of integer values:
+//!
+//! * [`assert_power_of_two!`]
+//! * [`assert_bits_set!`]
+//! * [`assert_bits_clear!`]
+//!
+//! ### Radix formatting
+//!
+//! Equality assertions which print integers and bytes in hexadecimal or binary on failure:
+//!
+//! * [`assert_eq_hex!`]
+//! * [`assert_eq_bin!`]
+//! * [`assert_bytes_eq!`]
+//!
+//! ### Floating point
+//!
+//! Assertions for approximate equality and finiteness of floating point values:
+//!
+//! * [`assert_abs_diff_eq!`]
+//! * [`assert_relative_eq!`]
+//! * [`assert_ulps_eq!`]
+//! * [`assert_slice_abs_diff_eq!`]
+//! * [`assert_sum_near!`]
+//! * [`assert_finite!`]
+//! * [`assert_all_finite!`]
+//!
+//! ### Durations
+//!
+//! Assertions for approximate equality of [`Duration`] values:
+//!
+//! * [`assert_duration_near!`]
+//!
+//! ### Ranges
+//!
+//! Assertions for values contained in ranges, and for how ranges relate to each other:
+//!
+//! * [`assert_in_range!`]
+//! * [`assert_in_closed_range!`]
+//! * [`assert_in_open_range!`]
+//! * [`assert_in_right_open_range!`]
+//! * [`assert_ranges_overlap!`]
+//! * [`assert_ranges_disjoint!`]
+//!
+//! ### Pointers
+//!
+//! Assertions for the addresses of pointers and references:
+//!
+//! * [`assert_ptr_eq!`]
+//! * [`assert_ptr_ne!`]
+//!
+//! ### Memory layout
+//!
+//! Assertions for the size and alignment of types:
+//!
+//! * [`assert_size_of_eq!`]
+//! * [`assert_align_of_eq!`]
+//!
+//! ### Panics
+//!
+//! Assertions for whether a closure panics. These require the `std` feature:
+//!
+//! * [`assert_panics!`]
+//! * [`assert_does_not_panic!`]
+//!
+//! ### Formatting
+//!
+//! Assertions on the formatted output of values. [`assert_debug_contains!`] requires the `std`
+//! feature:
+//!
+//! * [`assert_display_eq!`]
+//! * [`assert_debug_contains!`]
 //!
 //! ### Matching
 //!
@@ -28,6 +187,13 @@
 //! * [`assert_err!`]
 //! * [`assert_ok_eq!`]
 //! * [`assert_err_eq!`]
+//! * [`assert_ok_satisfies!`]
+//! * [`assert_err_satisfies!`]
+//! * [`assert_ok_matches!`]
+//! * [`assert_err_matches!`]
+//! * [`assert_ok_ok!`]
+//! * [`assert_ok_some!`]
+//! * [`assert_ok_none!`]
 //!
 //! ### `Option` macros
 //!
@@ -36,6 +202,11 @@
 //! * [`assert_some!`]
 //! * [`assert_none!`]
 //! * [`assert_some_eq!`]
+//! * [`assert_some_satisfies!`]
+//! * [`assert_some_matches!`]
+//! * [`assert_some_some!`]
+//! * [`assert_some_ok!`]
+//! * [`assert_some_err!`]
 //!
 //! ### `Poll` macros
 //!
@@ -46,29 +217,202 @@
 //! * [`assert_ready_ok!`]
 //! * [`assert_ready_err!`]
 //! * [`assert_ready_eq!`]
+//! * [`assert_ready_satisfies!`]
 //!
 //! [`core`]: https://doc.rust-lang.org/stable/core/#macros
 //! [`std`]: https://doc.rust-lang.org/stable/std/#macros
 //! [`Option`]: https://doc.rust-lang.org/core/option/enum.Option.html
 //! [`Result`]: https://doc.rust-lang.org/core/result/enum.Result.html
 //! [`Poll`]: https://doc.rust-lang.org/core/task/enum.Poll.html
+//! [`Duration`]: https://doc.rust-lang.org/core/time/struct.Duration.html
 //! [`assert_eq`]: https://doc.rust-lang.org/core/macro.assert_eq.html
 //! [`assert_ne`]: https://doc.rust-lang.org/core/macro.assert_ne.html
 
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod assert_abs_diff_eq;
+mod assert_align_of_eq;
+mod assert_all;
+mod assert_all_finite;
+mod assert_any;
+mod assert_bits_clear;
+mod assert_bits_set;
+mod assert_bytes_eq;
+mod assert_clone_eq;
+mod assert_cmp;
+mod assert_contains;
+mod assert_contains_all;
+mod assert_contains_in_order;
+mod assert_contains_subslice;
+mod assert_count_eq;
+mod assert_count_ge;
+mod assert_count_gt;
+mod assert_count_le;
+mod assert_count_lt;
+mod assert_count_ne;
+#[cfg(feature = "std")]
+mod assert_debug_contains;
+mod assert_default_eq;
+mod assert_default_ne;
+#[cfg(feature = "std")]
+mod assert_disjoint;
+mod assert_display_eq;
+#[cfg(feature = "std")]
+mod assert_does_not_panic;
+mod assert_duration_near;
+mod assert_empty;
+mod assert_eq_bin;
+mod assert_eq_hex;
 mod assert_err;
 mod assert_err_eq;
+mod assert_err_matches;
+mod assert_err_satisfies;
+mod assert_exactly_one;
+mod assert_exhausted;
+mod assert_finite;
+mod assert_first_eq;
 mod assert_ge;
+mod assert_ge_all;
+mod assert_ge_by_key;
 mod assert_gt;
+mod assert_gt_all;
+mod assert_gt_by_key;
+mod assert_in_closed_range;
+mod assert_in_open_range;
+mod assert_in_range;
+mod assert_in_right_open_range;
+mod assert_is_default;
+mod assert_iter_eq;
+mod assert_last_eq;
 mod assert_le;
+mod assert_le_all;
+mod assert_le_by_key;
+mod assert_len_eq;
+mod assert_len_in_range;
 mod assert_lt;
+mod assert_lt_all;
+mod assert_lt_by_key;
+#[cfg(feature = "std")]
+mod assert_map_contains_key;
+#[cfg(feature = "std")]
+mod assert_map_eq;
+#[cfg(feature = "std")]
+mod assert_map_get_eq;
 mod assert_matches;
+mod assert_max_eq;
+mod assert_min_eq;
+mod assert_monotonic_decreasing;
+mod assert_monotonic_increasing;
+mod assert_negative;
+mod assert_next_eq;
+mod assert_next_none;
+mod assert_next_some;
+mod assert_non_negative;
 mod assert_none;
+mod assert_not_empty;
 mod assert_ok;
 mod assert_ok_eq;
+mod assert_ok_matches;
+mod assert_ok_none;
+mod assert_ok_ok;
+mod assert_ok_satisfies;
+mod assert_ok_some;
+#[cfg(feature = "std")]
+mod assert_panics;
 mod assert_pending;
+mod assert_positive;
+mod assert_power_of_two;
+mod assert_ptr_eq;
+mod assert_ptr_ne;
+mod assert_ranges_disjoint;
+mod assert_ranges_overlap;
 mod assert_ready;
 mod assert_ready_eq;
 mod assert_ready_err;
 mod assert_ready_ok;
+mod assert_ready_satisfies;
+mod assert_relative_eq;
+#[cfg(feature = "std")]
+mod assert_same_elements;
+#[cfg(feature = "std")]
+mod assert_set_eq;
+mod assert_single;
+mod assert_size_of_eq;
+mod assert_slice_abs_diff_eq;
+mod assert_slice_ends_with;
+mod assert_slice_starts_with;
 mod assert_some;
 mod assert_some_eq;
+mod assert_some_err;
+mod assert_some_matches;
+mod assert_some_ok;
+mod assert_some_satisfies;
+mod assert_some_some;
+mod assert_sorted;
+mod assert_sorted_by;
+mod assert_sorted_by_key;
+mod assert_strictly_decreasing;
+mod assert_strictly_increasing;
+mod assert_strictly_sorted;
+#[cfg(feature = "std")]
+mod assert_subset;
+mod assert_sum_eq;
+mod assert_sum_near;
+#[cfg(feature = "std")]
+mod assert_superset;
+mod assert_ulps_eq;
+mod assert_zero;
+mod collection;
+#[cfg(feature = "std")]
+mod elements;
+mod maybe_debug;
+mod maybe_difference;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::assert_abs_diff_eq::AbsDiffEq;
+    pub use crate::assert_bits_set::Bits;
+    pub use crate::assert_bytes_eq::bytes_diff;
+    pub use crate::assert_contains_all::find_missing;
+    pub use crate::assert_contains_in_order::find_in_order;
+    pub use crate::assert_contains_subslice::find_subslice;
+    #[cfg(feature = "std")]
+    pub use crate::assert_debug_contains::debug_contains;
+    pub use crate::assert_default_eq::DefaultOf;
+    pub use crate::assert_display_eq::display_eq;
+    #[cfg(feature = "std")]
+    pub use crate::assert_does_not_panic::panic_message;
+    pub use crate::assert_duration_near::duration_abs_diff;
+    pub use crate::assert_eq_bin::Bin;
+    pub use crate::assert_eq_hex::{Hex, Radix};
+    pub use crate::assert_finite::Finite;
+    pub use crate::assert_iter_eq::first_divergence;
+    pub use crate::assert_lt_all::borrow_element;
+    pub use crate::assert_lt_by_key::by_key;
+    #[cfg(feature = "std")]
+    pub use crate::assert_map_contains_key::Map;
+    #[cfg(feature = "std")]
+    pub use crate::assert_map_eq::{MapPair, OrdKeys, UnorderedKeys};
+    pub use crate::assert_max_eq::BorrowEq;
+    pub use crate::assert_ok_satisfies::satisfies;
+    #[cfg(feature = "std")]
+    pub use crate::assert_panics::catch_unwind;
+    pub use crate::assert_power_of_two::PowerOfTwo;
+    pub use crate::assert_ranges_overlap::{range_syntax, ranges_overlap};
+    pub use crate::assert_relative_eq::RelativeEq;
+    pub use crate::assert_single::{preview, single};
+    pub use crate::assert_slice_starts_with::{ends_with, starts_with};
+    pub use crate::assert_sum_eq::{checked_sum, Summand};
+    pub use crate::assert_sum_near::sum_count;
+    pub use crate::assert_ulps_eq::UlpsEq;
+    pub use crate::assert_zero::Zero;
+    pub use crate::collection::{
+        BorrowCollection, Collection, NoMatch, TakeIterator, ViewCollection, ViewCollectionRef,
+        ViewIterator, ViewRange, ViewString, ViewTakenIterator,
+    };
+    #[cfg(feature = "std")]
+    pub use crate::elements::{collect, Elements, HashElements, OrdElements};
+    pub use crate::maybe_debug::{IsDebug, IsNotDebug, MaybeDebug};
+    pub use crate::maybe_difference::{IsAbsDiff, IsNotSub, IsSub, MaybeDifference};
+}
@@ -0,0 +1,58 @@
+use core::fmt;
+
+/// Wrapper used to format a value with [`Debug`] if it is implemented, and with a `<non-Debug>`
+/// placeholder otherwise.
+///
+/// The implementation is chosen through autoref-based specialization: calling `maybe_debug()` on a
+/// `&MaybeDebug<T>` resolves to [`IsDebug`] when `T: Debug`, and falls back to [`IsNotDebug`]
+/// otherwise. Both traits must be in scope at the call site.
+///
+/// This is an implementation detail of the comparison macros and is not part of the public API.
+///
+/// [`Debug`]: core::fmt::Debug
+#[doc(hidden)]
+pub struct MaybeDebug<'a, T: ?Sized>(pub &'a T);
+
+/// Placeholder printed in place of values that do not implement [`Debug`].
+///
+/// [`Debug`]: core::fmt::Debug
+#[doc(hidden)]
+pub struct NonDebug;
+
+impl fmt::Debug for NonDebug {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("<non-Debug>")
+    }
+}
+
+#[doc(hidden)]
+pub trait IsDebug {
+    type Target: fmt::Debug + ?Sized;
+
+    fn maybe_debug(&self) -> &Self::Target;
+}
+
+impl<T> IsDebug for MaybeDebug<'_, T>
+where
+    T: fmt::Debug + ?Sized,
+{
+    type Target = T;
+
+    fn maybe_debug(&self) -> &T {
+        self.0
+    }
+}
+
+#[doc(hidden)]
+pub trait IsNotDebug {
+    fn maybe_debug(&self) -> NonDebug;
+}
+
+impl<T> IsNotDebug for &MaybeDebug<'_, T>
+where
+    T: ?Sized,
+{
+    fn maybe_debug(&self) -> NonDebug {
+        NonDebug
+    }
+}
@@ -0,0 +1,94 @@
+use crate::assert_abs_diff_eq::AbsDiffEq;
+use core::{fmt, ops::Sub};
+
+/// Wrapper used to compute the difference `self.0 - self.1` for a panic message, if possible.
+///
+/// The implementation is chosen through autoref-based specialization: calling `difference()` on a
+/// `&&MaybeDifference<A, B>` resolves, in order of preference, to [`IsAbsDiff`] for primitive
+/// numbers of the same type, to [`IsSub`] for types implementing [`Sub`] with a [`Debug`] output,
+/// and to [`IsNotSub`] otherwise. All three traits must be in scope at the call site.
+///
+/// The primitive implementation is preferred because it can't overflow. It is only used once the
+/// comparison has already failed, so the absolute difference is equal to `self.0 - self.1`.
+///
+/// This is an implementation detail of the comparison macros and is not part of the public API.
+///
+/// [`Debug`]: core::fmt::Debug
+/// [`Sub`]: core::ops::Sub
+#[doc(hidden)]
+pub struct MaybeDifference<'a, A: ?Sized, B: ?Sized>(pub &'a A, pub &'a B);
+
+/// A difference to be appended to a panic message.
+#[doc(hidden)]
+pub struct Difference<T>(T);
+
+impl<T> fmt::Display for Difference<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, ",\n    difference: `{:?}`", self.0)
+    }
+}
+
+/// Placeholder appending nothing to a panic message when no difference can be computed.
+#[doc(hidden)]
+pub struct NoDifference;
+
+impl fmt::Display for NoDifference {
+    fn fmt(&self, _formatter: &mut fmt::Formatter) -> fmt::Result {
+        Ok(())
+    }
+}
+
+#[doc(hidden)]
+pub trait IsAbsDiff {
+    type Output;
+
+    fn difference(&self) -> Difference<Self::Output>;
+}
+
+impl<T> IsAbsDiff for &MaybeDifference<'_, T, T>
+where
+    T: AbsDiffEq + ?Sized,
+{
+    type Output = T::Epsilon;
+
+    fn difference(&self) -> Difference<T::Epsilon> {
+        Difference(self.0.abs_diff(self.1))
+    }
+}
+
+#[doc(hidden)]
+pub trait IsSub {
+    type Output;
+
+    fn difference(&self) -> Difference<Self::Output>;
+}
+
+impl<'a, A, B> IsSub for &&MaybeDifference<'a, A, B>
+where
+    &'a A: Sub<&'a B>,
+    <&'a A as Sub<&'a B>>::Output: fmt::Debug,
+{
+    type Output = <&'a A as Sub<&'a B>>::Output;
+
+    fn difference(&self) -> Difference<Self::Output> {
+        Difference(self.0 - self.1)
+    }
+}
+
+#[doc(hidden)]
+pub trait IsNotSub {
+    fn difference(&self) -> NoDifference;
+}
+
+impl<A, B> IsNotSub for MaybeDifference<'_, A, B>
+where
+    A: ?Sized,
+    B: ?Sized,
+{
+    fn difference(&self) -> NoDifference {
+        NoDifference
+    }
+}